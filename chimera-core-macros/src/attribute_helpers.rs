@@ -57,6 +57,11 @@ pub(crate) fn get_lazy(attrs: &[Attribute]) -> bool {
     false
 }
 
+/// 从属性中提取是否处于刷新作用域
+pub(crate) fn get_refresh_scope(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident("refresh_scope"))
+}
+
 /// 将 PascalCase 转换为 camelCase
 pub(crate) fn to_camel_case(s: &str) -> String {
     let mut chars = s.chars();
@@ -97,3 +102,102 @@ pub(crate) fn get_destroy_method(attrs: &[Attribute]) -> Option<String> {
     }
     None
 }
+
+/// 从文档注释中提取描述（多行注释以空格连接）
+pub(crate) fn get_doc_comment(attrs: &[Attribute]) -> String {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(nv) => match &nv.value {
+                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(s), .. }) => {
+                    Some(s.value().trim().to_string())
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 从 `#[deprecated]` 属性中提取废弃信息
+///
+/// 返回 None 表示未废弃，Some(note) 表示已废弃（note 为可选的说明）
+/// 支持格式:
+/// - #[deprecated]
+/// - #[deprecated = "说明"]
+/// - #[deprecated(note = "说明")]
+pub(crate) fn get_deprecation(attrs: &[Attribute]) -> Option<Option<String>> {
+    for attr in attrs {
+        if !attr.path().is_ident("deprecated") {
+            continue;
+        }
+
+        return Some(match &attr.meta {
+            syn::Meta::NameValue(nv) => match &nv.value {
+                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(s), .. }) => Some(s.value()),
+                _ => None,
+            },
+            syn::Meta::List(_) => {
+                let mut note = None;
+                let _ = attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("note") {
+                        note = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                    } else if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<syn::Expr>()?;
+                    }
+                    Ok(())
+                });
+                note
+            }
+            syn::Meta::Path(_) => None,
+        });
+    }
+    None
+}
+
+/// 生成配置元数据的 inventory 注册代码
+pub(crate) fn generate_config_metadata(
+    key: &str,
+    type_name: &str,
+    default_value: Option<String>,
+    attrs: &[Attribute],
+    source_type: &str,
+) -> proc_macro2::TokenStream {
+    let description = get_doc_comment(attrs);
+    let deprecation = get_deprecation(attrs);
+    let deprecated = deprecation.is_some();
+    let deprecation_note = match deprecation.flatten() {
+        Some(note) => quote! { Some(#note) },
+        None => quote! { None },
+    };
+    let default_value = match default_value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    };
+
+    quote! {
+        inventory::submit! {
+            chimera_core::metadata::ConfigPropertyMetadata {
+                key: #key,
+                type_name: #type_name,
+                default_value: #default_value,
+                description: #description,
+                source_type: #source_type,
+                deprecated: #deprecated,
+                deprecation_note: #deprecation_note,
+            }
+        }
+    }
+}
+
+/// 将类型转换为紧凑的类型名称字符串，例如 `Option < String >` -> `Option<String>`
+pub(crate) fn type_to_string(ty: &syn::Type) -> String {
+    let mut name = quote! { #ty }.to_string();
+    for (from, to) in [(" <", "<"), ("< ", "<"), (" >", ">"), (" ,", ","), (" ::", "::"), (":: ", "::"), ("& ", "&")] {
+        name = name.replace(from, to);
+    }
+    name
+}
@@ -0,0 +1,283 @@
+//! Auto-configuration impl block attribute macro
+//!
+//! 用于自动配置类的 impl 块，生成 AutoConfigurationRegistry 并处理类级别和 #[bean] 方法上的条件
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Attribute, Expr, ImplItem, ItemImpl, Lit, Token, Type};
+
+use crate::configuration_attr::generate_bean_method;
+
+/// #[auto_configuration(after = ..., before = ...)] 参数
+#[derive(Default)]
+struct AutoConfigurationArgs {
+    after: Vec<String>,
+    before: Vec<String>,
+}
+
+impl Parse for AutoConfigurationArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = AutoConfigurationArgs::default();
+        let items = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        for item in items {
+            let names = ordering_names(&item.value)?;
+            if item.path.is_ident("after") {
+                args.after.extend(names);
+            } else if item.path.is_ident("before") {
+                args.before.extend(names);
+            } else {
+                return Err(syn::Error::new_spanned(
+                    &item.path,
+                    "expected `after` or `before`",
+                ));
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+/// 解析顺序约束中的自动配置名称
+///
+/// 支持 `"Name"`、`Name`、`path::to::Name` 以及它们组成的数组，路径只取最后一段
+fn ordering_names(expr: &Expr) -> syn::Result<Vec<String>> {
+    match expr {
+        Expr::Lit(expr_lit) => match &expr_lit.lit {
+            Lit::Str(lit) => Ok(vec![lit.value()]),
+            other => Err(syn::Error::new_spanned(other, "expected a string or a type name")),
+        },
+        Expr::Path(expr_path) => {
+            let last = expr_path.path.segments.last().ok_or_else(|| {
+                syn::Error::new_spanned(expr_path, "expected a type name")
+            })?;
+            Ok(vec![last.ident.to_string()])
+        }
+        Expr::Array(array) => {
+            let mut names = Vec::new();
+            for elem in &array.elems {
+                names.extend(ordering_names(elem)?);
+            }
+            Ok(names)
+        }
+        other => Err(syn::Error::new_spanned(other, "expected a string, a type name or an array")),
+    }
+}
+
+/// Auto-configuration impl 块属性宏
+pub(crate) fn auto_configuration_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as AutoConfigurationArgs);
+    let input = parse_macro_input!(item as ItemImpl);
+    let self_ty = &input.self_ty;
+
+    // 自动配置名称使用类型名（不含路径）
+    let name = match self_ty.as_ref() {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_else(|| quote! { #self_ty }.to_string()),
+        other => quote! { #other }.to_string(),
+    };
+
+    let class_conditions = match parse_conditions(&input.attrs, None) {
+        Ok(conditions) => conditions,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    let mut registrars = Vec::new();
+    let mut beans = Vec::new();
+    for item in &input.items {
+        let ImplItem::Fn(method) = item else {
+            continue;
+        };
+        if !method.attrs.iter().any(|attr| attr.path().is_ident("bean")) {
+            continue;
+        }
+
+        let code = match generate_bean_method(self_ty, method) {
+            Ok(code) => code,
+            Err(error) => return error.into(),
+        };
+        let conditions = match parse_conditions(&method.attrs, Some(&code.return_type)) {
+            Ok(conditions) => conditions,
+            Err(error) => return error.to_compile_error().into(),
+        };
+
+        let register_fn_name = &code.register_fn_name;
+        let bean_name = &code.bean_name;
+        registrars.push(code.registrar);
+        beans.push(quote! {
+            chimera_core::autoconfigure::AutoConfigurationBean {
+                bean_name: #bean_name,
+                conditions: &[#(#conditions),*],
+                registrar: #register_fn_name,
+            }
+        });
+    }
+
+    // 条件属性已经处理，从输出中移除
+    let mut output = input.clone();
+    output.attrs.retain(|attr| !is_condition_attr(attr));
+    for item in &mut output.items {
+        if let ImplItem::Fn(method) = item {
+            method.attrs.retain(|attr| !is_condition_attr(attr));
+        }
+    }
+
+    let after = &args.after;
+    let before = &args.before;
+
+    let expanded = quote! {
+        #output
+
+        #(#registrars)*
+
+        inventory::submit! {
+            chimera_core::autoconfigure::AutoConfigurationRegistry {
+                name: #name,
+                after: &[#(#after),*],
+                before: &[#(#before),*],
+                conditions: &[#(#class_conditions),*],
+                class_bean_name: <#self_ty as chimera_core::Component>::bean_name,
+                register_class: <#self_ty as chimera_core::Component>::register,
+                beans: &[#(#beans),*],
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+fn is_condition_attr(attr: &Attribute) -> bool {
+    let path = attr.path();
+    path.is_ident("conditional_on_property")
+        || path.is_ident("conditional_on_bean")
+        || path.is_ident("conditional_on_missing_bean")
+}
+
+/// #[conditional_on_property(...)] 参数
+struct PropertyConditionArgs {
+    name: String,
+    having_value: Option<String>,
+    match_if_missing: bool,
+}
+
+impl Parse for PropertyConditionArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // 简写形式：#[conditional_on_property("feature.enabled")]
+        if input.peek(syn::LitStr) {
+            let lit: syn::LitStr = input.parse()?;
+            return Ok(Self {
+                name: lit.value(),
+                having_value: None,
+                match_if_missing: false,
+            });
+        }
+
+        let mut name = None;
+        let mut having_value = None;
+        let mut match_if_missing = false;
+        let items = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        for item in items {
+            let Expr::Lit(expr_lit) = &item.value else {
+                return Err(syn::Error::new_spanned(&item.value, "expected a literal"));
+            };
+            match (&expr_lit.lit, item.path.get_ident().map(|i| i.to_string()).as_deref()) {
+                (Lit::Str(lit), Some("name")) => name = Some(lit.value()),
+                (Lit::Str(lit), Some("having_value")) => having_value = Some(lit.value()),
+                (Lit::Bool(lit), Some("having_value")) => having_value = Some(lit.value.to_string()),
+                (Lit::Int(lit), Some("having_value")) => having_value = Some(lit.base10_digits().to_string()),
+                (Lit::Bool(lit), Some("match_if_missing")) => match_if_missing = lit.value,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &item,
+                        "expected `name = \"...\"`, `having_value = \"...\"` or `match_if_missing = true|false`",
+                    ))
+                }
+            }
+        }
+
+        let name = name.ok_or_else(|| input.error("missing `name = \"...\"`"))?;
+        Ok(Self {
+            name,
+            having_value,
+            match_if_missing,
+        })
+    }
+}
+
+/// 解析条件属性，`bean_type` 为 #[bean] 方法的返回类型（类级别条件为 None）
+fn parse_conditions(
+    attrs: &[Attribute],
+    bean_type: Option<&Type>,
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    let mut conditions = Vec::new();
+
+    for attr in attrs {
+        let path = attr.path();
+        if path.is_ident("conditional_on_property") {
+            let args: PropertyConditionArgs = attr.parse_args()?;
+            let name = &args.name;
+            let having_value = match &args.having_value {
+                Some(value) => quote! { Some(#value) },
+                None => quote! { None },
+            };
+            let match_if_missing = args.match_if_missing;
+            conditions.push(quote! {
+                chimera_core::autoconfigure::Condition::OnProperty {
+                    name: #name,
+                    having_value: #having_value,
+                    match_if_missing: #match_if_missing,
+                }
+            });
+        } else if path.is_ident("conditional_on_bean") || path.is_ident("conditional_on_missing_bean") {
+            let selector = parse_bean_selector(attr, bean_type)?;
+            let condition = if path.is_ident("conditional_on_bean") {
+                quote! { chimera_core::autoconfigure::Condition::OnBean(#selector) }
+            } else {
+                quote! { chimera_core::autoconfigure::Condition::OnMissingBean(#selector) }
+            };
+            conditions.push(condition);
+        }
+    }
+
+    Ok(conditions)
+}
+
+/// 解析 Bean 选择器：`("beanName")` 按名称，`(Type)` 按类型，无参数时使用 #[bean] 方法的返回类型
+fn parse_bean_selector(
+    attr: &Attribute,
+    bean_type: Option<&Type>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let ty = match &attr.meta {
+        syn::Meta::Path(_) => match bean_type {
+            Some(ty) => ty.clone(),
+            None => {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "a bean name or type is required when the condition is not on a #[bean] method",
+                ))
+            }
+        },
+        _ => {
+            if let Ok(lit) = attr.parse_args::<syn::LitStr>() {
+                let name = lit.value();
+                return Ok(quote! { chimera_core::autoconfigure::BeanSelector::Name(#name) });
+            }
+            attr.parse_args::<Type>()?
+        }
+    };
+
+    let type_name = quote! { #ty }.to_string().replace(' ', "");
+    Ok(quote! {
+        chimera_core::autoconfigure::BeanSelector::Type {
+            type_id: || std::any::TypeId::of::<#ty>(),
+            type_name: #type_name,
+        }
+    })
+}
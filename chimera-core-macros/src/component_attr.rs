@@ -1,15 +1,29 @@
 //! Component 属性宏实现
 //!
-//! 用于标记 Component 的 impl 块，检查方法名是否与 Component trait 冲突
+//! 用于标记 Component 的 impl 块，检查方法名是否与 Component trait 冲突，
+//! 并处理 `#[event_listener]` 标记的事件监听方法
 
 use proc_macro::TokenStream;
 use proc_macro_error::abort;
-use syn::{ImplItem, ImplItemFn, ItemImpl};
+use quote::{format_ident, quote};
+use syn::{Expr, FnArg, ImplItem, ImplItemFn, ItemImpl, Type};
 
 /// Component impl 块属性宏
 ///
 /// 用于标记 Component 类型的 impl 块，自动检查方法名是否与 Component trait 的保留方法冲突
 ///
+/// impl 块中标记 `#[event_listener]` 的方法会被注册为事件监听器，方法签名必须为
+/// `fn(&self, event: &E)`（E 为任意 Event 类型），支持 `async fn`，返回 `()` 或 `Result<()>`，
+/// 也可以返回事件、`Option<事件>` 或 `Vec<事件>`（及其 `Result`），返回的事件会作为后续事件继续发布：
+/// - `condition = "..."`：Rust 表达式，可使用 `self` 和 `event`，结果为 false 时跳过
+/// - `order = n`：执行顺序，值越小越先执行
+/// - `executor = "inline" | "spawn" | "blocking"`：执行方式，默认跟随多播器的同步/异步模式
+///
+/// 标记 `#[transactional_event_listener]` 的方法在此基础上绑定到事务阶段，事务中发布的事件
+/// 会在事务到达指定阶段时才执行：
+/// - `phase = "before_commit" | "after_commit" | "after_rollback" | "after_completion"`：默认 `after_commit`
+/// - `fallback_execution = true`：没有活动事务时仍然执行（默认跳过）
+///
 /// # 示例
 ///
 /// ```ignore
@@ -20,10 +34,16 @@ use syn::{ImplItem, ImplItemFn, ItemImpl};
 /// impl UserService {
 ///     pub fn create_user(&self) { }  // OK
 ///     pub fn register(&self) { }     // 编译错误：与 Component::register 冲突
+///
+///     #[event_listener(order = 1, condition = "event.amount > 100")]
+///     fn on_order_created(&self, event: &OrderCreatedEvent) { }
+///
+///     #[transactional_event_listener(phase = "after_commit")]
+///     async fn send_confirmation(&self, event: &OrderCreatedEvent) -> Result<()> { Ok(()) }
 /// }
 /// ```
 pub fn component_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let input = syn::parse_macro_input!(item as ItemImpl);
+    let mut input = syn::parse_macro_input!(item as ItemImpl);
 
     // 检查所有方法名
     for item in &input.items {
@@ -32,8 +52,279 @@ pub fn component_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     }
 
-    // 返回原始的 impl 块（不做任何修改）
-    TokenStream::from(quote::quote! { #input })
+    // 收集并移除 #[event_listener] / #[transactional_event_listener] 属性
+    let mut listeners = Vec::new();
+    for item in &mut input.items {
+        if let ImplItem::Fn(method) = item {
+            if let Some(listener) = parse_event_listener(method) {
+                listeners.push(listener);
+            }
+        }
+    }
+
+    if listeners.is_empty() {
+        return TokenStream::from(quote! { #input });
+    }
+
+    if !input.generics.params.is_empty() {
+        abort!(
+            input.generics,
+            "#[event_listener] methods are not supported in generic impl blocks"
+        );
+    }
+
+    let self_ty = input.self_ty.clone();
+    let type_name = match self_ty.as_ref() {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|s| s.ident.to_string())
+            .unwrap_or_default(),
+        other => quote! { #other }.to_string(),
+    };
+
+    // 条件表达式生成为隐藏方法，以便表达式中可以使用 self 和 event
+    let mut condition_methods = Vec::new();
+    let registrations = listeners.iter().map(|listener| {
+        let method_name = &listener.method;
+        let event_type = &listener.event_type;
+        let listener_name = format!("{}::{}", type_name, method_name);
+        let order = match &listener.order {
+            Some(order) => quote! { (#order) as i32 },
+            None => quote! { 0 },
+        };
+        let transaction = match &listener.transaction_phase {
+            Some(phase) => {
+                let phase = format_ident!("{}", phase);
+                let fallback_execution = listener.fallback_execution;
+                quote! {
+                    let listener = listener
+                        .with_transaction_phase(chimera_core::TransactionPhase::#phase)
+                        .with_fallback_execution(#fallback_execution);
+                }
+            }
+            None => quote! {},
+        };
+        let executor = match listener.executor.as_deref() {
+            Some("inline") => quote! { chimera_core::ListenerExecutor::Inline },
+            Some("spawn") => quote! { chimera_core::ListenerExecutor::Spawn },
+            Some("blocking") => quote! { chimera_core::ListenerExecutor::Blocking },
+            _ => quote! { chimera_core::ListenerExecutor::Default },
+        };
+
+        let constructor = if listener.is_async {
+            quote! {
+                let handler_bean = std::sync::Arc::clone(&bean);
+                let listener = chimera_core::MethodEventListener::<#event_type>::new_async(
+                    #listener_name,
+                    move |event: std::sync::Arc<dyn chimera_core::Event>| -> chimera_core::event::EventHandlerFuture {
+                        let bean = std::sync::Arc::clone(&handler_bean);
+                        Box::pin(async move {
+                            use chimera_core::FollowUpEvents;
+                            match event.as_any().downcast_ref::<#event_type>() {
+                                Some(typed_event) => bean.#method_name(typed_event).await.into_follow_up_events(),
+                                None => Ok(Vec::new()),
+                            }
+                        })
+                    },
+                );
+            }
+        } else {
+            quote! {
+                let handler_bean = std::sync::Arc::clone(&bean);
+                let listener = chimera_core::MethodEventListener::<#event_type>::new(
+                    #listener_name,
+                    move |event: &#event_type| {
+                        use chimera_core::FollowUpEvents;
+                        handler_bean.#method_name(event).into_follow_up_events()
+                    },
+                );
+            }
+        };
+
+        let condition = match &listener.condition {
+            Some(expr) => {
+                let condition_fn = format_ident!("__chimera_event_listener_condition_{}", method_name);
+                condition_methods.push(quote! {
+                    #[doc(hidden)]
+                    #[allow(unused_variables)]
+                    fn #condition_fn(&self, event: &#event_type) -> bool {
+                        #expr
+                    }
+                });
+                quote! {
+                    let condition_bean = std::sync::Arc::clone(&bean);
+                    let listener = listener.with_condition(move |event: &#event_type| {
+                        condition_bean.#condition_fn(event)
+                    });
+                }
+            }
+            None => quote! {},
+        };
+
+        quote! {
+            inventory::submit! {
+                chimera_core::EventListenerRegistry {
+                    registrar: |ctx: &std::sync::Arc<chimera_core::ApplicationContext>| {
+                        let bean = ctx.get_bean_by_type::<#self_ty>()?;
+                        #constructor
+                        let listener = listener.with_order(#order).with_executor(#executor);
+                        #transaction
+                        #condition
+                        Ok(std::sync::Arc::new(listener) as std::sync::Arc<dyn chimera_core::EventListener>)
+                    },
+                    name: #listener_name,
+                }
+            }
+        }
+    }).collect::<Vec<_>>();
+
+    let condition_impl = if condition_methods.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            impl #self_ty {
+                #(#condition_methods)*
+            }
+        }
+    };
+
+    TokenStream::from(quote! {
+        #input
+
+        #condition_impl
+
+        #(#registrations)*
+    })
+}
+
+/// 解析后的事件监听方法
+struct EventListenerMethod {
+    method: syn::Ident,
+    event_type: Type,
+    is_async: bool,
+    condition: Option<Expr>,
+    order: Option<Expr>,
+    executor: Option<String>,
+    /// 事务阶段（TransactionPhase 变体名），仅 #[transactional_event_listener]
+    transaction_phase: Option<&'static str>,
+    fallback_execution: bool,
+}
+
+/// 是否为事件监听器属性
+fn is_listener_attr(attr: &syn::Attribute) -> bool {
+    attr.path().is_ident("event_listener") || attr.path().is_ident("transactional_event_listener")
+}
+
+/// 解析方法上的 #[event_listener] / #[transactional_event_listener] 属性，并从方法上移除该属性
+fn parse_event_listener(method: &mut ImplItemFn) -> Option<EventListenerMethod> {
+    let attr = method.attrs.iter().find(|attr| is_listener_attr(attr))?.clone();
+    method.attrs.retain(|attr| !is_listener_attr(attr));
+
+    let transactional = attr.path().is_ident("transactional_event_listener");
+    let mut condition = None;
+    let mut order = None;
+    let mut executor = None;
+    let mut transaction_phase = transactional.then_some("AfterCommit");
+    let mut fallback_execution = false;
+    if matches!(attr.meta, syn::Meta::List(_)) {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("condition") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                let expr = lit.parse::<Expr>().map_err(|e| {
+                    syn::Error::new(lit.span(), format!("invalid condition expression: {}", e))
+                })?;
+                condition = Some(expr);
+                Ok(())
+            } else if meta.path.is_ident("order") {
+                order = Some(meta.value()?.parse::<Expr>()?);
+                Ok(())
+            } else if meta.path.is_ident("executor") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                match lit.value().as_str() {
+                    "inline" | "spawn" | "blocking" => {
+                        executor = Some(lit.value());
+                        Ok(())
+                    }
+                    other => Err(syn::Error::new(
+                        lit.span(),
+                        format!("unknown executor '{}', expected \"inline\", \"spawn\" or \"blocking\"", other),
+                    )),
+                }
+            } else if transactional && meta.path.is_ident("phase") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                transaction_phase = Some(match lit.value().as_str() {
+                    "before_commit" => "BeforeCommit",
+                    "after_commit" => "AfterCommit",
+                    "after_rollback" => "AfterRollback",
+                    "after_completion" => "AfterCompletion",
+                    other => {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                "unknown phase '{}', expected \"before_commit\", \"after_commit\", \"after_rollback\" or \"after_completion\"",
+                                other
+                            ),
+                        ))
+                    }
+                });
+                Ok(())
+            } else if transactional && meta.path.is_ident("fallback_execution") {
+                fallback_execution = meta.value()?.parse::<syn::LitBool>()?.value;
+                Ok(())
+            } else if transactional {
+                Err(meta.error(
+                    "unsupported transactional_event_listener attribute, expected `phase`, `fallback_execution`, `condition`, `order` or `executor`",
+                ))
+            } else {
+                Err(meta.error("unsupported event_listener attribute, expected `condition`, `order` or `executor`"))
+            }
+        });
+        if let Err(e) = result {
+            abort!(e.span(), "{}", e);
+        }
+    }
+
+    let sig = &method.sig;
+    let mut inputs = sig.inputs.iter();
+    match inputs.next() {
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() && receiver.mutability.is_none() => {}
+        _ => abort!(
+            sig.ident.span(),
+            "#[event_listener] method '{}' must take `&self`",
+            sig.ident;
+            help = "expected signature: fn {}(&self, event: &MyEvent)", sig.ident
+        ),
+    }
+
+    let event_type = match (inputs.next(), inputs.next()) {
+        (Some(FnArg::Typed(arg)), None) => match arg.ty.as_ref() {
+            Type::Reference(reference) if reference.mutability.is_none() => (*reference.elem).clone(),
+            _ => abort!(
+                arg.ty,
+                "#[event_listener] event parameter must be a shared reference to an Event type";
+                help = "expected signature: fn {}(&self, event: &MyEvent)", sig.ident
+            ),
+        },
+        _ => abort!(
+            sig.ident.span(),
+            "#[event_listener] method '{}' must take exactly one event parameter",
+            sig.ident;
+            help = "expected signature: fn {}(&self, event: &MyEvent)", sig.ident
+        ),
+    };
+
+    Some(EventListenerMethod {
+        method: sig.ident.clone(),
+        event_type,
+        is_async: sig.asyncness.is_some(),
+        condition,
+        order,
+        executor,
+        transaction_phase,
+        fallback_execution,
+    })
 }
 
 /// Component trait 保留的方法名
@@ -41,6 +332,7 @@ const RESERVED_METHODS: &[&str] = &[
     "bean_name",
     "scope",
     "lazy",
+    "refresh_scope",
     "dependencies",
     "init_callback",
     "destroy_callback",
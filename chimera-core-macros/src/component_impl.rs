@@ -3,7 +3,8 @@ use quote::quote;
 use syn::{parse_macro_input, Data, DeriveInput, Fields, Type};
 
 use crate::attribute_helpers::{
-    get_component_name, get_destroy_method, get_init_method, get_lazy, get_scope, to_camel_case,
+    generate_config_metadata, get_component_name, get_destroy_method, get_init_method, get_lazy,
+    get_refresh_scope, get_scope, to_camel_case, type_to_string,
 };
 use crate::value_injection::get_value_info;
 
@@ -25,6 +26,7 @@ pub(crate) fn derive_component_impl(input: TokenStream) -> TokenStream {
 
     let scope = get_scope(&input.attrs);
     let lazy = get_lazy(&input.attrs);
+    let refresh_scope = get_refresh_scope(&input.attrs);
     let init_method = get_init_method(&input.attrs);
     let destroy_method = get_destroy_method(&input.attrs);
 
@@ -116,6 +118,26 @@ pub(crate) fn derive_component_impl(input: TokenStream) -> TokenStream {
         base_injection
     });
 
+    // 生成value字段的配置元数据
+    let value_metadata = value_fields.iter().filter_map(|field| {
+        let value_info = get_value_info(&field.attrs)?;
+        let default_value = value_info.default_value.as_ref().map(|tokens| {
+            let text = tokens.to_string();
+            text.strip_prefix('"')
+                .and_then(|t| t.strip_suffix('"'))
+                .map(String::from)
+                .unwrap_or(text)
+        });
+
+        Some(generate_config_metadata(
+            &value_info.key,
+            &type_to_string(&field.ty),
+            default_value,
+            &field.attrs,
+            &name.to_string(),
+        ))
+    });
+
     // 生成value字段注入代码
     let value_injections = value_fields.iter().map(|field| {
         let field_name = &field.ident;
@@ -521,6 +543,7 @@ pub(crate) fn derive_component_impl(input: TokenStream) -> TokenStream {
     };
 
     let expanded = quote! {
+        #[allow(deprecated)]
         impl chimera_core::Component for #name {
             fn bean_name() -> &'static str {
                 #bean_name
@@ -534,6 +557,10 @@ pub(crate) fn derive_component_impl(input: TokenStream) -> TokenStream {
                 #lazy
             }
 
+            fn refresh_scope() -> bool {
+                #refresh_scope
+            }
+
             fn dependencies() -> Vec<String> {
                 vec![#(#dependency_names.to_string()),*]
             }
@@ -567,6 +594,9 @@ pub(crate) fn derive_component_impl(input: TokenStream) -> TokenStream {
 
         // 条件性注册EventListener
         #event_listener_registration
+
+        // 注册配置元数据
+        #(#value_metadata)*
     };
 
     TokenStream::from(expanded)
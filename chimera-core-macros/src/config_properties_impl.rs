@@ -2,6 +2,8 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, DeriveInput, Data, Fields, Attribute};
 
+use crate::attribute_helpers::{generate_config_metadata, type_to_string};
+
 /// ConfigurationProperties 配置信息
 struct ConfigPropertiesInfo {
     prefix: Option<String>,
@@ -74,6 +76,27 @@ pub(crate) fn derive_configuration_properties_impl(input: TokenStream) -> TokenS
         vec![]
     };
 
+    // 生成字段的配置元数据
+    let field_metadata = fields.iter().map(|field| {
+        let field_name = field.ident.as_ref().unwrap().to_string();
+        let config_key = get_field_config_info(&field.attrs)
+            .and_then(|info| info.config_key)
+            .unwrap_or_else(|| field_name_to_config_key(&field_name));
+        let full_key = if prefix.is_empty() {
+            config_key
+        } else {
+            format!("{}.{}", prefix, config_key)
+        };
+
+        generate_config_metadata(
+            &full_key,
+            &type_to_string(&field.ty),
+            None,
+            &field.attrs,
+            &name.to_string(),
+        )
+    });
+
     // 生成字段绑定代码
     let field_bindings = fields.iter().map(|field| {
         let field_name = &field.ident;
@@ -175,6 +198,7 @@ pub(crate) fn derive_configuration_properties_impl(input: TokenStream) -> TokenS
 
     // 生成实现代码
     let expanded = quote! {
+        #[allow(deprecated)]
         impl #name {
             /// 从 Environment 绑定配置
             pub fn bind(env: &std::sync::Arc<chimera_core::config::Environment>) -> std::result::Result<Self, anyhow::Error> {
@@ -187,18 +211,29 @@ pub(crate) fn derive_configuration_properties_impl(input: TokenStream) -> TokenS
 
             /// 注册到容器（内部使用）
             fn __register_to_context(context: &std::sync::Arc<chimera_core::ApplicationContext>) -> chimera_core::Result<()> {
+                use chimera_core::Container;
+
                 let env = std::sync::Arc::clone(context.environment());
 
-                // 绑定配置
-                let instance = Self::bind(&env)
+                // 启动时先绑定一次，尽早暴露配置错误
+                Self::bind(&env)
                     .map_err(|e| anyhow::anyhow!(
                         "{}: {}", #bean_name, e
                     ))?;
 
-                // 注册为单例 Bean
-                context.register_singleton(#bean_name, move || {
-                    Ok(instance.clone())
-                })?;
+                // 注册为刷新作用域的单例 Bean，配置热加载后会在下次访问时重新绑定
+                let definition = chimera_core::BeanDefinition::new(
+                    #bean_name,
+                    chimera_core::bean::FunctionFactory::new(move || {
+                        Self::bind(&env).map_err(|e| anyhow::anyhow!(
+                            "{}: {}", #bean_name, e
+                        ))
+                    }),
+                )
+                .with_scope(chimera_core::Scope::Singleton)
+                .with_refresh_scope(true);
+
+                context.as_ref().register(definition)?;
 
                 Ok(())
             }
@@ -213,6 +248,9 @@ pub(crate) fn derive_configuration_properties_impl(input: TokenStream) -> TokenS
                 name: #bean_name,
             }
         }
+
+        // 注册配置元数据
+        #(#field_metadata)*
     };
 
     TokenStream::from(expanded)
@@ -32,138 +32,19 @@ pub(crate) fn configuration_impl(attr: TokenStream, item: TokenStream) -> TokenS
     
     // 生成注册代码
     let registrations = bean_methods.iter().map(|method| {
-        let method_name = &method.sig.ident;
-        let bean_name = extract_bean_name(&method.attrs, &method_name.to_string());
-        let scope = extract_scope(&method.attrs);
-        let is_lazy = extract_lazy(&method.attrs);
-        let init_method = extract_init(&method.attrs);
-        let destroy_method = extract_destroy(&method.attrs);
-
-        // 检查返回类型
-        let (return_type, is_result) = match &method.sig.output {
-            ReturnType::Default => {
-                return syn::Error::new_spanned(
-                    method,
-                    "#[bean] method must return a value"
-                ).to_compile_error();
-            }
-            ReturnType::Type(_, ty) => {
-                if let Some(inner) = extract_result_type(ty) {
-                    (inner, true)
-                } else {
-                    (ty.as_ref(), false)
-                }
-            }
-        };
-        
-        // Generate different code based on whether method returns Result or not
-        let bean_creation = if is_result {
-            // Method returns Result<T, E>, use ? to propagate errors
-            quote! {
-                let result = config_ref.#method_name();
-                let bean_instance: #return_type = result?;
-            }
-        } else {
-            // Method returns T directly, no need for ? operator
-            quote! {
-                let bean_instance: #return_type = config_ref.#method_name();
-            }
-        };
-
-        // 生成 init 回调代码
-        let init_callback_code = if let Some(ref init_method_name) = init_method {
-            let init_ident = syn::Ident::new(init_method_name, proc_macro2::Span::call_site());
-            quote! {
-                let definition = definition.with_init(|bean: &mut dyn std::any::Any| -> chimera_core::Result<()> {
-                    if let Some(instance) = bean.downcast_mut::<#return_type>() {
-                        // 使用 IntoResult trait 支持返回 () 或 Result<()>
-                        use chimera_core::IntoResult;
-                        instance.#init_ident().into_result()?;
-                    }
-                    Ok(())
-                });
-            }
-        } else {
-            quote! {}
-        };
-
-        // 生成 destroy 回调代码
-        let destroy_callback_code = if let Some(ref destroy_method_name) = destroy_method {
-            let destroy_ident = syn::Ident::new(destroy_method_name, proc_macro2::Span::call_site());
-            quote! {
-                let definition = definition.with_destroy(|bean: &mut dyn std::any::Any| -> chimera_core::Result<()> {
-                    if let Some(instance) = bean.downcast_mut::<#return_type>() {
-                        // 使用 IntoResult trait 支持返回 () 或 Result<()>
-                        use chimera_core::IntoResult;
-                        instance.#destroy_ident().into_result()?;
-                    }
-                    Ok(())
-                });
-            }
-        } else {
-            quote! {}
-        };
-
-        let scope_code = match scope.as_str() {
-            "prototype" => quote! { chimera_core::Scope::Prototype },
-            _ => quote! { chimera_core::Scope::Singleton },
+        let code = match generate_bean_method(self_ty, method) {
+            Ok(code) => code,
+            Err(error) => return error,
         };
-
-        let register_fn_name = syn::Ident::new(
-            &format!("__register_bean_{}_{}", bean_name.replace("-", "_"), method_name),
-            proc_macro2::Span::call_site(),
-        );
+        let register_fn_name = &code.register_fn_name;
+        let bean_name = &code.bean_name;
+        let registrar = &code.registrar;
 
         // 获取类型名的字符串形式
         let self_ty_str = quote! { #self_ty }.to_string();
 
-        // 为每个 bean 方法生成注册代码
         quote! {
-            #[allow(non_snake_case)]
-            fn #register_fn_name(
-                context: &std::sync::Arc<chimera_core::ApplicationContext>,
-                config_instance: std::sync::Arc<dyn std::any::Any + Send + Sync>,
-            ) -> chimera_core::Result<()> {
-                use chimera_core::Container;
-
-                let ctx = std::sync::Arc::clone(context);
-                let config = config_instance.clone();
-
-                // Factory 闭包直接返回具体类型，让 FunctionFactory 推断正确的 TypeId
-                let factory = move || -> chimera_core::Result<#return_type> {
-                    let config_ref = config.clone()
-                        .downcast::<#self_ty>()
-                        .map_err(|_e: std::sync::Arc<dyn std::any::Any + Send + Sync>| {
-                            anyhow::anyhow!(
-                                "Failed to downcast config instance for bean '{}'", #bean_name
-                            )
-                        })?;
-
-                    #bean_creation
-                    Ok(bean_instance)
-                };
-
-                let definition = chimera_core::BeanDefinition::new(
-                    #bean_name,
-                    chimera_core::bean::FunctionFactory::new(factory),
-                )
-                .with_scope(#scope_code);
-
-                let definition = if #is_lazy {
-                    definition.with_lazy(true)
-                } else {
-                    definition
-                };
-
-                // 添加 init 回调（如果指定了 init 方法）
-                #init_callback_code
-
-                // 添加 destroy 回调（如果指定了 destroy 方法）
-                #destroy_callback_code
-
-                ctx.as_ref().register(definition)?;
-                Ok(())
-            }
+            #registrar
 
             inventory::submit! {
                 chimera_core::bean::BeanMethodRegistry {
@@ -186,6 +67,165 @@ pub(crate) fn configuration_impl(attr: TokenStream, item: TokenStream) -> TokenS
     TokenStream::from(expanded)
 }
 
+/// 单个 #[bean] 方法生成的注册代码
+pub(crate) struct BeanMethodCode {
+    /// 注册函数名
+    pub register_fn_name: syn::Ident,
+    /// Bean 名称
+    pub bean_name: String,
+    /// Bean 类型（去掉 Result 包装）
+    pub return_type: Type,
+    /// 注册函数定义
+    pub registrar: proc_macro2::TokenStream,
+}
+
+/// 为 #[bean] 方法生成注册函数
+pub(crate) fn generate_bean_method(
+    self_ty: &Type,
+    method: &ImplItemFn,
+) -> Result<BeanMethodCode, proc_macro2::TokenStream> {
+    let method_name = &method.sig.ident;
+    let bean_name = extract_bean_name(&method.attrs, &method_name.to_string());
+    let scope = extract_scope(&method.attrs);
+    let is_lazy = extract_lazy(&method.attrs);
+    let is_refresh_scope = extract_refresh_scope(&method.attrs);
+    let init_method = extract_init(&method.attrs);
+    let destroy_method = extract_destroy(&method.attrs);
+
+    // 检查返回类型
+    let (return_type, is_result) = match &method.sig.output {
+        ReturnType::Default => {
+            return Err(syn::Error::new_spanned(
+                method,
+                "#[bean] method must return a value"
+            ).to_compile_error());
+        }
+        ReturnType::Type(_, ty) => {
+            if let Some(inner) = extract_result_type(ty) {
+                (inner, true)
+            } else {
+                (ty.as_ref(), false)
+            }
+        }
+    };
+    
+    // Generate different code based on whether method returns Result or not
+    let bean_creation = if is_result {
+        // Method returns Result<T, E>, use ? to propagate errors
+        quote! {
+            let result = config_ref.#method_name();
+            let bean_instance: #return_type = result?;
+        }
+    } else {
+        // Method returns T directly, no need for ? operator
+        quote! {
+            let bean_instance: #return_type = config_ref.#method_name();
+        }
+    };
+
+    // 生成 init 回调代码
+    let init_callback_code = if let Some(ref init_method_name) = init_method {
+        let init_ident = syn::Ident::new(init_method_name, proc_macro2::Span::call_site());
+        quote! {
+            let definition = definition.with_init(|bean: &mut dyn std::any::Any| -> chimera_core::Result<()> {
+                if let Some(instance) = bean.downcast_mut::<#return_type>() {
+                    // 使用 IntoResult trait 支持返回 () 或 Result<()>
+                    use chimera_core::IntoResult;
+                    instance.#init_ident().into_result()?;
+                }
+                Ok(())
+            });
+        }
+    } else {
+        quote! {}
+    };
+
+    // 生成 destroy 回调代码
+    let destroy_callback_code = if let Some(ref destroy_method_name) = destroy_method {
+        let destroy_ident = syn::Ident::new(destroy_method_name, proc_macro2::Span::call_site());
+        quote! {
+            let definition = definition.with_destroy(|bean: &mut dyn std::any::Any| -> chimera_core::Result<()> {
+                if let Some(instance) = bean.downcast_mut::<#return_type>() {
+                    // 使用 IntoResult trait 支持返回 () 或 Result<()>
+                    use chimera_core::IntoResult;
+                    instance.#destroy_ident().into_result()?;
+                }
+                Ok(())
+            });
+        }
+    } else {
+        quote! {}
+    };
+
+    let scope_code = match scope.as_str() {
+        "prototype" => quote! { chimera_core::Scope::Prototype },
+        _ => quote! { chimera_core::Scope::Singleton },
+    };
+
+    let register_fn_name = syn::Ident::new(
+        &format!("__register_bean_{}_{}", bean_name.replace("-", "_"), method_name),
+        proc_macro2::Span::call_site(),
+    );
+
+    // 为 bean 方法生成注册函数
+    let registrar = quote! {
+        #[allow(non_snake_case)]
+        fn #register_fn_name(
+            context: &std::sync::Arc<chimera_core::ApplicationContext>,
+            config_instance: std::sync::Arc<dyn std::any::Any + Send + Sync>,
+        ) -> chimera_core::Result<()> {
+            use chimera_core::Container;
+
+            let ctx = std::sync::Arc::clone(context);
+            let config = config_instance.clone();
+
+            // Factory 闭包直接返回具体类型，让 FunctionFactory 推断正确的 TypeId
+            let factory = move || -> chimera_core::Result<#return_type> {
+                let config_ref = config.clone()
+                    .downcast::<#self_ty>()
+                    .map_err(|_e: std::sync::Arc<dyn std::any::Any + Send + Sync>| {
+                        anyhow::anyhow!(
+                            "Failed to downcast config instance for bean '{}'", #bean_name
+                        )
+                    })?;
+
+                #bean_creation
+                Ok(bean_instance)
+            };
+
+            let definition = chimera_core::BeanDefinition::new(
+                #bean_name,
+                chimera_core::bean::FunctionFactory::new(factory),
+            )
+            .with_scope(#scope_code);
+
+            let definition = if #is_lazy {
+                definition.with_lazy(true)
+            } else {
+                definition
+            };
+
+            let definition = definition.with_refresh_scope(#is_refresh_scope);
+
+            // 添加 init 回调（如果指定了 init 方法）
+            #init_callback_code
+
+            // 添加 destroy 回调（如果指定了 destroy 方法）
+            #destroy_callback_code
+
+            ctx.as_ref().register(definition)?;
+            Ok(())
+        }
+    };
+
+    Ok(BeanMethodCode {
+        register_fn_name,
+        bean_name,
+        return_type: return_type.clone(),
+        registrar,
+    })
+}
+
 /// 提取 bean 名称
 fn extract_bean_name(attrs: &[Attribute], default_name: &str) -> String {
     for attr in attrs {
@@ -215,6 +255,11 @@ fn extract_lazy(attrs: &[Attribute]) -> bool {
     attrs.iter().any(|attr| attr.path().is_ident("lazy"))
 }
 
+/// 提取 refresh_scope 标记
+fn extract_refresh_scope(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident("refresh_scope"))
+}
+
 /// 提取 init 方法名
 fn extract_init(attrs: &[Attribute]) -> Option<String> {
     for attr in attrs {
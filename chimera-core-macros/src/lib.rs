@@ -5,6 +5,7 @@ mod component_impl;
 mod component_attr;
 mod configuration_impl;
 mod configuration_attr;
+mod auto_configuration_attr;
 mod bean_impl;
 mod bean_post_processor_impl;
 mod bean_factory_post_processor_impl;
@@ -23,13 +24,14 @@ use proc_macro_error::proc_macro_error;
 /// #[component(name = "userService")]  // 可选：指定bean名称（完整形式）
 /// #[scope("singleton")]   // 可选：指定作用域 (singleton/prototype)
 /// #[lazy]                 // 可选：延迟初始化
+/// #[refresh_scope]        // 可选：配置变更后在下次访问时重建
 /// #[init]                 // 可选：初始化回调（默认调用 init 方法）
 /// #[init("custom_init")]  // 可选：自定义初始化方法名
 /// #[destroy]              // 可选：销毁回调（默认调用 destroy 方法）
 /// #[destroy("cleanup")]   // 可选：自定义销毁方法名
 /// #[event_listener]       // 可选：自动注册为EventListener
 /// ```
-#[proc_macro_derive(Component, attributes(component, scope, lazy, autowired, value, init, destroy, event_listener))]
+#[proc_macro_derive(Component, attributes(component, scope, lazy, refresh_scope, autowired, value, init, destroy, event_listener))]
 pub fn derive_component(input: TokenStream) -> TokenStream {
     component_impl::derive_component_impl(input)
 }
@@ -142,6 +144,38 @@ pub fn lazy(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
 
+/// RefreshScope 属性宏
+///
+/// 用于标记 Bean 方法处于刷新作用域：配置热加载后，Bean 会在下次访问时按最新配置重建
+///
+/// **注意**：此宏只是一个标记，真正的处理由 `#[configuration]` 属性宏完成
+/// 必须在 Configuration 的 impl 块上添加 `#[configuration]` 属性
+///
+/// # 用法
+///
+/// ```ignore
+/// #[derive(Configuration)]
+/// pub struct AppConfig {
+///     #[autowired]
+///     environment: Arc<Environment>,
+/// }
+///
+/// #[configuration]
+/// impl AppConfig {
+///     /// 限流配置变更后重建
+///     #[bean]
+///     #[refresh_scope]
+///     pub fn rate_limiter(&self) -> RateLimiter {
+///         RateLimiter::new(self.environment.get_i64_or("app.rate-limit", 100))
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn refresh_scope(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    // RefreshScope 宏只是一个标记，实际处理由 configuration 属性宏完成
+    item
+}
+
 /// Init 属性宏
 ///
 /// 用于指定 Bean 的初始化回调方法
@@ -274,6 +308,48 @@ pub fn configuration(attr: TokenStream, item: TokenStream) -> TokenStream {
     configuration_attr::configuration_impl(attr, item)
 }
 
+/// 自动配置属性宏
+///
+/// 用于自动配置类的 impl 块。自动配置类在用户组件和 `#[bean]` 方法注册之后处理，
+/// 类和 `#[bean]` 方法上的条件不满足时跳过，用于提供可被用户覆盖的默认 Bean
+///
+/// # 参数
+///
+/// - `after = "Name"` / `after = [A, B]` - 在指定自动配置之后处理
+/// - `before = "Name"` / `before = [A, B]` - 在指定自动配置之前处理
+///
+/// # 条件
+///
+/// 条件属性写在 `#[auto_configuration]` 之后（类级别）或 `#[bean]` 方法上：
+///
+/// - `#[conditional_on_property(name = "...", having_value = "...", match_if_missing = true)]`
+/// - `#[conditional_on_bean("beanName")]` / `#[conditional_on_bean(Type)]`
+/// - `#[conditional_on_missing_bean]` - 默认使用 `#[bean]` 方法的返回类型，也可以指定名称或类型
+///
+/// # 用法
+///
+/// ```ignore
+/// #[derive(Configuration)]
+/// pub struct CacheAutoConfiguration {
+///     #[autowired]
+///     environment: Arc<Environment>,
+/// }
+///
+/// #[auto_configuration(after = "MetricsAutoConfiguration")]
+/// #[conditional_on_property(name = "cache.enabled", having_value = "true", match_if_missing = true)]
+/// impl CacheAutoConfiguration {
+///     #[bean("cacheManager")]
+///     #[conditional_on_missing_bean]
+///     pub fn cache_manager(&self) -> CacheManager {
+///         CacheManager::from_environment(&self.environment)
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_configuration(attr: TokenStream, item: TokenStream) -> TokenStream {
+    auto_configuration_attr::auto_configuration_impl(attr, item)
+}
+
 /// Configuration派生宏
 ///
 /// 用于标记配置类，配置类是特殊的 Component，用于包含 `#[bean]` 工厂方法
@@ -310,9 +386,9 @@ pub fn configuration(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// - Configuration 本身也是一个 Component，会被自动注册到容器
 /// - 支持 `#[component("name")]` 指定配置类的 bean 名称
-/// - 支持 `#[scope]`, `#[lazy]`, `#[init]`, `#[destroy]` 等 Component 属性
+/// - 支持 `#[scope]`, `#[lazy]`, `#[refresh_scope]`, `#[init]`, `#[destroy]` 等 Component 属性
 /// - 其中的 `#[bean]` 方法会在 `scan_bean_methods()` 时被扫描和注册
-#[proc_macro_derive(Configuration, attributes(component, scope, lazy, autowired, value, init, destroy))]
+#[proc_macro_derive(Configuration, attributes(component, scope, lazy, refresh_scope, autowired, value, init, destroy))]
 pub fn derive_configuration(input: TokenStream) -> TokenStream {
     configuration_impl::derive_configuration_impl(input)
 }
@@ -433,7 +509,7 @@ pub fn derive_smart_initializing_singleton(input: TokenStream) -> TokenStream {
 ///
 /// # Component trait 保留的方法名
 ///
-/// - `bean_name()`, `scope()`, `lazy()`, `dependencies()`
+/// - `bean_name()`, `scope()`, `lazy()`, `refresh_scope()`, `dependencies()`
 /// - `init_callback()`, `destroy_callback()`
 /// - `is_event_listener()`, `as_event_listener()`
 /// - `create_from_context()`, `register()`
@@ -1,17 +1,65 @@
-use crate::{ApplicationContext, Result};
-use crate::config::{TomlPropertySource, EnvironmentPropertySource};
-use crate::event::ApplicationStartedEvent;
+use crate::{ApplicationContext, Container, Result};
+use crate::availability::ReadinessState;
+use crate::component::{Component, ComponentRegistrar, ComponentScan};
+use crate::config::{
+    ConfigTreePropertySource, ConfigValue, Environment, EnvironmentPropertySource, JsonPropertySource,
+    MapPropertySource, PropertySource, RandomValuePropertySource, SystemPropertySource,
+    TomlPropertySource,
+};
+use crate::event::{
+    ApplicationBeansInitializedEvent, ApplicationContextPreparedEvent,
+    ApplicationEnvironmentPreparedEvent, ApplicationEventMulticaster, ApplicationFailedEvent,
+    ApplicationReadyEvent, ApplicationStartedEvent, ApplicationStartingEvent, Event, EventListener,
+    FollowUpEvents, MethodEventListener, SimpleApplicationEventMulticaster,
+};
 use crate::logging::LoggingConfig;
-use crate::plugin::{PluginRegistry, load_plugins};
+use crate::outbox::{EventOutbox, FileOutboxStore, RetryPolicy};
+use crate::plugin::{PluginRegistry, PluginReport, load_plugins};
+use crate::reload::ConfigReloader;
+use crate::shutdown::{shutdown_signal, ShutdownHandle};
+use crate::crypto::{AesGcmDecryptor, PropertyDecryptor};
+use crate::diagnostics::analyze_failure;
+use crate::constants::{CONFIG_IMPORT, LOCAL_SERVER_ADDRESS};
+use crate::profiles::expand_profile_groups;
 use anyhow::Context;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::path::Path;
 
+/// 复制错误链中每一层的信息
+///
+/// anyhow::Error 无法克隆，副本不能向下转型为具体的错误类型
+fn copy_error(error: &anyhow::Error) -> anyhow::Error {
+    let mut messages: Vec<String> = error.chain().map(|cause| cause.to_string()).collect();
+    let mut copy = anyhow::Error::msg(messages.pop().unwrap_or_default());
+    while let Some(message) = messages.pop() {
+        copy = copy.context(message);
+    }
+    copy
+}
+
 /// 正在运行的应用
 ///
 /// 包装 ApplicationContext 并提供额外的生命周期管理方法
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// // 在测试中启动应用（配置 server.port = 0 由系统分配端口）
+/// let app = ChimeraApplication::new().start().await?;
+/// app.wait_until_ready().await;
+///
+/// let addr = app.local_server_address().expect("web server not started");
+/// // ... 向 addr 发送请求 ...
+///
+/// app.shutdown().await?;
+/// ```
 pub struct RunningApplication {
     context: Arc<ApplicationContext>,
+    plugin_registry: Arc<PluginRegistry>,
+    keep_alive: bool,
+    shutdown_started: AtomicBool,
 }
 
 impl RunningApplication {
@@ -25,9 +73,88 @@ impl RunningApplication {
         self.context
     }
 
-    /// 手动触发关闭
-    pub fn shutdown(self) -> Result<()> {
-        self.context.shutdown().map_err(|e| e.into())
+    /// 已加载的插件报告
+    pub fn plugin_report(&self) -> PluginReport {
+        self.plugin_registry.report()
+    }
+
+    /// 是否存在需要保持应用运行的插件（如 Web 服务器）
+    pub fn is_keep_alive(&self) -> bool {
+        self.keep_alive
+    }
+
+    /// 获取停机句柄，调用 `shutdown()` 以编程方式触发优雅停机
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.context.shutdown_handle()
+    }
+
+    /// 当前就绪状态
+    pub fn readiness_state(&self) -> ReadinessState {
+        self.context.availability().readiness_state()
+    }
+
+    /// 应用是否可以接收流量
+    pub fn is_ready(&self) -> bool {
+        self.context.availability().is_ready()
+    }
+
+    /// 等待应用就绪，返回 false 表示应用在就绪之前就开始停机
+    pub async fn wait_until_ready(&self) -> bool {
+        self.context.availability().wait_until_ready().await
+    }
+
+    /// Web 服务器实际监听的地址（未启动 Web 服务器时为 None）
+    pub fn local_server_address(&self) -> Option<SocketAddr> {
+        self.context
+            .environment()
+            .get_string(LOCAL_SERVER_ADDRESS)
+            .and_then(|address| address.parse().ok())
+    }
+
+    /// Web 服务器实际监听的端口（配置 `server.port = 0` 时为系统分配的端口）
+    pub fn local_server_port(&self) -> Option<u16> {
+        self.local_server_address().map(|address| address.port())
+    }
+
+    /// 等待进程停机信号（SIGTERM / SIGINT）或 `ShutdownHandle` 触发停机，然后执行优雅停机
+    pub async fn wait_for_shutdown(&self) -> Result<()> {
+        let handle = self.shutdown_handle();
+        tracing::info!("Graceful shutdown enabled (SIGTERM or Ctrl+C to shutdown)");
+
+        tokio::select! {
+            signal = shutdown_signal() => {
+                tracing::info!("Received shutdown signal ({}), initiating graceful shutdown", signal);
+            }
+            _ = handle.wait() => {
+                tracing::info!("Shutdown requested, initiating graceful shutdown");
+            }
+        }
+
+        self.shutdown().await
+    }
+
+    /// 优雅停机：停止接收流量、关闭插件，最后关闭应用上下文
+    ///
+    /// 等待进行中请求的时间由各插件自行限制（Web 服务器受 `chimera.lifecycle.shutdown-timeout` 限制），
+    /// 单个插件关闭失败不影响其余插件和上下文的关闭
+    ///
+    /// 重复调用时只有第一次生效
+    pub async fn shutdown(&self) -> Result<()> {
+        if self.shutdown_started.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        // 通知各组件停止接收新的工作
+        self.context.set_readiness_state(ReadinessState::RefusingTraffic);
+        self.context.shutdown_handle().shutdown();
+
+        // 先关闭插件（Web 服务器在此等待进行中的请求完成）
+        if let Err(e) = self.plugin_registry.shutdown_all(&self.context).await {
+            tracing::error!("Error during plugin shutdown: {}", e);
+        }
+
+        // 再关闭应用上下文
+        self.context.shutdown()
     }
 }
 
@@ -50,6 +177,12 @@ pub struct ChimeraApplication {
     /// 激活的 profiles
     profiles: Vec<String>,
 
+    /// 内联配置（`key=value` 形式）
+    properties: Vec<String>,
+
+    /// 环境变量前缀
+    env_prefix: String,
+
     /// 是否显示 banner
     show_banner: bool,
 
@@ -62,8 +195,20 @@ pub struct ChimeraApplication {
     /// 自定义 shutdown hooks
     shutdown_hooks: Vec<Box<dyn Fn() -> Result<()> + Send + Sync>>,
 
+    /// 自定义配置值解密器
+    decryptors: Vec<Arc<dyn PropertyDecryptor>>,
+
     /// 插件注册表
     plugin_registry: PluginRegistry,
+
+    /// 早期事件监听器（在 ApplicationContext 创建之前注册）
+    listeners: Vec<Arc<dyn EventListener>>,
+
+    /// 组件扫描设置
+    component_scan: ComponentScan,
+
+    /// 显式注册的组件
+    components: Vec<ComponentRegistrar>,
 }
 
 impl ChimeraApplication {
@@ -75,11 +220,17 @@ impl ChimeraApplication {
         Self {
             config_files: Vec::new(), // 初始为空，将在 run 时根据规则查找
             profiles: Vec::new(),
+            properties: Vec::new(),
+            env_prefix: crate::constants::ENV_PREFIX.to_string(),
             show_banner: true,
             logging_config: None,
             initializers: Vec::new(),
             shutdown_hooks: Vec::new(),
+            decryptors: Vec::new(),
             plugin_registry: load_plugins(), // 自动加载所有插件
+            listeners: Vec::new(),
+            component_scan: ComponentScan::new(),
+            components: Vec::new(),
         }
     }
 
@@ -101,6 +252,27 @@ impl ChimeraApplication {
         self
     }
 
+    /// 添加内联配置，每项为 `key=value` 形式
+    ///
+    /// 内联配置的优先级高于配置文件和环境变量，适合在测试中覆盖配置，例如 `["server.port=0"]`
+    pub fn properties<I, S>(mut self, properties: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.properties.extend(properties.into_iter().map(Into::into));
+        self
+    }
+
+    /// 设置环境变量前缀（默认 `CHIMERA_`）
+    ///
+    /// 例如设置为 `MYAPP` 后，`MYAPP_SERVER_PORT` 对应 `server.port`，
+    /// `MYAPP_PROFILES_ACTIVE` 用于激活 profiles，`MYAPP_APPLICATION_JSON` 用于传入 JSON 配置
+    pub fn env_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.env_prefix = prefix.into();
+        self
+    }
+
     /// 设置是否显示 banner
     pub fn banner(mut self, show: bool) -> Self {
         self.show_banner = show;
@@ -135,13 +307,172 @@ impl ChimeraApplication {
         self
     }
 
+    /// 添加配置值解密器
+    ///
+    /// 用于解密 `{cipher}` 开头的配置值，内置的 AES-256-GCM 解密器会在配置了密钥时自动注册
+    pub fn decryptor<D>(mut self, decryptor: D) -> Self
+    where
+        D: PropertyDecryptor + 'static,
+    {
+        self.decryptors.push(Arc::new(decryptor));
+        self
+    }
+
+    /// 显式注册组件（不依赖组件扫描）
+    pub fn with_component<T: Component>(mut self) -> Self {
+        self.components.push(|context| context.register_component::<T>());
+        self
+    }
+
+    /// 显式注册配置类（不依赖组件扫描），其 `#[bean]` 方法在组件扫描之后注册
+    pub fn with_configuration<T: Component>(mut self) -> Self {
+        self.components.push(|context| context.register_configuration::<T>());
+        self
+    }
+
+    /// 添加组件扫描过滤器，参数为组件的 Bean 名称，返回 false 时扫描跳过该组件
+    pub fn scan_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.component_scan.add_filter(Arc::new(filter));
+        self
+    }
+
+    /// 设置是否启用全局组件扫描（默认启用）
+    ///
+    /// 禁用后只注册 `with_component` / `with_configuration` 指定的组件、插件注册的 Bean
+    /// 以及初始化器中手动注册的 Bean
+    pub fn component_scan(mut self, enabled: bool) -> Self {
+        self.component_scan.set_enabled(enabled);
+        self
+    }
+
+    /// 设置是否处理自动配置（默认处理）
+    ///
+    /// 自动配置不受 `component_scan` 和 `scan_filter` 影响，禁用后不注册任何自动配置提供的默认 Bean
+    pub fn auto_configuration(mut self, enabled: bool) -> Self {
+        self.component_scan.set_auto_configuration_enabled(enabled);
+        self
+    }
+
+    /// 设置是否加载插件（默认加载所有已注册的插件）
+    ///
+    /// 禁用后不加载任何插件（包括 Web 服务器），适合只需要核心容器的测试
+    pub fn plugins(mut self, enabled: bool) -> Self {
+        self.plugin_registry = if enabled { load_plugins() } else { PluginRegistry::new() };
+        self
+    }
+
+    /// 添加早期事件监听器
+    ///
+    /// 早期监听器在 ApplicationContext 创建之前注册，可以收到 ApplicationStartingEvent、
+    /// ApplicationEnvironmentPreparedEvent 等启动阶段的事件，上下文创建后会同时注册到上下文中
+    pub fn listener(mut self, listener: Arc<dyn EventListener>) -> Self {
+        self.listeners.push(listener);
+        self
+    }
+
+    /// 以闭包添加指定事件类型的早期事件监听器
+    pub fn on<E, R, F>(self, handler: F) -> Self
+    where
+        E: Event,
+        R: FollowUpEvents,
+        F: Fn(&E) -> R + Send + Sync + 'static,
+    {
+        let name = format!("on<{}>", std::any::type_name::<E>());
+        let listener = MethodEventListener::<E>::new(name, move |event: &E| {
+            handler(event).into_follow_up_events()
+        });
+        self.listener(Arc::new(listener))
+    }
+
     /// 运行应用
+    ///
+    /// 存在需要保持运行的插件（如 Web 服务器）时，阻塞直到收到 SIGTERM / SIGINT
+    /// 或通过 `ShutdownHandle` 触发停机，完成优雅停机后返回
     pub async fn run(self) -> Result<RunningApplication> {
+        let application = self.start().await?;
+        if application.keep_alive {
+            tracing::info!("Application will keep running (has keep-alive plugins)");
+            application.wait_for_shutdown().await?;
+        }
+        Ok(application)
+    }
+
+    /// 启动应用，完成启动后立即返回而不阻塞
+    ///
+    /// 启动过程中依次发布 ApplicationStartingEvent、ApplicationEnvironmentPreparedEvent、
+    /// ApplicationContextPreparedEvent、ApplicationBeansInitializedEvent、ApplicationStartedEvent、
+    /// ApplicationReadyEvent，任一阶段失败时发布 ApplicationFailedEvent，关闭已经启动的插件
+    /// 和应用上下文（执行 shutdown hooks），然后返回原始错误。
+    /// 返回的 `RunningApplication` 可用于获取监听地址、等待就绪或停机，适合在测试中启动 Web 应用
+    pub async fn start(mut self) -> Result<RunningApplication> {
+        // 上下文创建之前，早期监听器通过独立的多播器接收事件
+        let early_multicaster = SimpleApplicationEventMulticaster::new();
+        for listener in &self.listeners {
+            early_multicaster.add_listener(Arc::clone(listener));
+        }
+
+        let mut plugin_registry = std::mem::take(&mut self.plugin_registry);
+        let mut prepared_context = None;
+        match self.launch(&early_multicaster, &mut plugin_registry, &mut prepared_context).await {
+            Ok(application) => Ok(application),
+            Err(e) => {
+                let analysis = analyze_failure(&e, prepared_context.as_ref());
+                match &analysis {
+                    Some(analysis) => tracing::error!("\n\n{}\n", analysis),
+                    None => tracing::error!("Application run failed: {:#}", e),
+                }
+
+                // 监听器收到错误的副本，保留具体类型的原始错误返回给调用方
+                let event = Arc::new(
+                    ApplicationFailedEvent::new(Arc::new(copy_error(&e))).with_analysis(analysis),
+                );
+                let published = match &prepared_context {
+                    Some(context) => context.publish_event_and_wait(event).await,
+                    None => early_multicaster.multicast_event_and_wait(event).await,
+                };
+                if let Err(listener_error) = published {
+                    tracing::error!("ApplicationFailedEvent listener failed: {:#}", listener_error);
+                }
+
+                // 关闭已经启动的插件（例如已经绑定端口的 Web 服务器）和应用上下文
+                if let Some(context) = &prepared_context {
+                    context.shutdown_handle().shutdown();
+                    if let Err(shutdown_error) = plugin_registry.shutdown_all(context).await {
+                        tracing::error!("Error during plugin shutdown: {}", shutdown_error);
+                    }
+                    if let Err(shutdown_error) = context.shutdown() {
+                        tracing::error!("Error during context shutdown: {}", shutdown_error);
+                    }
+                }
+
+                Err(e)
+            }
+        }
+    }
+
+    /// 执行启动流程，上下文创建后写入 `prepared_context` 以便失败时发布事件并关闭上下文，
+    /// 启动成功时 `plugin_registry` 移交给返回的 `RunningApplication`
+    async fn launch(
+        mut self,
+        early_multicaster: &SimpleApplicationEventMulticaster,
+        plugin_registry: &mut PluginRegistry,
+        prepared_context: &mut Option<Arc<ApplicationContext>>,
+    ) -> Result<RunningApplication> {
         use crate::constants::*;
 
         // 初始化日志系统
         let logging_config = self.logging_config.clone().unwrap_or_else(LoggingConfig::from_env);
-        logging_config.init()?;
+        if tracing::dispatcher::has_been_set() {
+            // 同一进程中多次启动应用（例如测试）时沿用已有的日志订阅者
+            tracing::debug!("Logging already initialized, skipping");
+        } else {
+            logging_config.init()?;
+        }
+
+        early_multicaster.multicast_event(Arc::new(ApplicationStartingEvent::new()));
 
         // 记录启动开始时间
         let start_time = std::time::Instant::now();
@@ -169,10 +500,24 @@ impl ChimeraApplication {
             }
         }
 
+        // 添加随机值和系统信息配置源（优先级最低，用于 ${random.*}、${hostname} 等占位符）
+        temp_builder = temp_builder
+            .add_property_source(Box::new(RandomValuePropertySource::new()))
+            .add_property_source(Box::new(SystemPropertySource::new()));
+
         // 添加环境变量配置源（优先级最高）
-        temp_builder = temp_builder.add_property_source(Box::new(
-            EnvironmentPropertySource::new()
-        ));
+        let env_source = EnvironmentPropertySource::with_prefix(&self.env_prefix);
+        let env_prefix = env_source.prefix().to_string();
+        temp_builder = temp_builder.add_property_source(Box::new(env_source));
+        if let Some(json_source) = JsonPropertySource::from_env(&env_prefix)? {
+            temp_builder = temp_builder.add_property_source(Box::new(json_source));
+        }
+        if let Some(inline_source) = self.inline_property_source()? {
+            temp_builder = temp_builder.add_property_source(Box::new(inline_source));
+        }
+
+        // 在读取任何配置之前注册解密器
+        self.register_decryptors(&temp_builder, &env_prefix)?;
 
         // 构建临时 context 仅用于读取配置
         let temp_context = temp_builder.build()?;
@@ -196,7 +541,8 @@ impl ChimeraApplication {
         let mut active_profiles = Vec::new();
 
         // 1. 先从环境变量读取（最高优先级）
-        if let Ok(profiles_str) = std::env::var(ENV_PROFILES_ACTIVE) {
+        let profiles_env = format!("{}PROFILES_ACTIVE", env_prefix);
+        if let Ok(profiles_str) = std::env::var(&profiles_env) {
             active_profiles = profiles_str.split(',')
                 .map(|s| s.trim().to_string())
                 .filter(|s| !s.is_empty())
@@ -218,6 +564,9 @@ impl ChimeraApplication {
             tracing::debug!("Profiles from code: {:?}", active_profiles);
         }
 
+        // 展开 profile 组（chimera.profiles.group.<name>），包括导入的文件和 profile 配置文件中定义的组
+        let active_profiles = self.resolve_profile_groups(temp_context.environment(), &active_profiles, &env_prefix)?;
+
         if !active_profiles.is_empty() {
             tracing::info!("Active profiles: {:?}", active_profiles);
         } else {
@@ -229,24 +578,97 @@ impl ChimeraApplication {
             .async_events(async_events);
 
         // 加载配置文件（按优先级：default -> profile specific -> environment）
-        self.load_configurations(&mut builder, &active_profiles)?;
+        let loaded_configs = self.load_configurations(&mut builder, &active_profiles, ConfigLoadState::default())?;
+
+        // 添加随机值和系统信息配置源
+        builder = builder
+            .add_property_source(Box::new(RandomValuePropertySource::new()))
+            .add_property_source(Box::new(SystemPropertySource::new()));
 
         // 添加环境变量配置源（优先级最高）
         builder = builder.add_property_source(Box::new(
-            EnvironmentPropertySource::new()
+            EnvironmentPropertySource::with_prefix(&env_prefix)
         ));
-        tracing::debug!("Environment variable prefix: {}", ENV_PREFIX);
+        if let Some(json_source) = JsonPropertySource::from_env(&env_prefix)? {
+            tracing::info!("Loaded JSON configuration from {}", json_source.name());
+            builder = builder.add_property_source(Box::new(json_source));
+        }
+        if let Some(inline_source) = self.inline_property_source()? {
+            tracing::debug!("Loaded {} inline properties", self.properties.len());
+            builder = builder.add_property_source(Box::new(inline_source));
+        }
+        tracing::debug!("Environment variable prefix: {}", env_prefix);
+
+        // 注册解密器并校验所有加密值，无法解密时终止启动
+        self.register_decryptors(&builder, &env_prefix)?;
+        builder
+            .environment()
+            .validate_encrypted_values()
+            .context("Invalid encrypted configuration")?;
 
         // 设置 profiles
-        builder = builder.set_active_profiles(active_profiles);
+        builder = builder.set_active_profiles(active_profiles.clone());
 
         // 构建 ApplicationContext
         let context = builder.build()?;
+        let component_scan = std::mem::take(&mut self.component_scan);
+        context.with_component_scan(|scan| *scan = component_scan);
         tracing::info!("ApplicationContext creating");
+        *prepared_context = Some(Arc::clone(&context));
+
+        // 注册在 ChimeraApplication 中配置的 shutdown hooks（后续阶段启动失败时同样会执行）
+        for hook in std::mem::take(&mut self.shutdown_hooks) {
+            context.register_shutdown_hook(hook);
+        }
+
+        // 早期监听器同时注册到上下文中，接收后续阶段的事件
+        for listener in &self.listeners {
+            context.register_listener(Arc::clone(listener));
+        }
+
+        // 解析插件（移除被禁用的插件、按依赖关系排序），并加入插件的默认配置
+        plugin_registry.resolve(context.environment())?;
+        plugin_registry.apply_default_properties(context.environment());
+        tracing::info!("{}", plugin_registry.report());
+
+        // 设置后续事件的最大链路深度和是否允许重复
+        if let Some(max_depth) = context.environment().get_i64(CONFIG_EVENTS_MAX_FOLLOW_UP_DEPTH) {
+            context
+                .event_publisher()
+                .multicaster()
+                .set_max_follow_up_depth(max_depth.max(0) as usize);
+        }
+        if let Some(allow) = context.environment().get_bool(CONFIG_EVENTS_ALLOW_REPEATED_FOLLOW_UPS) {
+            context.event_publisher().multicaster().set_allow_repeated_follow_ups(allow);
+        }
+
+        // 启用事件发件箱（注册为 Bean，插件启动后在后台重放未完成的事件）
+        let event_outbox = if context.environment().get_bool(CONFIG_EVENTS_OUTBOX_ENABLED).unwrap_or(false) {
+            let path = context
+                .environment()
+                .get_string(CONFIG_EVENTS_OUTBOX_PATH)
+                .unwrap_or_else(|| crate::outbox::DEFAULT_OUTBOX_PATH.to_string());
+            let store = FileOutboxStore::open(&path)
+                .with_context(|| format!("Failed to open event outbox: {}", path))?;
+            let outbox = EventOutbox::new(Arc::clone(context.event_publisher()), Arc::new(store))
+                .with_retry_policy(RetryPolicy::from_environment(context.environment()));
+
+            let bean = outbox.clone();
+            context.register_singleton("eventOutbox", move || Ok(bean.clone()))?;
+            tracing::info!("Event outbox enabled: {}", path);
+            Some(outbox)
+        } else {
+            None
+        };
 
         // 设置应用名称（使用从配置读取的名称）
         context.set_app_name(app_name.clone());
 
+        context.publish_event(Arc::new(ApplicationEnvironmentPreparedEvent::new(
+            Arc::clone(context.environment()),
+            active_profiles,
+        )));
+
         // 执行自定义初始化器（在扫描组件之前）
         for initializer in &self.initializers {
             initializer(&context)?;
@@ -254,20 +676,34 @@ impl ChimeraApplication {
 
         // 执行插件配置阶段
         tracing::info!("Configuring plugins");
-        self.plugin_registry.configure_all(&context)?;
+        plugin_registry.configure_all(&context)?;
+
+        context.publish_event(Arc::new(ApplicationContextPreparedEvent::new(Arc::clone(&context))));
 
         // 自动扫描并绑定 ConfigurationProperties
         tracing::info!("Scanning for @ConfigurationProperties annotated beans");
         context.scan_configuration_properties()?;
 
+        // 注册显式指定的组件
+        for register in &self.components {
+            register(&context)?;
+        }
+
         // 自动扫描组件
         tracing::info!("Scanning for @Component annotated beans");
         context.scan_components()?;
 
+        // 调用 BeanDefinitionRegistryPostProcessor（在创建任何 Bean 实例之前，包括配置类）
+        context.invoke_bean_definition_registry_post_processors()?;
+
         // 自动扫描并注册 @Bean 方法（需要在组件扫描之后，因为配置类本身是 Component）
         tracing::info!("Scanning for @Bean annotated methods");
         context.scan_bean_methods()?;
 
+        // 处理自动配置（需要在用户组件和 @Bean 方法之后，以便按条件跳过用户已定义的 Bean）
+        tracing::info!("Processing auto-configurations");
+        context.process_auto_configurations()?;
+
         // 自动扫描并注册 BeanFactoryPostProcessor（在 Bean 实例化之前）
         tracing::info!("Scanning for @BeanFactoryPostProcessor annotated processors");
         context.scan_bean_factory_post_processors();
@@ -284,6 +720,15 @@ impl ChimeraApplication {
         tracing::info!("Scanning for EventListener implementations");
         context.scan_event_listeners()?;
 
+        // 检查配置键（疑似拼写错误的未知键、已废弃的键）
+        if context.environment().get_bool(CONFIG_WARN_UNKNOWN_KEYS).unwrap_or(true) {
+            crate::metadata::report(context.environment());
+        }
+        if let Some(output) = context.environment().get_string(CONFIG_METADATA_OUTPUT) {
+            crate::metadata::write_json(&output)?;
+            tracing::info!("Configuration metadata written to: {}", output);
+        }
+
         // 验证依赖
         tracing::info!("Validating bean dependencies");
         context.validate_dependencies()?;
@@ -294,14 +739,55 @@ impl ChimeraApplication {
         context.initialize()?;
         tracing::info!("ApplicationContext initialized");
 
-        // 注册在 ChimeraApplication 中配置的 shutdown hooks
-        for hook in self.shutdown_hooks {
-            context.register_shutdown_hook(hook);
+        context.publish_event(Arc::new(ApplicationBeansInitializedEvent::new(
+            context.get_bean_names().len(),
+        )));
+
+        // 启用配置热加载
+        if context.environment().get_bool(CONFIG_RELOAD_ENABLED).unwrap_or(false) {
+            let debounce_ms = context
+                .environment()
+                .get_i64(CONFIG_RELOAD_DEBOUNCE_MS)
+                .map(|ms| ms.max(0) as u64)
+                .unwrap_or(crate::reload::DEFAULT_RELOAD_DEBOUNCE_MS);
+
+            let reloader = ConfigReloader::new(&context)
+                .debounce(std::time::Duration::from_millis(debounce_ms));
+            for (config_file, priority) in &loaded_configs.loaded {
+                reloader.watch_file(config_file, *priority);
+            }
+            for (tree_path, priority) in &loaded_configs.config_trees {
+                let tree = tree_path.clone();
+                let priority = *priority;
+                reloader.watch_directory(tree_path, Arc::new(move || {
+                    let source = ConfigTreePropertySource::from_directory(&tree)?.with_priority(priority);
+                    Ok(Box::new(source) as Box<dyn PropertySource>)
+                }));
+            }
+            reloader.start()?;
+
+            context.register_shutdown_hook(move || {
+                reloader.stop();
+                Ok(())
+            });
         }
 
         // 执行插件启动阶段
         tracing::info!("Starting plugins");
-        self.plugin_registry.startup_all(&context).await?;
+        plugin_registry.startup_all(&context).await?;
+
+        // 在后台重放上次运行遗留的未完成事件（不阻塞启动，本次发布的事件不会被重放），停机时取消尚未完成的重放
+        if let Some(outbox) = event_outbox {
+            let replay = tokio::spawn(async move {
+                if let Err(e) = outbox.replay().await {
+                    tracing::error!("Failed to replay event outbox: {:#}", e);
+                }
+            });
+            context.register_shutdown_hook(move || {
+                replay.abort();
+                Ok(())
+            });
+        }
 
         // 计算启动耗时（包含插件启动时间）
         let elapsed = start_time.elapsed();
@@ -316,58 +802,99 @@ impl ChimeraApplication {
         ));
         context.publish_event(event);
 
+        // 发布 ApplicationReadyEvent
+        context.publish_event(Arc::new(ApplicationReadyEvent::new(app_name.clone(), elapsed_ms)));
+        context.set_readiness_state(ReadinessState::AcceptingTraffic);
+
         // 检查是否有需要保持应用运行的插件
-        let needs_keep_alive = self.plugin_registry.has_keep_alive_plugin();
+        let keep_alive = plugin_registry.has_keep_alive_plugin();
+        if !keep_alive {
+            tracing::info!("Application started successfully (no keep-alive plugins, will exit after run)");
+        }
 
-        if needs_keep_alive {
-            tracing::info!("Application will keep running (has keep-alive plugins)");
+        Ok(RunningApplication {
+            context,
+            plugin_registry: Arc::new(std::mem::take(plugin_registry)),
+            keep_alive,
+            shutdown_started: AtomicBool::new(false),
+        })
+    }
 
-            // 设置优雅停机信号处理（Ctrl+C）
-            let context_for_signal = Arc::clone(&context);
-            let plugin_registry_for_signal = self.plugin_registry;
-            tokio::spawn(async move {
-                match tokio::signal::ctrl_c().await {
-                    Ok(()) => {
-                        tracing::info!(
-                            "Received shutdown signal (Ctrl+C), initiating graceful shutdown"
-                        );
-
-                        // 先关闭插件
-                        if let Err(e) = plugin_registry_for_signal
-                            .shutdown_all(&context_for_signal)
-                            .await
-                        {
-                            tracing::error!("Error during plugin shutdown: {}", e);
-                        }
-
-                        // 再关闭应用上下文
-                        if let Err(e) = context_for_signal.shutdown() {
-                            tracing::error!("Error during context shutdown: {}", e);
-                            std::process::exit(1);
-                        }
-                        std::process::exit(0);
-                    }
-                    Err(err) => {
-                        tracing::error!("Unable to listen for shutdown signal: {}", err);
-                    }
-                }
-            });
-            tracing::info!("Graceful shutdown hook registered (Ctrl+C to shutdown)");
+    /// 向构建中的 Environment 注册解密器：显式添加的解密器和按密钥配置创建的 AES-256-GCM 解密器
+    fn register_decryptors(&self, builder: &crate::context::ApplicationContextBuilder, env_prefix: &str) -> Result<()> {
+        let environment = builder.environment();
+        for decryptor in &self.decryptors {
+            environment.add_decryptor(Arc::clone(decryptor));
+        }
+        if let Some(decryptor) = AesGcmDecryptor::from_environment(environment, env_prefix)? {
+            environment.add_decryptor(Arc::new(decryptor));
+        }
+        Ok(())
+    }
 
-            // 阻塞直到收到关闭信号
-            let () = std::future::pending().await;
-        } else {
-            tracing::info!("Application started successfully (no keep-alive plugins, will exit after run)");
+    /// 构建内联配置源（优先级高于环境变量和 JSON 配置）
+    fn inline_property_source(&self) -> Result<Option<MapPropertySource>> {
+        if self.properties.is_empty() {
+            return Ok(None);
+        }
+
+        let mut source = MapPropertySource::new("inlineProperties").with_priority(INLINE_PROPERTIES_PRIORITY);
+        for property in &self.properties {
+            let (key, value) = property
+                .split_once('=')
+                .ok_or_else(|| anyhow::anyhow!("Invalid inline property '{}', expected key=value", property))?;
+            source = source.with_property(key.trim(), ConfigValue::String(value.trim().to_string()));
         }
+        Ok(Some(source))
+    }
 
-        Ok(RunningApplication { context })
+    /// 展开 profile 组
+    ///
+    /// 组可以定义在导入的文件或 profile 配置文件中，这些文件要在确定 profiles 之后才能加载：
+    /// 先用基础配置展开，再按展开结果加载全部配置文件重新展开，直到不再出现新的 profile。
+    /// 每一轮都在上一轮的结果上展开，profiles 只增不减，因此循环一定会结束
+    fn resolve_profile_groups(
+        &self,
+        environment: &Environment,
+        profiles: &[String],
+        env_prefix: &str,
+    ) -> Result<Vec<String>> {
+        let mut resolved = expand_profile_groups(environment, profiles);
+        loop {
+            let mut discovery = ApplicationContext::builder();
+            let state = ConfigLoadState {
+                quiet: true,
+                ..ConfigLoadState::default()
+            };
+            self.load_configurations(&mut discovery, &resolved, state)?;
+
+            // 环境变量、JSON 和内联配置中的组定义优先于配置文件
+            discovery = discovery.add_property_source(Box::new(EnvironmentPropertySource::with_prefix(env_prefix)));
+            if let Some(json_source) = JsonPropertySource::from_env(env_prefix)? {
+                discovery = discovery.add_property_source(Box::new(json_source));
+            }
+            if let Some(inline_source) = self.inline_property_source()? {
+                discovery = discovery.add_property_source(Box::new(inline_source));
+            }
+            self.register_decryptors(&discovery, env_prefix)?;
+
+            let expanded = expand_profile_groups(discovery.environment(), &resolved);
+            if expanded.len() == resolved.len() {
+                return Ok(resolved);
+            }
+            tracing::debug!("Profile groups in loaded configuration activated: {:?}", &expanded[resolved.len()..]);
+            resolved = expanded;
+        }
     }
 
     /// 加载配置文件
     ///
     /// 加载顺序（优先级从低到高）：
     /// 1. application.toml (default)
-    /// 2. application-{profile}.toml (profile specific)
+    /// 2. application.toml 通过 `chimera.config.import` 导入的文件或目录
+    /// 3. application-{profile}.toml (profile specific) 及其导入的文件
+    ///
+    /// 每个文件中匹配激活 profiles 的 `[chimera.profile.<表达式>]` 配置段会覆盖该文件的普通配置
     ///
     /// 配置文件查找顺序（类似Spring Boot）：
     /// - 如果用户手动指定了配置文件路径，则使用指定的路径
@@ -375,12 +902,16 @@ impl ChimeraApplication {
     ///   1. config/application.toml
     ///   2. application.toml
     ///
-    /// 后加载的配置会覆盖先加载的配置
+    /// 后加载的配置会覆盖先加载的配置，优先级按加载顺序从 0 依次递增，始终低于环境变量配置源
+    ///
+    /// 返回加载结果（成功加载的配置文件、配置树及其优先级）
     fn load_configurations(
         &self,
         builder: &mut crate::context::ApplicationContextBuilder,
         active_profiles: &[String],
-    ) -> Result<()> {
+        mut state: ConfigLoadState,
+    ) -> Result<ConfigLoadState> {
+
         // 确定要使用的配置文件列表
         let config_files = if self.config_files.is_empty() {
             // 用户未指定配置文件，使用默认查找规则
@@ -392,21 +923,20 @@ impl ChimeraApplication {
 
         // 1. 加载默认配置文件 (application.toml)
         for base_config in &config_files {
-            self.try_load_config_file(builder, base_config, 0)?;
+            self.try_load_config_file(builder, base_config, active_profiles, false, &mut state)?;
         }
 
         // 2. 加载 profile 特定配置文件
         // application-dev.toml, application-prod.toml, etc.
-        for (index, profile) in active_profiles.iter().enumerate() {
+        for profile in active_profiles {
             for base_config in &config_files {
                 // 从 application.toml 推导出 application-dev.toml
                 let profile_config = self.get_profile_config_path(base_config, profile);
-                // 优先级递增：profile 配置优先级高于默认配置
-                self.try_load_config_file(builder, &profile_config, 10 + index as i32)?;
+                self.try_load_config_file(builder, &profile_config, active_profiles, false, &mut state)?;
             }
         }
 
-        Ok(())
+        Ok(state)
     }
 
     /// 查找默认配置文件
@@ -446,30 +976,138 @@ impl ChimeraApplication {
     }
 
     /// 尝试加载配置文件
+    ///
+    /// `required` 为 true 时文件缺失或解析失败会返回错误（用于非 optional 的导入），
+    /// 否则仅记录日志。加载成功后会继续处理文件中的 `chimera.config.import`
     fn try_load_config_file(
         &self,
         builder: &mut crate::context::ApplicationContextBuilder,
         config_file: &str,
-        priority: i32,
+        active_profiles: &[String],
+        required: bool,
+        state: &mut ConfigLoadState,
     ) -> Result<()> {
-        if Path::new(config_file).exists() {
-            match TomlPropertySource::from_file(config_file) {
-                Ok(source) => {
-                    tracing::info!("Loaded configuration from: {} (priority: {})", config_file, priority);
-                    builder.add_property_source_mut(
-                        Box::new(source.with_priority(priority))
-                    );
-                }
-                Err(e) => {
+        let path = Path::new(config_file);
+        if !path.exists() {
+            if required {
+                anyhow::bail!("Imported configuration not found: {}", config_file);
+            }
+            tracing::debug!("Configuration file not found: {}", config_file);
+            return Ok(());
+        }
+
+        // 避免重复加载及循环导入
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        if !state.visited.insert(canonical) {
+            tracing::debug!("Configuration file already loaded: {}", config_file);
+            return Ok(());
+        }
+
+        let source = match TomlPropertySource::from_file(config_file) {
+            Ok(source) => source,
+            Err(e) if required => {
+                return Err(e.context(format!("Failed to load imported configuration: {}", config_file)));
+            }
+            Err(e) => {
+                if !state.quiet {
                     tracing::warn!("Failed to load {}: {}", config_file, e);
                 }
+                return Ok(());
+            }
+        };
+
+        let priority = state.allocate_priority(config_file)?;
+
+        let source = source
+            .with_active_profiles(active_profiles)
+            .with_priority(priority);
+        let imports = source
+            .get(CONFIG_IMPORT)
+            .and_then(|value| value.as_string_array())
+            .unwrap_or_default();
+
+        if !state.quiet {
+            tracing::info!("Loaded configuration from: {} (priority: {})", config_file, priority);
+        }
+        builder.add_property_source_mut(Box::new(source));
+        state.loaded.push((config_file.to_string(), priority));
+
+        // 处理导入，相对路径基于当前文件所在目录
+        let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        for import in imports {
+            let (optional, location) = match import.strip_prefix(OPTIONAL_IMPORT_PREFIX) {
+                Some(location) => (true, location.trim()),
+                None => (false, import.trim()),
+            };
+
+            if let Some(tree) = location.strip_prefix(CONFIG_TREE_IMPORT_PREFIX) {
+                let tree_path = base_dir.join(tree.trim());
+                self.try_load_config_tree(builder, &tree_path, optional, state)?;
+                continue;
+            }
+
+            let import_path = base_dir.join(location);
+
+            if import_path.is_dir() {
+                for file in Self::list_config_files(&import_path)? {
+                    self.try_load_config_file(builder, &file, active_profiles, true, state)?;
+                }
+            } else if optional && !import_path.exists() {
+                tracing::debug!("Optional configuration import not found: {}", import_path.display());
+            } else {
+                let file = import_path.to_string_lossy().to_string();
+                self.try_load_config_file(builder, &file, active_profiles, true, state)?;
             }
-        } else {
-            tracing::debug!("Configuration file not found: {}", config_file);
         }
+
         Ok(())
     }
 
+    /// 加载配置树（`configtree:` 导入）
+    fn try_load_config_tree(
+        &self,
+        builder: &mut crate::context::ApplicationContextBuilder,
+        tree_path: &Path,
+        optional: bool,
+        state: &mut ConfigLoadState,
+    ) -> Result<()> {
+        if !tree_path.is_dir() {
+            if optional {
+                tracing::debug!("Optional config tree not found: {}", tree_path.display());
+                return Ok(());
+            }
+            anyhow::bail!("Imported config tree not found: {}", tree_path.display());
+        }
+
+        let canonical = tree_path.canonicalize().unwrap_or_else(|_| tree_path.to_path_buf());
+        if !state.visited.insert(canonical) {
+            return Ok(());
+        }
+
+        let priority = state.allocate_priority(&tree_path.display().to_string())?;
+
+        let source = ConfigTreePropertySource::from_directory(tree_path)?.with_priority(priority);
+        if !state.quiet {
+            tracing::info!("Loaded config tree from: {} (priority: {})", tree_path.display(), priority);
+        }
+        builder.add_property_source_mut(Box::new(source));
+        state.config_trees.push((tree_path.to_path_buf(), priority));
+
+        Ok(())
+    }
+
+    /// 列出目录下的 TOML 配置文件（按文件名排序，保证加载顺序确定）
+    fn list_config_files(dir: &Path) -> Result<Vec<String>> {
+        let mut files: Vec<String> = std::fs::read_dir(dir)
+            .with_context(|| format!("Failed to read configuration directory: {}", dir.display()))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "toml"))
+            .map(|p| p.to_string_lossy().to_string())
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
     /// 打印 banner
     fn print_banner(&self) {
         println!();
@@ -484,8 +1122,108 @@ impl ChimeraApplication {
     }
 }
 
+/// 配置文件加载状态
+#[derive(Default)]
+struct ConfigLoadState {
+    /// 下一个配置源的优先级（从 0 开始，不超过 MAX_CONFIG_FILE_PRIORITY）
+    next_priority: i32,
+
+    /// 已加载的文件（规范化路径），用于去重和防止循环导入
+    visited: std::collections::HashSet<std::path::PathBuf>,
+
+    /// 已加载的配置文件及其优先级
+    loaded: Vec<(String, i32)>,
+
+    /// 已加载的配置树目录及其优先级
+    config_trees: Vec<(std::path::PathBuf, i32)>,
+
+    /// 只用于展开 profile 组的预加载，不输出加载日志
+    quiet: bool,
+}
+
+impl ConfigLoadState {
+    /// 为下一个配置文件（或配置树）分配优先级
+    ///
+    /// 优先级限制在环境变量配置源之下，超出时返回错误，避免配置文件覆盖环境变量
+    fn allocate_priority(&mut self, location: &str) -> Result<i32> {
+        if self.next_priority > MAX_CONFIG_FILE_PRIORITY {
+            anyhow::bail!(
+                "Too many configuration files: cannot load {} (at most {} files and config trees are supported)",
+                location,
+                MAX_CONFIG_FILE_PRIORITY + 1
+            );
+        }
+        let priority = self.next_priority;
+        self.next_priority += 1;
+        Ok(priority)
+    }
+}
+
+/// 可选导入前缀，文件不存在时忽略
+const OPTIONAL_IMPORT_PREFIX: &str = "optional:";
+
+/// 配置树导入前缀
+const CONFIG_TREE_IMPORT_PREFIX: &str = "configtree:";
+
+/// 内联配置的优先级
+const INLINE_PROPERTIES_PRIORITY: i32 = 1000;
+
+/// 配置文件和配置树可使用的最高优先级（低于环境变量配置源的 100）
+const MAX_CONFIG_FILE_PRIORITY: i32 = 99;
+
 impl Default for ChimeraApplication {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_dir(files: &[(&str, &str)]) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("chimera-profiles-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for (name, content) in files {
+            std::fs::write(dir.join(name), content).unwrap();
+        }
+        dir
+    }
+
+    fn profiles(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn profile_groups_from_imported_and_profile_files_are_expanded() {
+        let dir = config_dir(&[
+            ("application.toml", "[chimera.config]\nimport = [\"groups.toml\"]\n"),
+            ("groups.toml", "[chimera.profiles.group]\nprod = [\"db-prod\"]\n"),
+            ("application-db-prod.toml", "[chimera.profiles.group]\ndb-prod = [\"pool\"]\n"),
+        ]);
+        let app = ChimeraApplication::new()
+            .config_file(dir.join("application.toml").to_string_lossy());
+
+        let resolved = app
+            .resolve_profile_groups(&Environment::new(), &profiles(&["prod", "dev"]), "CHIMERA_PROFILE_GROUPS_TEST_")
+            .unwrap();
+
+        assert_eq!(resolved, ["prod", "db-prod", "pool", "dev"]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn inline_profile_groups_override_configuration_files() {
+        let dir = config_dir(&[("application.toml", "[chimera.profiles.group]\nprod = [\"db-prod\"]\n")]);
+        let app = ChimeraApplication::new()
+            .config_file(dir.join("application.toml").to_string_lossy())
+            .properties(["chimera.profiles.group.prod=metrics"]);
+
+        let resolved = app
+            .resolve_profile_groups(&Environment::new(), &profiles(&["prod"]), "CHIMERA_PROFILE_GROUPS_TEST_")
+            .unwrap();
+
+        assert_eq!(resolved, ["prod", "metrics"]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
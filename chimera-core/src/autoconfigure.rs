@@ -0,0 +1,526 @@
+//! 自动配置
+//!
+//! 自动配置类是在用户组件和 `#[bean]` 方法注册之后才处理的配置类，用于提供可被用户覆盖的默认 Bean。
+//! 每个自动配置类及其 `#[bean]` 方法都可以声明条件，条件不满足时跳过：
+//!
+//! - `#[conditional_on_property(name = "...", having_value = "...", match_if_missing = ...)]` - 配置值匹配
+//! - `#[conditional_on_bean("name")]` / `#[conditional_on_bean(Type)]` - 容器中已存在指定 Bean
+//! - `#[conditional_on_missing_bean]` - 容器中不存在返回类型的 Bean（也可以指定名称或类型）
+//!
+//! 自动配置类之间可以通过 `after` / `before` 声明处理顺序，处理结果记录在
+//! `ConditionEvaluationReport` 中，便于排查某个自动配置为何生效或被跳过
+//!
+//! ## 使用示例
+//!
+//! ```ignore
+//! #[derive(Configuration)]
+//! pub struct CacheAutoConfiguration {
+//!     #[autowired]
+//!     environment: Arc<Environment>,
+//! }
+//!
+//! #[auto_configuration(after = "MetricsAutoConfiguration")]
+//! #[conditional_on_property(name = "cache.enabled", having_value = "true", match_if_missing = true)]
+//! impl CacheAutoConfiguration {
+//!     #[bean("cacheManager")]
+//!     #[conditional_on_missing_bean]
+//!     pub fn cache_manager(&self) -> CacheManager {
+//!         CacheManager::from_environment(&self.environment)
+//!     }
+//! }
+//! ```
+
+use crate::bean::BeanMethodRegistrar;
+use crate::bean_factory::ListableBeanFactory;
+use crate::config::ConfigValue;
+use crate::context::{ApplicationContext, Container};
+use crate::Result;
+use anyhow::bail;
+use serde::Serialize;
+use std::any::TypeId;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+/// Bean 选择器（按名称或类型）
+pub enum BeanSelector {
+    /// 按 Bean 名称
+    Name(&'static str),
+    /// 按 Bean 类型
+    Type {
+        type_id: fn() -> TypeId,
+        type_name: &'static str,
+    },
+}
+
+impl BeanSelector {
+    /// 查找匹配的 Bean 名称
+    fn find(&self, context: &ApplicationContext) -> Vec<String> {
+        match self {
+            BeanSelector::Name(name) => {
+                if context.contains_bean(name) {
+                    vec![name.to_string()]
+                } else {
+                    Vec::new()
+                }
+            }
+            BeanSelector::Type { type_id, .. } => {
+                let mut names = context.get_bean_factory().get_bean_names_for_type(type_id());
+                names.sort();
+                names
+            }
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            BeanSelector::Name(name) => format!("name: {}", name),
+            BeanSelector::Type { type_name, .. } => format!("type: {}", type_name),
+        }
+    }
+}
+
+/// 自动配置条件
+pub enum Condition {
+    /// 配置值条件
+    ///
+    /// 未设置 `having_value` 时，配置存在且不为 "false" 即匹配
+    OnProperty {
+        name: &'static str,
+        having_value: Option<&'static str>,
+        match_if_missing: bool,
+    },
+    /// 容器中存在指定 Bean
+    OnBean(BeanSelector),
+    /// 容器中不存在指定 Bean
+    OnMissingBean(BeanSelector),
+}
+
+impl Condition {
+    /// 评估条件
+    pub fn evaluate(&self, context: &ApplicationContext) -> ConditionOutcome {
+        match self {
+            Condition::OnProperty { name, having_value, match_if_missing } => {
+                let condition = match having_value {
+                    Some(expected) => format!("#[conditional_on_property] ({}={})", name, expected),
+                    None => format!("#[conditional_on_property] ({})", name),
+                };
+                let (matched, message) = match context.environment().get(name) {
+                    None if *match_if_missing => (true, format!("property '{}' is not set (match if missing)", name)),
+                    None => (false, format!("property '{}' is not set", name)),
+                    Some(value) => {
+                        let actual = property_value_to_string(&value);
+                        let matched = match having_value {
+                            Some(expected) => actual.eq_ignore_ascii_case(expected),
+                            None => !actual.eq_ignore_ascii_case("false"),
+                        };
+                        let verb = if matched { "has" } else { "has unexpected" };
+                        (matched, format!("property '{}' {} value '{}'", name, verb, actual))
+                    }
+                };
+                ConditionOutcome { condition, matched, message }
+            }
+            Condition::OnBean(selector) => {
+                let found = selector.find(context);
+                ConditionOutcome {
+                    condition: format!("#[conditional_on_bean] ({})", selector.describe()),
+                    matched: !found.is_empty(),
+                    message: if found.is_empty() {
+                        "did not find any beans".to_string()
+                    } else {
+                        format!("found bean(s) {}", quote_names(&found))
+                    },
+                }
+            }
+            Condition::OnMissingBean(selector) => {
+                let found = selector.find(context);
+                ConditionOutcome {
+                    condition: format!("#[conditional_on_missing_bean] ({})", selector.describe()),
+                    matched: found.is_empty(),
+                    message: if found.is_empty() {
+                        "did not find any beans".to_string()
+                    } else {
+                        format!("found bean(s) {}", quote_names(&found))
+                    },
+                }
+            }
+        }
+    }
+}
+
+fn property_value_to_string(value: &ConfigValue) -> String {
+    match value {
+        ConfigValue::String(s) => s.clone(),
+        ConfigValue::Int(i) => i.to_string(),
+        ConfigValue::Float(f) => f.to_string(),
+        ConfigValue::Bool(b) => b.to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+fn quote_names(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| format!("'{}'", name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 自动配置类中的 `#[bean]` 方法
+pub struct AutoConfigurationBean {
+    /// Bean 名称
+    pub bean_name: &'static str,
+    /// Bean 条件
+    pub conditions: &'static [Condition],
+    /// 注册函数
+    pub registrar: BeanMethodRegistrar,
+}
+
+/// 自动配置类注册信息
+///
+/// 由 `#[auto_configuration]` 宏生成并通过 inventory 提交
+pub struct AutoConfigurationRegistry {
+    /// 自动配置类名称（类型名）
+    pub name: &'static str,
+    /// 需要在这些自动配置之后处理
+    pub after: &'static [&'static str],
+    /// 需要在这些自动配置之前处理
+    pub before: &'static [&'static str],
+    /// 类级别条件
+    pub conditions: &'static [Condition],
+    /// 配置类的 Bean 名称
+    pub class_bean_name: fn() -> &'static str,
+    /// 将配置类注册到容器
+    pub register_class: fn(&Arc<ApplicationContext>) -> Result<()>,
+    /// `#[bean]` 方法
+    pub beans: &'static [AutoConfigurationBean],
+}
+
+inventory::collect!(AutoConfigurationRegistry);
+
+/// 单个条件的评估结果
+#[derive(Debug, Clone, Serialize)]
+pub struct ConditionOutcome {
+    /// 条件描述
+    pub condition: String,
+    /// 是否匹配
+    pub matched: bool,
+    /// 匹配或不匹配的原因
+    pub message: String,
+}
+
+/// `#[bean]` 方法的评估结果
+#[derive(Debug, Clone, Serialize)]
+pub struct BeanConditionOutcome {
+    /// Bean 名称
+    pub bean_name: String,
+    /// 是否注册
+    pub matched: bool,
+    /// 各条件的评估结果
+    pub conditions: Vec<ConditionOutcome>,
+}
+
+/// 自动配置类的评估结果
+#[derive(Debug, Clone, Serialize)]
+pub struct AutoConfigurationOutcome {
+    /// 自动配置类名称
+    pub name: String,
+    /// 类级别条件是否全部匹配
+    pub matched: bool,
+    /// 类级别条件的评估结果
+    pub conditions: Vec<ConditionOutcome>,
+    /// `#[bean]` 方法的评估结果（类级别条件不匹配时为空）
+    pub beans: Vec<BeanConditionOutcome>,
+}
+
+/// 条件评估报告
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConditionEvaluationReport {
+    /// 按处理顺序排列的自动配置评估结果
+    pub auto_configurations: Vec<AutoConfigurationOutcome>,
+}
+
+impl ConditionEvaluationReport {
+    /// 生效的自动配置
+    pub fn positive_matches(&self) -> impl Iterator<Item = &AutoConfigurationOutcome> {
+        self.auto_configurations.iter().filter(|outcome| outcome.matched)
+    }
+
+    /// 被跳过的自动配置
+    pub fn negative_matches(&self) -> impl Iterator<Item = &AutoConfigurationOutcome> {
+        self.auto_configurations.iter().filter(|outcome| !outcome.matched)
+    }
+}
+
+impl fmt::Display for ConditionEvaluationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_conditions(f: &mut fmt::Formatter<'_>, conditions: &[ConditionOutcome], indent: &str) -> fmt::Result {
+            if conditions.is_empty() {
+                writeln!(f, "{}- no conditions", indent)?;
+            }
+            for outcome in conditions {
+                let status = if outcome.matched { "matched" } else { "did not match" };
+                writeln!(f, "{}- {} {}: {}", indent, outcome.condition, status, outcome.message)?;
+            }
+            Ok(())
+        }
+
+        writeln!(f)?;
+        writeln!(f, "============================")?;
+        writeln!(f, "CONDITIONS EVALUATION REPORT")?;
+        writeln!(f, "============================")?;
+        writeln!(f)?;
+        writeln!(f, "Positive matches:")?;
+        writeln!(f, "-----------------")?;
+        for outcome in self.positive_matches() {
+            writeln!(f, "   {} matched:", outcome.name)?;
+            write_conditions(f, &outcome.conditions, "      ")?;
+            for bean in &outcome.beans {
+                let status = if bean.matched { "registered" } else { "skipped" };
+                writeln!(f, "      {}#{} {}:", outcome.name, bean.bean_name, status)?;
+                write_conditions(f, &bean.conditions, "         ")?;
+            }
+        }
+        writeln!(f)?;
+        writeln!(f, "Negative matches:")?;
+        writeln!(f, "-----------------")?;
+        for outcome in self.negative_matches() {
+            writeln!(f, "   {}:", outcome.name)?;
+            write_conditions(f, &outcome.conditions, "      ")?;
+        }
+        Ok(())
+    }
+}
+
+/// 按 after / before 对自动配置拓扑排序，没有顺序约束的按名称排列
+fn sort_auto_configurations(
+    registries: Vec<&'static AutoConfigurationRegistry>,
+) -> Result<Vec<&'static AutoConfigurationRegistry>> {
+    let index_by_name: HashMap<&str, usize> = registries
+        .iter()
+        .enumerate()
+        .map(|(index, registry)| (registry.name, index))
+        .collect();
+
+    // edges[a] 包含必须排在 a 之后的自动配置
+    let mut edges: Vec<HashSet<usize>> = vec![HashSet::new(); registries.len()];
+    let mut in_degree = vec![0usize; registries.len()];
+    let mut add_edge = |from: usize, to: usize| {
+        if edges[from].insert(to) {
+            in_degree[to] += 1;
+        }
+    };
+
+    for (index, registry) in registries.iter().enumerate() {
+        for name in registry.after {
+            if let Some(&other) = index_by_name.get(name) {
+                add_edge(other, index);
+            }
+        }
+        for name in registry.before {
+            if let Some(&other) = index_by_name.get(name) {
+                add_edge(index, other);
+            }
+        }
+    }
+
+    let mut ready: BinaryHeap<Reverse<(&str, usize)>> = registries
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| in_degree[*index] == 0)
+        .map(|(index, registry)| Reverse((registry.name, index)))
+        .collect();
+
+    let mut sorted = Vec::with_capacity(registries.len());
+    while let Some(Reverse((_, index))) = ready.pop() {
+        sorted.push(registries[index]);
+        for &next in &edges[index] {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                ready.push(Reverse((registries[next].name, next)));
+            }
+        }
+    }
+
+    if sorted.len() < registries.len() {
+        let mut cycle: Vec<&str> = registries
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| in_degree[*index] > 0)
+            .map(|(_, registry)| registry.name)
+            .collect();
+        cycle.sort_unstable();
+        bail!("Circular auto-configuration ordering detected among: {}", cycle.join(", "));
+    }
+
+    Ok(sorted)
+}
+
+/// 自动配置类的 Bean 名称（组件扫描时跳过这些配置类，由自动配置按条件注册）
+pub(crate) fn auto_configuration_class_names() -> HashSet<&'static str> {
+    inventory::iter::<AutoConfigurationRegistry>
+        .into_iter()
+        .map(|registry| (registry.class_bean_name)())
+        .collect()
+}
+
+impl ApplicationContext {
+    /// 处理所有自动配置
+    ///
+    /// 应在用户组件和 `#[bean]` 方法注册之后调用，以便 `#[conditional_on_missing_bean]` 能看到用户定义的 Bean
+    ///
+    /// 自动配置不经过组件扫描过滤器，只能通过 `ChimeraApplication::auto_configuration(false)` 整体禁用
+    pub fn process_auto_configurations(self: &Arc<Self>) -> Result<()> {
+        if !self.component_scan().is_auto_configuration_enabled() {
+            tracing::info!("Auto-configuration disabled");
+            return Ok(());
+        }
+
+        let registries =
+            sort_auto_configurations(inventory::iter::<AutoConfigurationRegistry>.into_iter().collect())?;
+
+        tracing::info!("Processing {} auto-configuration(s)", registries.len());
+
+        let mut report = ConditionEvaluationReport::default();
+        for registry in registries {
+            let conditions: Vec<ConditionOutcome> = registry
+                .conditions
+                .iter()
+                .map(|condition| condition.evaluate(self))
+                .collect();
+            let matched = conditions.iter().all(|outcome| outcome.matched);
+
+            let mut beans = Vec::new();
+            if matched {
+                (registry.register_class)(self)?;
+                let class_bean_name = (registry.class_bean_name)();
+                // 生效的自动配置类按显式注册处理，其上的事件监听器和后处理器不受扫描过滤器影响
+                self.with_component_scan(|scan| scan.add_explicit(class_bean_name));
+                let instance = self.get_bean(class_bean_name).map_err(|e| {
+                    e.context(format!("Failed to create auto-configuration '{}'", registry.name))
+                })?;
+
+                for bean in registry.beans {
+                    let bean_conditions: Vec<ConditionOutcome> = bean
+                        .conditions
+                        .iter()
+                        .map(|condition| condition.evaluate(self))
+                        .collect();
+                    let bean_matched = bean_conditions.iter().all(|outcome| outcome.matched);
+
+                    if bean_matched {
+                        (bean.registrar)(self, Arc::clone(&instance))?;
+                        tracing::debug!(
+                            "Auto-configuration '{}' registered bean '{}'",
+                            registry.name,
+                            bean.bean_name
+                        );
+                    } else {
+                        tracing::debug!(
+                            "Auto-configuration '{}' skipped bean '{}'",
+                            registry.name,
+                            bean.bean_name
+                        );
+                    }
+
+                    beans.push(BeanConditionOutcome {
+                        bean_name: bean.bean_name.to_string(),
+                        matched: bean_matched,
+                        conditions: bean_conditions,
+                    });
+                }
+            } else {
+                tracing::debug!("Auto-configuration '{}' did not match", registry.name);
+            }
+
+            report.auto_configurations.push(AutoConfigurationOutcome {
+                name: registry.name.to_string(),
+                matched,
+                conditions,
+                beans,
+            });
+        }
+
+        tracing::info!(
+            "Auto-configuration completed: {} matched, {} skipped",
+            report.positive_matches().count(),
+            report.negative_matches().count()
+        );
+        tracing::debug!("{}", report);
+
+        self.set_condition_evaluation_report(report);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(
+        name: &'static str,
+        after: &'static [&'static str],
+        before: &'static [&'static str],
+    ) -> &'static AutoConfigurationRegistry {
+        Box::leak(Box::new(AutoConfigurationRegistry {
+            name,
+            after,
+            before,
+            conditions: &[],
+            class_bean_name: || "unused",
+            register_class: |_| Ok(()),
+            beans: &[],
+        }))
+    }
+
+    fn sort(registries: Vec<&'static AutoConfigurationRegistry>) -> Result<Vec<&'static str>> {
+        Ok(sort_auto_configurations(registries)?
+            .into_iter()
+            .map(|registry| registry.name)
+            .collect())
+    }
+
+    #[test]
+    fn unordered_configurations_are_sorted_by_name() {
+        let order = sort(vec![registry("C", &[], &[]), registry("A", &[], &[]), registry("B", &[], &[])]).unwrap();
+
+        assert_eq!(order, ["A", "B", "C"]);
+    }
+
+    #[test]
+    fn after_and_before_constraints_are_respected() {
+        let order = sort(vec![
+            registry("A", &["C"], &[]),
+            registry("B", &[], &[]),
+            registry("C", &[], &[]),
+            registry("D", &[], &["B"]),
+        ])
+        .unwrap();
+
+        assert_eq!(order, ["C", "A", "D", "B"]);
+    }
+
+    #[test]
+    fn constraints_on_unknown_configurations_are_ignored() {
+        let order = sort(vec![registry("B", &["Missing"], &[]), registry("A", &[], &["Other"])]).unwrap();
+
+        assert_eq!(order, ["A", "B"]);
+    }
+
+    #[test]
+    fn cycles_are_reported() {
+        let error = sort(vec![
+            registry("A", &["B"], &[]),
+            registry("B", &[], &[]),
+            registry("C", &["A"], &["B"]),
+            registry("D", &[], &[]),
+        ])
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Circular auto-configuration ordering detected among: A, B, C"
+        );
+    }
+}
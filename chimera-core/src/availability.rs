@@ -0,0 +1,128 @@
+//! 应用可用性
+//!
+//! 跟踪应用是否可以接收流量：启动完成（ApplicationReadyEvent 之后）进入 `AcceptingTraffic`，
+//! 开始优雅停机时进入 `RefusingTraffic`。状态变化时发布 `ReadinessStateChangedEvent`
+//!
+//! ## 使用示例
+//!
+//! ```ignore
+//! let app = ChimeraApplication::new().start().await?;
+//!
+//! // 等待应用可以接收请求
+//! app.wait_until_ready().await;
+//! ```
+
+use crate::event::Event;
+use std::any::Any;
+use std::time::SystemTime;
+use tokio::sync::watch;
+
+/// 就绪状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadinessState {
+    /// 正在启动
+    Starting,
+    /// 可以接收流量
+    AcceptingTraffic,
+    /// 拒绝接收流量（正在停机）
+    RefusingTraffic,
+}
+
+/// 应用可用性状态
+///
+/// 可以克隆并在多个位置持有，所有克隆共享同一状态
+#[derive(Clone)]
+pub struct ApplicationAvailability {
+    sender: watch::Sender<ReadinessState>,
+}
+
+impl ApplicationAvailability {
+    /// 创建新的可用性状态（初始为 `Starting`）
+    pub fn new() -> Self {
+        let (sender, _) = watch::channel(ReadinessState::Starting);
+        Self { sender }
+    }
+
+    /// 当前就绪状态
+    pub fn readiness_state(&self) -> ReadinessState {
+        *self.sender.borrow()
+    }
+
+    /// 是否可以接收流量
+    pub fn is_ready(&self) -> bool {
+        self.readiness_state() == ReadinessState::AcceptingTraffic
+    }
+
+    /// 更新就绪状态，返回状态是否发生变化
+    pub fn set_readiness_state(&self, state: ReadinessState) -> bool {
+        self.sender.send_if_modified(|current| {
+            if *current == state {
+                false
+            } else {
+                *current = state;
+                true
+            }
+        })
+    }
+
+    /// 等待应用进入 `AcceptingTraffic` 状态
+    ///
+    /// 返回 false 表示应用在就绪之前就开始停机
+    pub async fn wait_until_ready(&self) -> bool {
+        let mut receiver = self.sender.subscribe();
+        let ready = match receiver
+            .wait_for(|state| *state != ReadinessState::Starting)
+            .await
+        {
+            Ok(state) => *state == ReadinessState::AcceptingTraffic,
+            Err(_) => false,
+        };
+        ready
+    }
+}
+
+impl Default for ApplicationAvailability {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for ApplicationAvailability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApplicationAvailability")
+            .field("readiness_state", &self.readiness_state())
+            .finish()
+    }
+}
+
+/// 就绪状态变化事件
+#[derive(Debug, Clone)]
+pub struct ReadinessStateChangedEvent {
+    /// 新的就绪状态
+    pub state: ReadinessState,
+    /// 事件时间戳
+    pub timestamp: SystemTime,
+}
+
+impl ReadinessStateChangedEvent {
+    pub fn new(state: ReadinessState) -> Self {
+        Self {
+            state,
+            timestamp: SystemTime::now(),
+        }
+    }
+}
+
+impl Event for ReadinessStateChangedEvent {
+    fn event_name(&self) -> &str {
+        "ReadinessStateChangedEvent"
+    }
+
+    fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
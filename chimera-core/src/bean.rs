@@ -49,6 +49,9 @@ pub struct BeanDefinition {
     /// Bean 的依赖列表（用于静态依赖分析）
     pub dependencies: Vec<String>,
 
+    /// 是否处于刷新作用域（配置变更后在下次访问时重建，仅对单例有效）
+    pub refresh_scope: bool,
+
     /// 初始化回调（@PostConstruct / InitializingBean）
     pub init_callback: Option<InitCallback>,
 
@@ -68,6 +71,7 @@ impl BeanDefinition {
             factory: Box::new(factory),
             lazy: false,
             dependencies: Vec::new(),
+            refresh_scope: false,
             init_callback: None,
             destroy_callback: None,
         }
@@ -91,6 +95,12 @@ impl BeanDefinition {
         self
     }
 
+    /// 设置是否处于刷新作用域
+    pub fn with_refresh_scope(mut self, refresh_scope: bool) -> Self {
+        self.refresh_scope = refresh_scope;
+        self
+    }
+
     /// 设置初始化回调
     pub fn with_init<F>(mut self, init_fn: F) -> Self
     where
@@ -117,6 +127,7 @@ impl fmt::Debug for BeanDefinition {
             .field("scope", &self.scope)
             .field("lazy", &self.lazy)
             .field("dependencies", &self.dependencies)
+            .field("refresh_scope", &self.refresh_scope)
             .field("type_name", &self.factory.type_name())
             .finish()
     }
@@ -9,10 +9,11 @@ use parking_lot::RwLock;
 use anyhow::{Context, anyhow, bail};
 
 use crate::{
-    bean::BeanDefinition,
+    bean::{BeanDefinition, FactoryBean},
+    error::{BeanCreationError, NoSuchBeanError},
     Result,
     lifecycle::BeanPostProcessor,
-    utils::dependency::CreationTracker,
+    utils::dependency::{CreationTracker, DependencyValidationError},
 };
 
 /// BeanFactory - 最基础的容器接口
@@ -63,9 +64,12 @@ pub trait ConfigurableBeanFactory: BeanFactory {
     /// 检查是否包含指定的 Bean 定义
     fn contains_bean_definition(&self, name: &str) -> bool;
 
-    /// 移除 Bean 定义
+    /// 移除 Bean 定义（同时移除已创建的单例实例）
     fn remove_bean_definition(&self, name: &str) -> Result<()>;
 
+    /// 检查指定名称的单例是否已经创建
+    fn contains_singleton(&self, name: &str) -> bool;
+
     /// 修改 Bean 定义
     fn modify_bean_definition<F>(&self, name: &str, modifier: F) -> Result<()>
     where
@@ -150,7 +154,7 @@ impl DefaultListableBeanFactory {
 
         let definition = definitions
             .get(name)
-            .ok_or_else(|| anyhow!("Bean not found: {}", name))?;
+            .ok_or_else(|| NoSuchBeanError::Name(name.to_string()))?;
 
         // 检查循环依赖
         if self
@@ -163,11 +167,9 @@ impl DefaultListableBeanFactory {
                 .current_creating()
                 .map_err(|e| anyhow!("Failed to get creation chain: {}", e))?;
 
-            bail!(
-                "Circular dependency detected: {} -> {}",
-                creating_chain.join(" -> "),
-                name
-            );
+            let mut cycle = creating_chain;
+            cycle.push(name.to_string());
+            return Err(DependencyValidationError::CircularDependency { cycle }.into());
         }
 
         // 标记为正在创建
@@ -176,10 +178,10 @@ impl DefaultListableBeanFactory {
             .start_creating(name)
             .map_err(|e| anyhow!("Failed to start creation tracking: {}", e))?
         {
-            bail!(
-                "Circular dependency detected on '{}'",
-                name
-            );
+            return Err(DependencyValidationError::CircularDependency {
+                cycle: vec![name.to_string(), name.to_string()],
+            }
+            .into());
         }
 
         // 使用 RAII 模式确保在任何情况下都会清理标记
@@ -207,7 +209,7 @@ impl DefaultListableBeanFactory {
 
         // 1. 实例化 Bean（构造函数 + 依赖注入）
         let instance = definition.factory.create()
-            .context(format!("Failed to create bean '{}'", name))?;
+            .map_err(|e| BeanCreationError::new(name, e))?;
 
         let mut bean: Arc<dyn Any + Send + Sync> = Arc::from(instance);
 
@@ -264,6 +266,53 @@ impl DefaultListableBeanFactory {
     }
 }
 
+impl DefaultListableBeanFactory {
+    /// 刷新所有处于刷新作用域的单例 Bean
+    ///
+    /// 从单例缓存中移除这些 Bean（能获取到唯一引用时调用 destroy 回调），
+    /// 下次访问时会按最新配置重新创建。返回被刷新的 Bean 名称
+    ///
+    /// 注意：已经持有旧实例 `Arc` 的 Bean 不会自动更新，需要重新从容器获取
+    pub fn refresh_scoped_beans(&self) -> Vec<String> {
+        let names: Vec<String> = {
+            let definitions = self.definitions.read();
+            definitions
+                .iter()
+                .filter(|(_, def)| def.refresh_scope && def.scope == crate::Scope::Singleton)
+                .map(|(name, _)| name.clone())
+                .collect()
+        };
+
+        let mut refreshed = Vec::new();
+        for name in names {
+            let removed = self.singletons.write().remove(&name);
+            let Some(mut bean) = removed else {
+                continue;
+            };
+
+            let definitions = self.definitions.read();
+            if let Some(destroy_fn) = definitions.get(&name).and_then(|d| d.destroy_callback.as_ref()) {
+                match Arc::get_mut(&mut bean) {
+                    Some(bean_mut) => {
+                        if let Err(e) = destroy_fn(bean_mut) {
+                            tracing::warn!("Failed to destroy refresh-scoped bean '{}': {}", name, e);
+                        }
+                    }
+                    None => tracing::debug!(
+                        "Refresh-scoped bean '{}' still has active references, skipping destroy",
+                        name
+                    ),
+                }
+            }
+
+            tracing::debug!("Refresh-scoped bean '{}' evicted, will be recreated on next access", name);
+            refreshed.push(name);
+        }
+
+        refreshed
+    }
+}
+
 impl Default for DefaultListableBeanFactory {
     fn default() -> Self {
         Self::new()
@@ -280,7 +329,7 @@ impl BeanFactory for DefaultListableBeanFactory {
 
             let definition = definitions.get(name).ok_or_else(|| {
                 tracing::debug!("Bean '{}' not found in container", name);
-                anyhow!("Bean not found: {}", name)
+                NoSuchBeanError::Name(name.to_string())
             })?;
 
             definition.scope
@@ -358,7 +407,7 @@ impl BeanFactoryExt for DefaultListableBeanFactory {
                 bean.downcast::<T>()
                     .map_err(|_| anyhow!("Type mismatch: expected {}, found unknown", type_name))
             } else {
-                bail!("Bean not found: No bean found for type '{}'", type_name)
+                Err(NoSuchBeanError::Type(type_name.to_string()).into())
             }
         }
     }
@@ -392,7 +441,7 @@ impl ListableBeanFactory for DefaultListableBeanFactory {
         let definitions = self.definitions.read();
         definitions
             .iter()
-            .filter(|(_, def)| def.factory.type_id() == type_id)
+            .filter(|(_, def)| FactoryBean::type_id(def.factory.as_ref()) == type_id)
             .map(|(name, _)| name.clone())
             .collect()
     }
@@ -412,7 +461,8 @@ impl ConfigurableBeanFactory for DefaultListableBeanFactory {
             bail!("Cannot register bean definition: configuration is frozen");
         }
 
-        let type_id = definition.factory.type_id();
+        // 需要显式调用 FactoryBean::type_id，否则会解析为 Box 自身的 Any::type_id
+        let type_id = FactoryBean::type_id(definition.factory.as_ref());
         let type_name = definition.factory.type_name();
 
         tracing::trace!(
@@ -460,12 +510,23 @@ impl ConfigurableBeanFactory for DefaultListableBeanFactory {
         let mut definitions = self.definitions.write();
         definitions
             .remove(name)
-            .ok_or_else(|| anyhow!("Bean not found: {}", name))?;
+            .ok_or_else(|| NoSuchBeanError::Name(name.to_string()))?;
+        self.type_to_name.write().retain(|_, bean_name| bean_name != name);
+        drop(definitions);
+
+        // 已创建的实例不再对应任何定义，避免之后按名称获取到旧实例
+        if self.singletons.write().remove(name).is_some() {
+            tracing::debug!("Cached singleton evicted: '{}'", name);
+        }
 
         tracing::debug!("Bean definition removed: '{}'", name);
         Ok(())
     }
 
+    fn contains_singleton(&self, name: &str) -> bool {
+        self.singletons.read().contains_key(name)
+    }
+
     fn add_bean_post_processor(&self, processor: Arc<dyn BeanPostProcessor>) {
         let mut processors = self.bean_post_processors.write();
         processors.push(processor);
@@ -489,7 +550,7 @@ impl ConfigurableBeanFactory for DefaultListableBeanFactory {
             tracing::debug!("Bean definition '{}' modified successfully", name);
             Ok(())
         } else {
-            bail!("Bean not found: {}", name)
+            Err(NoSuchBeanError::Name(name.to_string()).into())
         }
     }
 
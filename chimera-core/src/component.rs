@@ -1,5 +1,6 @@
 use crate::{ApplicationContext, Result, Scope, Container};
 use crate::event::EventListener;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Component注册函数类型
@@ -35,6 +36,117 @@ pub struct EventListenerRegistry {
 
 inventory::collect!(EventListenerRegistry);
 
+/// 组件扫描过滤器，参数为组件的 Bean 名称，返回 false 时跳过该组件
+pub type ScanFilter = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
+/// 组件扫描设置
+///
+/// 决定 inventory 中收集到的哪些组件会被注册到容器：
+/// - 全局扫描启用时，注册所有通过过滤器的组件
+/// - 显式注册的组件（`with_component` / `with_configuration`）不受全局扫描开关和过滤器影响
+///
+/// 同样适用于 `#[bean]` 方法（按配置类的 Bean 名称）、事件监听器、BeanPostProcessor、
+/// BeanFactoryPostProcessor 和 SmartInitializingSingleton。
+/// `#[derive(ConfigurationProperties)]` 只读取配置，始终绑定
+///
+/// 自动配置不受全局扫描开关和过滤器影响，只由 `auto_configuration` 开关控制
+#[derive(Clone)]
+pub struct ComponentScan {
+    enabled: bool,
+    auto_configuration: bool,
+    filters: Vec<ScanFilter>,
+    explicit: HashSet<String>,
+    /// 显式注册的配置类：类型名 -> Bean 名称
+    configurations: HashMap<String, String>,
+}
+
+impl ComponentScan {
+    /// 创建默认设置（启用全局扫描，没有过滤器）
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            auto_configuration: true,
+            filters: Vec::new(),
+            explicit: HashSet::new(),
+            configurations: HashMap::new(),
+        }
+    }
+
+    /// 是否启用全局扫描
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// 启用或禁用全局扫描
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// 是否处理自动配置
+    pub fn is_auto_configuration_enabled(&self) -> bool {
+        self.auto_configuration
+    }
+
+    /// 启用或禁用自动配置
+    pub fn set_auto_configuration_enabled(&mut self, enabled: bool) {
+        self.auto_configuration = enabled;
+    }
+
+    /// 添加过滤器（多个过滤器需要同时通过）
+    pub fn add_filter(&mut self, filter: ScanFilter) {
+        self.filters.push(filter);
+    }
+
+    /// 记录显式注册的组件
+    pub(crate) fn add_explicit(&mut self, name: &str) {
+        self.explicit.insert(name.to_string());
+    }
+
+    /// 记录显式注册的配置类，其 `#[bean]` 方法在 `scan_bean_methods` 时注册
+    pub(crate) fn add_explicit_configuration(&mut self, type_name: &str, bean_name: &str) {
+        self.add_explicit(bean_name);
+        self.configurations.insert(type_name.to_string(), bean_name.to_string());
+    }
+
+    /// 显式注册的配置类的 Bean 名称
+    fn explicit_configuration(&self, type_name: &str) -> Option<&str> {
+        self.configurations.get(type_name).map(String::as_str)
+    }
+
+    /// 组件是否显式注册
+    pub fn is_explicit(&self, name: &str) -> bool {
+        self.explicit.contains(name)
+    }
+
+    /// 组件是否应由全局扫描注册（显式注册的组件返回 false，避免重复注册）
+    pub fn matches(&self, name: &str) -> bool {
+        self.enabled && !self.is_explicit(name) && self.filters.iter().all(|filter| filter(name))
+    }
+
+    /// 组件是否被选中（显式注册或由全局扫描注册）
+    pub fn includes(&self, name: &str) -> bool {
+        self.is_explicit(name) || self.matches(name)
+    }
+}
+
+impl Default for ComponentScan {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for ComponentScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentScan")
+            .field("enabled", &self.enabled)
+            .field("auto_configuration", &self.auto_configuration)
+            .field("filters", &self.filters.len())
+            .field("explicit", &self.explicit)
+            .field("configurations", &self.configurations)
+            .finish()
+    }
+}
+
 /// Component trait - 用于标记可以自动注册到容器的组件
 ///
 /// 通过 #[derive(Component)] 宏自动实现
@@ -68,6 +180,11 @@ pub trait Component: Sized + Send + Sync + 'static {
         false
     }
 
+    /// 是否处于刷新作用域（配置变更后在下次访问时重建）
+    fn refresh_scope() -> bool {
+        false
+    }
+
     /// 获取依赖的 bean 名称列表
     fn dependencies() -> Vec<String> {
         Vec::new()
@@ -108,6 +225,7 @@ pub trait Component: Sized + Send + Sync + 'static {
         let ctx = Arc::clone(context);
         let scope = Self::scope();
         let lazy = Self::lazy();
+        let refresh_scope = Self::refresh_scope();
         let dependencies = Self::dependencies();
 
         let mut definition = crate::BeanDefinition::new(
@@ -118,6 +236,7 @@ pub trait Component: Sized + Send + Sync + 'static {
         )
         .with_scope(scope)
         .with_lazy(lazy)
+        .with_refresh_scope(refresh_scope)
         .with_dependencies(dependencies);
 
         // 添加初始化回调
@@ -158,11 +277,21 @@ impl ApplicationContext {
     pub fn scan_components(self: &Arc<Self>) -> Result<()> {
         tracing::info!("Starting component scan for @Component annotated beans");
 
-        let components: Vec<_> = inventory::iter::<ComponentRegistry>().collect();
+        // 自动配置类由 process_auto_configurations 按条件注册
+        let auto_configurations = crate::autoconfigure::auto_configuration_class_names();
+        let scan = self.component_scan();
+        let components: Vec<_> = inventory::iter::<ComponentRegistry>()
+            .filter(|component| !auto_configurations.contains(component.name))
+            .filter(|component| scan.matches(component.name))
+            .collect();
         let total = components.len();
 
         if total == 0 {
-            tracing::warn!("No @Component annotated beans found in classpath");
+            if scan.is_enabled() {
+                tracing::warn!("No @Component annotated beans found in classpath");
+            } else {
+                tracing::info!("Global component scan is disabled");
+            }
             return Ok(());
         }
 
@@ -222,11 +351,14 @@ impl ApplicationContext {
 
     /// 自动扫描并注册EventListener
     ///
-    /// 在Bean初始化后调用，自动注册所有实现了EventListener的Component
+    /// 在Bean初始化后调用，自动注册所有实现了EventListener的Component以及 #[event_listener] 方法
     pub fn scan_event_listeners(self: &Arc<Self>) -> Result<()> {
-        tracing::info!("Starting event listener scan for @Component beans implementing EventListener");
+        tracing::info!("Starting event listener scan for @Component beans and #[event_listener] methods");
 
-        let listeners: Vec<_> = inventory::iter::<EventListenerRegistry>().collect();
+        let scan = self.component_scan();
+        let listeners: Vec<_> = inventory::iter::<EventListenerRegistry>()
+            .filter(|listener| scan.includes(listener.name))
+            .collect();
         let total = listeners.len();
 
         if total == 0 {
@@ -262,7 +394,14 @@ impl ApplicationContext {
     pub fn scan_bean_methods(self: &Arc<Self>) -> Result<()> {
         tracing::info!("Starting bean method scan for @Bean annotated methods");
 
-        let bean_methods: Vec<_> = inventory::iter::<crate::bean::BeanMethodRegistry>().collect();
+        // 显式注册的配置类不受全局扫描开关和过滤器影响
+        let scan = self.component_scan();
+        let bean_methods: Vec<_> = inventory::iter::<crate::bean::BeanMethodRegistry>()
+            .filter(|method| {
+                scan.explicit_configuration(short_type_name(method.config_type_name)).is_some()
+                    || scan.matches(&config_bean_name(method.config_type_name))
+            })
+            .collect();
         let total = bean_methods.len();
 
         if total == 0 {
@@ -273,7 +412,6 @@ impl ApplicationContext {
         tracing::info!("Found {} @Bean annotated method(s) to register", total);
 
         // 按配置类分组，以便为每个配置类创建一次实例
-        use std::collections::HashMap;
         let mut methods_by_config: HashMap<&str, Vec<_>> = HashMap::new();
 
         for method in bean_methods.iter() {
@@ -293,14 +431,14 @@ impl ApplicationContext {
 
             // 尝试从容器获取配置类实例
             // 配置类应该已经通过 #[derive(Component)] 注册
-            let config_instance = self.get_bean(config_type)
-                .or_else(|_| {
+            let config_instance = match scan.explicit_configuration(short_type_name(config_type)) {
+                // 显式注册的配置类使用其声明的 Bean 名称
+                Some(bean_name) => self.get_bean(bean_name),
+                None => self.get_bean(config_type).or_else(|_| {
                     // 如果按类型名获取失败，尝试按 camelCase 转换的名称获取
-                    let camel_name = crate::utils::naming::to_camel_case(
-                        config_type.split("::").last().unwrap_or(config_type)
-                    );
-                    self.get_bean(&camel_name)
-                })
+                    self.get_bean(&config_bean_name(config_type))
+                }),
+            }
                 .map_err(|e| {
                     anyhow::anyhow!(
                         format!(
@@ -336,4 +474,33 @@ impl ApplicationContext {
         tracing::info!("Bean method scan completed successfully, registered {} bean(s)", total);
         Ok(())
     }
+
+    /// 显式注册组件（不依赖组件扫描）
+    ///
+    /// 组件的事件监听器、BeanPostProcessor 等会在对应的扫描阶段被识别，全局扫描禁用时也是如此
+    pub fn register_component<T: Component>(self: &Arc<Self>) -> Result<()> {
+        self.with_component_scan(|scan| scan.add_explicit(T::bean_name()));
+        T::register(self)
+    }
+
+    /// 显式注册配置类（不依赖组件扫描）
+    ///
+    /// 配置类的 `#[bean]` 方法与扫描到的配置类一样在 `scan_bean_methods()` 时注册，
+    /// 以便配置类可以依赖之后注册或扫描到的组件
+    pub fn register_configuration<T: Component>(self: &Arc<Self>) -> Result<()> {
+        self.with_component_scan(|scan| {
+            scan.add_explicit_configuration(short_type_name(std::any::type_name::<T>()), T::bean_name())
+        });
+        T::register(self)
+    }
+}
+
+/// 去掉类型路径，只保留类型名
+fn short_type_name(type_name: &str) -> &str {
+    type_name.rsplit("::").next().unwrap_or(type_name).trim()
+}
+
+/// 根据 `#[configuration]` 记录的类型名推断配置类的 Bean 名称
+fn config_bean_name(config_type_name: &str) -> String {
+    crate::utils::naming::to_camel_case(short_type_name(config_type_name))
 }
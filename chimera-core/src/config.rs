@@ -1,11 +1,16 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fs;
 use std::path::Path;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 use anyhow::{Context, Result};
+use crate::crypto::{PropertyDecryptor, CIPHER_PREFIX, ENCRYPT_KEY_ENV_SUFFIX, ENCRYPT_KEY_FILE_ENV_SUFFIX};
+use crate::profiles::ProfileExpression;
+
+/// TOML 中按 profile 激活的配置段所在的表（`[chimera.profile.<表达式>]`）
+const PROFILE_SECTION_PATH: [&str; 2] = ["chimera", "profile"];
 
 /// 配置值类型
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ConfigValue {
     String(String),
     Int(i64),
@@ -43,6 +48,24 @@ impl ConfigValue {
         }
     }
 
+    /// 转换为字符串数组
+    ///
+    /// 数组取其中的字符串元素，字符串按逗号分隔
+    pub fn as_string_array(&self) -> Option<Vec<String>> {
+        match self {
+            ConfigValue::Array(arr) => {
+                Some(arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+            }
+            ConfigValue::String(s) => {
+                Some(s.split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect())
+            }
+            _ => None,
+        }
+    }
+
     /// 转换为布尔值
     pub fn as_bool(&self) -> Option<bool> {
         match self {
@@ -83,6 +106,9 @@ pub struct Environment {
 
     /// 当前激活的 profile
     active_profiles: RwLock<Vec<String>>,
+
+    /// 加密配置值解密器
+    decryptors: RwLock<Vec<Arc<dyn PropertyDecryptor>>>,
 }
 
 impl std::fmt::Debug for Environment {
@@ -100,6 +126,7 @@ impl Environment {
         Self {
             sources: RwLock::new(Vec::new()),
             active_profiles: RwLock::new(Vec::new()),
+            decryptors: RwLock::new(Vec::new()),
         }
     }
 
@@ -111,17 +138,249 @@ impl Environment {
         sources.sort_by(|a, b| b.priority().cmp(&a.priority()));
     }
 
+    /// 按名称原子替换配置源
+    ///
+    /// 如果不存在同名配置源则直接添加。返回替换前后 `get` 解析结果发生变化的配置键（已排序）：
+    /// 被更高优先级配置源覆盖的键不算变化，引用了变化配置的占位符所在的键算作变化
+    pub fn replace_property_source(&self, source: Box<dyn PropertySource>) -> Vec<String> {
+        let candidates = self.replacement_candidates(source.as_ref());
+        let before: Vec<Option<ConfigValue>> = candidates.iter().map(|key| self.get(key)).collect();
+
+        {
+            let mut sources = self.sources.write().unwrap();
+            match sources.iter().position(|s| s.name() == source.name()) {
+                Some(index) => sources[index] = source,
+                None => sources.push(source),
+            }
+            sources.sort_by_key(|s| std::cmp::Reverse(s.priority()));
+        }
+
+        candidates
+            .into_iter()
+            .zip(before)
+            .filter(|(key, before)| self.get(key) != *before)
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// 替换配置源时可能变化的配置键（已排序）
+    ///
+    /// 包括新旧配置源之间原始值不同的键，以及所有值中带占位符的键；
+    /// 引用 `random.*` 的键每次读取都不同，不参与比较
+    fn replacement_candidates(&self, source: &dyn PropertySource) -> Vec<String> {
+        let sources = self.sources.read().unwrap();
+
+        let mut candidates: BTreeSet<String> = match sources.iter().find(|s| s.name() == source.name()) {
+            Some(old) => source
+                .keys()
+                .into_iter()
+                .filter(|key| old.get(key) != source.get(key))
+                .chain(old.keys().into_iter().filter(|key| source.get(key).is_none()))
+                .collect(),
+            None => source.keys().into_iter().collect(),
+        };
+
+        for s in sources.iter().map(|s| s.as_ref()).chain(std::iter::once(source)) {
+            for key in s.keys() {
+                if let Some(ConfigValue::String(raw)) = s.get(&key) {
+                    if raw.contains("${") && !raw.contains("${random.") {
+                        candidates.insert(key);
+                    }
+                }
+            }
+        }
+
+        candidates.into_iter().collect()
+    }
+
+    /// 获取每个配置源包含的配置键（按优先级从高到低）
+    pub fn keys_by_source(&self) -> Vec<(String, Vec<String>)> {
+        self.sources
+            .read()
+            .unwrap()
+            .iter()
+            .map(|s| (s.name().to_string(), s.keys()))
+            .collect()
+    }
+
+    /// 获取所有配置源名称（按优先级从高到低）
+    pub fn property_source_names(&self) -> Vec<String> {
+        self.sources
+            .read()
+            .unwrap()
+            .iter()
+            .map(|s| s.name().to_string())
+            .collect()
+    }
+
+    /// 添加加密配置值解密器
+    pub fn add_decryptor(&self, decryptor: Arc<dyn PropertyDecryptor>) {
+        tracing::debug!("Registered property decryptor: {}", decryptor.name());
+        self.decryptors.write().unwrap().push(decryptor);
+    }
+
     /// 获取配置值
+    ///
+    /// - `{cipher}` 开头的值会通过已注册的解密器解密（启动时已校验所有加密值都能解密）
+    /// - 字符串中的 `${key}` / `${key:default}` 占位符会被解析为对应的配置值，
+    ///   例如 `${random.uuid}`、`${hostname}`、`${server.port:8080}`
     pub fn get(&self, key: &str) -> Option<ConfigValue> {
-        let sources = self.sources.read().unwrap();
-        for source in sources.iter() {
-            if let Some(value) = source.get(key) {
-                tracing::debug!("Config '{}' found in source '{}'", key, source.name());
-                return Some(value);
+        self.get_with_depth(key, 0)
+    }
+
+    /// 获取配置值（带占位符解析深度，防止循环引用）
+    fn get_with_depth(&self, key: &str, depth: usize) -> Option<ConfigValue> {
+        let found = {
+            let sources = self.sources.read().unwrap();
+            sources.iter().find_map(|source| {
+                source.get(key).inspect(|_| {
+                    tracing::debug!("Config '{}' found in source '{}'", key, source.name());
+                })
+            })
+        };
+
+        match found {
+            Some(value) => {
+                let value = self.decrypt_value(key, value)?;
+                self.resolve_value_placeholders(key, value, depth)
+            }
+            None => {
+                tracing::debug!("Config '{}' not found in any source", key);
+                None
             }
         }
-        tracing::debug!("Config '{}' not found in any source", key);
-        None
+    }
+
+    /// 解析字符串中的 `${...}` 占位符
+    ///
+    /// 无法解析且没有默认值的占位符会原样保留
+    pub fn resolve_placeholders(&self, text: &str) -> String {
+        self.resolve_placeholders_with_depth(text, 0)
+    }
+
+    fn resolve_value_placeholders(&self, key: &str, value: ConfigValue, depth: usize) -> Option<ConfigValue> {
+        match value {
+            ConfigValue::String(s) if s.contains(PLACEHOLDER_PREFIX) => {
+                if depth >= MAX_PLACEHOLDER_DEPTH {
+                    tracing::error!("Circular placeholder reference detected while resolving '{}'", key);
+                    return None;
+                }
+                Some(ConfigValue::String(self.resolve_placeholders_with_depth(&s, depth + 1)))
+            }
+            ConfigValue::Array(values) => values
+                .into_iter()
+                .map(|v| self.resolve_value_placeholders(key, v, depth))
+                .collect::<Option<Vec<_>>>()
+                .map(ConfigValue::Array),
+            other => Some(other),
+        }
+    }
+
+    fn resolve_placeholders_with_depth(&self, text: &str, depth: usize) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(start) = rest.find(PLACEHOLDER_PREFIX) {
+            result.push_str(&rest[..start]);
+            let after = &rest[start + PLACEHOLDER_PREFIX.len()..];
+
+            let end = match find_placeholder_end(after) {
+                Some(end) => end,
+                None => {
+                    // 未闭合的占位符，原样保留
+                    result.push_str(&rest[start..]);
+                    return result;
+                }
+            };
+
+            let placeholder = &after[..end];
+            let (name, default) = split_placeholder_default(placeholder);
+            let name = self.resolve_placeholders_with_depth(name, depth);
+
+            let resolved = self
+                .get_with_depth(&name, depth)
+                .and_then(|v| config_value_to_string(&v))
+                .or_else(|| default.map(|d| self.resolve_placeholders_with_depth(d, depth)));
+
+            match resolved {
+                Some(value) => result.push_str(&value),
+                None => {
+                    tracing::warn!("Could not resolve placeholder '${{{}}}'", placeholder);
+                    result.push_str(&rest[start..start + PLACEHOLDER_PREFIX.len() + end + 1]);
+                }
+            }
+
+            rest = &after[end + 1..];
+        }
+
+        result.push_str(rest);
+        result
+    }
+
+    /// 解密配置值（包括数组中的元素）
+    ///
+    /// 启动时已通过 `validate_encrypted_values` 校验所有加密值，这里的失败只会出现在运行时新增的配置源中
+    fn decrypt_value(&self, key: &str, value: ConfigValue) -> Option<ConfigValue> {
+        match self.try_decrypt_value(value) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                tracing::error!("Failed to decrypt config '{}': {:#}", key, e);
+                None
+            }
+        }
+    }
+
+    fn try_decrypt_value(&self, value: ConfigValue) -> Result<ConfigValue> {
+        match value {
+            ConfigValue::String(s) => match s.strip_prefix(CIPHER_PREFIX) {
+                Some(encrypted) => {
+                    let decryptors = self.decryptors.read().unwrap();
+                    let decryptor = decryptors
+                        .iter()
+                        .find(|d| d.can_decrypt(encrypted))
+                        .ok_or_else(|| anyhow::anyhow!("No decryptor available for encrypted value"))?;
+                    decryptor.decrypt(encrypted).map(ConfigValue::String)
+                }
+                None => Ok(ConfigValue::String(s)),
+            },
+            ConfigValue::Array(values) => values
+                .into_iter()
+                .map(|v| self.try_decrypt_value(v))
+                .collect::<Result<Vec<_>>>()
+                .map(ConfigValue::Array),
+            other => Ok(other),
+        }
+    }
+
+    /// 校验配置源中的所有加密值都能被已注册的解密器解密
+    ///
+    /// 无法解密的值会导致返回错误（列出所有失败的配置键），避免应用在缺少敏感配置时以默认值启动
+    pub fn validate_encrypted_values(&self) -> Result<()> {
+        let sources = self.sources.read().unwrap();
+        let failures: Vec<String> = sources
+            .iter()
+            .flat_map(|source| self.encrypted_value_failures(source.as_ref()))
+            .collect();
+        decryption_result(failures)
+    }
+
+    /// 校验单个配置源中的加密值（例如重新加载配置文件之前）
+    pub fn validate_source_encrypted_values(&self, source: &dyn PropertySource) -> Result<()> {
+        decryption_result(self.encrypted_value_failures(source))
+    }
+
+    fn encrypted_value_failures(&self, source: &dyn PropertySource) -> Vec<String> {
+        let mut keys = source.keys();
+        keys.sort();
+
+        keys.into_iter()
+            .filter_map(|key| {
+                let value = source.get(&key)?;
+                self.try_decrypt_value(value)
+                    .err()
+                    .map(|e| format!("'{}' in {}: {:#}", key, source.name(), e))
+            })
+            .collect()
     }
 
     /// 获取字符串配置
@@ -170,18 +429,7 @@ impl Environment {
     /// 1. TOML数组: key = ["a", "b", "c"]
     /// 2. 逗号分隔字符串: key = "a, b, c"
     pub fn get_string_array(&self, key: &str) -> Option<Vec<String>> {
-        match self.get(key)? {
-            ConfigValue::Array(arr) => {
-                Some(arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
-            }
-            ConfigValue::String(s) => {
-                Some(s.split(',')
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect())
-            }
-            _ => None,
-        }
+        self.get(key)?.as_string_array()
     }
 
     /// 设置激活的 profile
@@ -195,9 +443,17 @@ impl Environment {
         self.active_profiles.read().unwrap().clone()
     }
 
-    /// 检查是否包含指定的 profile
+    /// 检查激活的 profiles 是否匹配指定的 profile 表达式
+    ///
+    /// 支持 `prod`、`!prod`、`prod & !eu`、`prod | (staging & eu)` 等表达式
     pub fn accepts_profiles(&self, profile: &str) -> bool {
-        self.active_profiles.read().unwrap().contains(&profile.to_string())
+        match ProfileExpression::parse(profile) {
+            Ok(expression) => expression.matches(&self.active_profiles.read().unwrap()),
+            Err(e) => {
+                tracing::warn!("Invalid profile expression '{}': {}", profile, e);
+                false
+            }
+        }
     }
 }
 
@@ -207,42 +463,144 @@ impl Default for Environment {
     }
 }
 
+/// 将解密失败的配置键汇总为错误
+fn decryption_result(failures: Vec<String>) -> Result<()> {
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        anyhow::bail!("Failed to decrypt configuration values:\n  {}", failures.join("\n  "))
+    }
+}
+
 
 
 // ========== Property Sources ==========
 
 /// 环境变量配置源
+///
+/// 支持宽松绑定（relaxed binding），配置键与环境变量名按以下规则对应：
+/// - `.` 和 `-` 均映射为 `_`，`[n]` 映射为 `_n`
+/// - 环境变量名不区分大小写
+/// - 例如: `server.request-timeout` 可通过 `CHIMERA_SERVER_REQUEST_TIMEOUT`
+///   或 `CHIMERA_SERVER_REQUESTTIMEOUT` 设置
+///
+/// 列表可通过带索引的变量覆盖：`CHIMERA_APP_ALLOW_IP_LIST_0`、`CHIMERA_APP_ALLOW_IP_LIST_1` ...
+///
+/// 环境变量在创建配置源时读取一次，之后的修改不会生效；加密密钥变量（如 `CHIMERA_ENCRYPT_KEY`）不会读取
 pub struct EnvironmentPropertySource {
     prefix: String,
     priority: i32,
+
+    /// 带前缀的环境变量快照，键为去除前缀并转为大写的变量名
+    variables: HashMap<String, String>,
 }
 
 impl EnvironmentPropertySource {
     /// 创建环境变量配置源
     ///
-    /// 使用固定的 CHIMERA_ 前缀读取环境变量
+    /// 使用默认的 CHIMERA_ 前缀读取环境变量
     /// 例如: CHIMERA_DATABASE_URL 会被转换为 database.url 配置键
     pub fn new() -> Self {
+        Self::with_prefix(crate::constants::ENV_PREFIX)
+    }
+
+    /// 使用自定义前缀创建环境变量配置源
+    ///
+    /// 前缀会被规范化为大写并以 `_` 结尾，例如 `myapp` -> `MYAPP_`；
+    /// 空前缀表示直接读取不带前缀的环境变量
+    pub fn with_prefix(prefix: impl AsRef<str>) -> Self {
+        let prefix = normalize_env_prefix(prefix.as_ref());
+        let json_var = format!("{}{}", prefix, APPLICATION_JSON_SUFFIX);
+
+        let mut variables = HashMap::new();
+        for (name, value) in std::env::vars() {
+            if name.eq_ignore_ascii_case(&json_var) {
+                continue;
+            }
+            let Some(stripped) = strip_prefix_ignore_case(&name, &prefix) else {
+                continue;
+            };
+            // 仅大小写不同的同名变量，优先使用全大写的变量
+            let folded = stripped.to_uppercase();
+            // 加密密钥只由 PropertyDecryptor 直接读取，不作为配置暴露
+            if folded == ENCRYPT_KEY_ENV_SUFFIX || folded == ENCRYPT_KEY_FILE_ENV_SUFFIX {
+                continue;
+            }
+            if stripped == folded || !variables.contains_key(&folded) {
+                variables.insert(folded, value);
+            }
+        }
+
         Self {
-            prefix: crate::constants::ENV_PREFIX.to_string(),
+            prefix,
             priority: 100, // 环境变量优先级较高
+            variables,
         }
     }
 
-    /// 将环境变量名转换为配置键
-    /// 例如: CHIMERA_DATABASE_URL -> database.url
-    fn env_to_key(&self, env_key: &str) -> String {
-        if let Some(stripped) = env_key.strip_prefix(&self.prefix) {
-            stripped.to_lowercase().replace('_', ".")
-        } else {
-            env_key.to_lowercase().replace('_', ".")
+    /// 获取环境变量前缀
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// 将环境变量名（不含前缀，大写）转换为配置键
+    ///
+    /// 优先匹配已声明元数据的配置键（按 `key_to_env_candidates` 的宽松规则比较），
+    /// 例如 SERVER_REQUEST_TIMEOUT -> server.request-timeout；带索引的列表变量映射为列表本身的键。
+    /// 无法匹配时退化为小写并将 `_` 替换为 `.`
+    fn env_to_key(name: &str, known_keys: &[&str]) -> String {
+        let (base, indexed) = match name.rsplit_once('_') {
+            Some((base, index)) if !base.is_empty() && index.parse::<usize>().is_ok() => (base, true),
+            _ => (name, false),
+        };
+
+        let matches = |candidate: &str| {
+            known_keys
+                .iter()
+                .find(|key| Self::key_to_env_candidates(key).iter().any(|c| c == candidate))
+                .map(|key| key.to_string())
+        };
+
+        matches(name)
+            .or_else(|| indexed.then(|| matches(base)).flatten())
+            .unwrap_or_else(|| {
+                let name = if indexed { base } else { name };
+                name.to_lowercase().replace('_', ".")
+            })
+    }
+
+    /// 将配置键转换为候选的环境变量名（不含前缀）
+    ///
+    /// 例如: server.request-timeout -> [SERVER_REQUEST_TIMEOUT, SERVER_REQUESTTIMEOUT]
+    fn key_to_env_candidates(key: &str) -> Vec<String> {
+        let indexed = key.replace('[', ".").replace(']', "");
+        let underscored = indexed.replace(['.', '-'], "_").to_uppercase();
+        let dashless = indexed.replace('-', "").replace('.', "_").to_uppercase();
+
+        let mut candidates = vec![underscored];
+        if !candidates.contains(&dashless) {
+            candidates.push(dashless);
         }
+        candidates
     }
 
-    /// 将配置键转换为环境变量名
-    /// 例如: database.url -> APP_DATABASE_URL
-    fn key_to_env(&self, key: &str) -> String {
-        format!("{}{}", self.prefix, key.replace('.', "_").to_uppercase())
+    /// 读取环境变量（忽略大小写）
+    fn lookup(&self, name: &str) -> Option<&String> {
+        self.variables.get(name)
+    }
+
+    /// 读取带索引的列表变量，例如 NAME_0, NAME_1 ...
+    fn lookup_indexed(&self, name: &str) -> Option<ConfigValue> {
+        let mut values = Vec::new();
+        while let Some(value) = self.lookup(&format!("{}_{}", name, values.len())) {
+            values.push(ConfigValue::String(value.clone()));
+        }
+
+        if values.is_empty() {
+            None
+        } else {
+            Some(ConfigValue::Array(values))
+        }
     }
 }
 
@@ -252,17 +610,501 @@ impl PropertySource for EnvironmentPropertySource {
     }
 
     fn get(&self, key: &str) -> Option<ConfigValue> {
-        let env_key = self.key_to_env(key);
-        std::env::var(&env_key)
+        let candidates = Self::key_to_env_candidates(key);
+
+        candidates
+            .iter()
+            .find_map(|name| self.lookup(name))
+            .map(|value| ConfigValue::String(value.clone()))
+            .or_else(|| candidates.iter().find_map(|name| self.lookup_indexed(name)))
+    }
+
+    fn keys(&self) -> Vec<String> {
+        let metadata = crate::metadata::all();
+        let known_keys: Vec<&str> = metadata.iter().map(|m| m.key).collect();
+
+        let mut keys: Vec<String> = self
+            .variables
+            .keys()
+            .map(|name| Self::env_to_key(name, &known_keys))
+            .collect();
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+}
+
+/// JSON 配置变量名后缀（与前缀拼接，例如 CHIMERA_APPLICATION_JSON）
+const APPLICATION_JSON_SUFFIX: &str = "APPLICATION_JSON";
+
+/// 规范化环境变量前缀
+fn normalize_env_prefix(prefix: &str) -> String {
+    let prefix = prefix.trim().to_uppercase();
+    if prefix.is_empty() || prefix.ends_with('_') {
+        prefix
+    } else {
+        format!("{}_", prefix)
+    }
+}
+
+/// 忽略大小写地去除前缀
+fn strip_prefix_ignore_case<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    if value.len() >= prefix.len()
+        && value.is_char_boundary(prefix.len())
+        && value[..prefix.len()].eq_ignore_ascii_case(prefix)
+    {
+        Some(&value[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+/// 配置树配置源
+///
+/// 读取目录结构作为配置，适用于 Kubernetes secret/configmap 挂载的卷：
+/// - 文件名为配置键，文件内容为配置值（去除末尾换行）
+/// - 子目录映射为点分隔的键，例如 `db/password` -> `db.password`
+/// - 以 `.` 开头的文件和目录会被忽略（包括 Kubernetes 的 `..data` 等数据目录）
+/// - 非 UTF-8 的文件（如 keystore）会被跳过并记录警告
+///
+/// 可通过 `chimera.config.import = ["configtree:/etc/secrets/"]` 导入
+pub struct ConfigTreePropertySource {
+    name: String,
+    properties: HashMap<String, ConfigValue>,
+    priority: i32,
+}
+
+impl ConfigTreePropertySource {
+    /// 从目录加载配置树
+    pub fn from_directory(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.is_dir() {
+            anyhow::bail!("Config tree is not a directory: {}", path.display());
+        }
+
+        let mut properties = HashMap::new();
+        Self::read_tree(path, String::new(), &mut properties)?;
+
+        Ok(Self {
+            name: format!("configtree:{}", path.display()),
+            properties,
+            priority: 0,
+        })
+    }
+
+    /// 递归读取目录
+    fn read_tree(dir: &Path, prefix: String, result: &mut HashMap<String, ConfigValue>) -> Result<()> {
+        let entries = fs::read_dir(dir)
+            .with_context(|| format!("Failed to read config tree: {}", dir.display()))?;
+
+        for entry in entries {
+            let entry = entry?;
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            // Kubernetes 挂载卷中的 `..data`、`..2024_01_01_00_00_00.000` 等目录保存实际数据，
+            // 顶层的同名符号链接已指向其中的文件，跳过以免重复读取
+            if file_name.starts_with("..") {
+                continue;
+            }
+            // 其他隐藏文件
+            if file_name.starts_with('.') {
+                continue;
+            }
+
+            let key = if prefix.is_empty() {
+                file_name
+            } else {
+                format!("{}.{}", prefix, file_name)
+            };
+
+            // 使用 fs::metadata 以跟随符号链接
+            let entry_path = entry.path();
+            let metadata = fs::metadata(&entry_path)?;
+            if metadata.is_dir() {
+                Self::read_tree(&entry_path, key, result)?;
+            } else if metadata.is_file() {
+                let bytes = fs::read(&entry_path)
+                    .with_context(|| format!("Failed to read config tree file: {}", entry_path.display()))?;
+                // 二进制文件（如 keystore）不能作为配置值，跳过而不是终止启动
+                let content = match String::from_utf8(bytes) {
+                    Ok(content) => content,
+                    Err(_) => {
+                        tracing::warn!(
+                            "Skipping non UTF-8 config tree file: {}",
+                            entry_path.display()
+                        );
+                        continue;
+                    }
+                };
+                let value = content
+                    .strip_suffix('\n')
+                    .map(|v| v.strip_suffix('\r').unwrap_or(v))
+                    .unwrap_or(&content)
+                    .to_string();
+                result.insert(key, ConfigValue::String(value));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 设置优先级
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+impl PropertySource for ConfigTreePropertySource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get(&self, key: &str) -> Option<ConfigValue> {
+        self.properties.get(key).cloned()
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.properties.keys().cloned().collect()
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+}
+
+/// 占位符前缀
+const PLACEHOLDER_PREFIX: &str = "${";
+
+/// 占位符最大嵌套解析深度
+const MAX_PLACEHOLDER_DEPTH: usize = 16;
+
+/// 查找占位符的结束位置（支持嵌套的 `${...}`）
+fn find_placeholder_end(text: &str) -> Option<usize> {
+    let mut nesting = 0;
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'$' if bytes.get(i + 1) == Some(&b'{') => {
+                nesting += 1;
+                i += 1;
+            }
+            b'}' if nesting == 0 => return Some(i),
+            b'}' => nesting -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// 拆分占位符中的默认值，例如 `server.port:8080` -> (`server.port`, Some(`8080`))
+///
+/// 括号内的冒号不作为分隔符，嵌套占位符内的冒号同样忽略
+fn split_placeholder_default(placeholder: &str) -> (&str, Option<&str>) {
+    let mut depth = 0;
+    for (i, c) in placeholder.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ':' if depth == 0 => return (&placeholder[..i], Some(&placeholder[i + 1..])),
+            _ => {}
+        }
+    }
+    (placeholder, None)
+}
+
+/// 将标量配置值转换为字符串
+fn config_value_to_string(value: &ConfigValue) -> Option<String> {
+    match value {
+        ConfigValue::String(s) => Some(s.clone()),
+        ConfigValue::Int(i) => Some(i.to_string()),
+        ConfigValue::Float(f) => Some(f.to_string()),
+        ConfigValue::Bool(b) => Some(b.to_string()),
+        ConfigValue::Array(_) | ConfigValue::Object(_) => None,
+    }
+}
+
+/// 随机值配置源
+///
+/// 以 `random.` 开头的键每次读取都会生成新的随机值，通常通过占位符使用：
+/// - `${random.uuid}` - UUID v4
+/// - `${random.value}` - 32 位十六进制随机字符串
+/// - `${random.int}` / `${random.long}` - 随机整数
+/// - `${random.int(1,100)}` / `${random.long(1000,2000)}` - 指定范围 `[min, max)` 的随机整数
+/// - `${random.int(100)}` - `[0, max)` 范围的随机整数
+///
+/// 注意：每次解析都会生成新值，需要稳定值的场景应绑定到 Bean 字段后复用
+pub struct RandomValuePropertySource {
+    priority: i32,
+}
+
+impl RandomValuePropertySource {
+    /// 创建随机值配置源
+    pub fn new() -> Self {
+        Self { priority: -200 }
+    }
+
+    /// 设置优先级
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// 解析范围参数，例如 `(1,100)` 或 `[1,100]`
+    fn parse_range(args: &str) -> Option<(i64, i64)> {
+        let inner = args
+            .strip_prefix(['(', '['])?
+            .strip_suffix([')', ']'])?;
+
+        let mut parts = inner.split(',').map(|p| p.trim().parse::<i64>());
+        let first = parts.next()?.ok()?;
+        let range = match parts.next() {
+            Some(second) => (first, second.ok()?),
+            None => (0, first),
+        };
+
+        if parts.next().is_some() || range.0 >= range.1 {
+            return None;
+        }
+        Some(range)
+    }
+
+    fn random_int(args: &str, full_range: (i64, i64)) -> Option<ConfigValue> {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        if args.is_empty() {
+            return Some(ConfigValue::Int(rng.gen_range(full_range.0..=full_range.1)));
+        }
+
+        let (min, max) = Self::parse_range(args)?;
+        Some(ConfigValue::Int(rng.gen_range(min..max)))
+    }
+}
+
+impl Default for RandomValuePropertySource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PropertySource for RandomValuePropertySource {
+    fn name(&self) -> &str {
+        "random"
+    }
+
+    fn get(&self, key: &str) -> Option<ConfigValue> {
+        let kind = key.strip_prefix("random.")?;
+
+        if kind == "uuid" {
+            return Some(ConfigValue::String(uuid::Uuid::new_v4().to_string()));
+        }
+        if kind == "value" {
+            return Some(ConfigValue::String(uuid::Uuid::new_v4().simple().to_string()));
+        }
+        if let Some(args) = kind.strip_prefix("int") {
+            return Self::random_int(args, (i32::MIN as i64, i32::MAX as i64));
+        }
+        if let Some(args) = kind.strip_prefix("long") {
+            return Self::random_int(args, (i64::MIN, i64::MAX));
+        }
+
+        tracing::warn!("Unknown random property: {}", key);
+        None
+    }
+
+    fn keys(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+}
+
+/// 系统信息配置源
+///
+/// 提供以下配置键（优先级最低，可被配置文件覆盖）：
+/// - `hostname` - 主机名
+/// - `pid` - 当前进程 ID
+/// - `user.home` - 用户主目录
+/// - `user.name` - 当前用户名
+pub struct SystemPropertySource {
+    properties: HashMap<String, ConfigValue>,
+    priority: i32,
+}
+
+impl SystemPropertySource {
+    /// 创建系统信息配置源
+    pub fn new() -> Self {
+        let mut properties = HashMap::new();
+
+        if let Some(hostname) = Self::hostname() {
+            properties.insert("hostname".to_string(), ConfigValue::String(hostname));
+        }
+        properties.insert("pid".to_string(), ConfigValue::Int(std::process::id() as i64));
+        if let Some(home) = std::env::var("HOME").ok().or_else(|| std::env::var("USERPROFILE").ok()) {
+            properties.insert("user.home".to_string(), ConfigValue::String(home));
+        }
+        if let Some(user) = std::env::var("USER").ok().or_else(|| std::env::var("USERNAME").ok()) {
+            properties.insert("user.name".to_string(), ConfigValue::String(user));
+        }
+
+        Self {
+            properties,
+            priority: -300,
+        }
+    }
+
+    /// 设置优先级
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// 获取主机名
+    fn hostname() -> Option<String> {
+        std::env::var("HOSTNAME")
             .ok()
-            .map(|v| ConfigValue::String(v))
+            .or_else(|| std::env::var("COMPUTERNAME").ok())
+            .or_else(|| fs::read_to_string("/proc/sys/kernel/hostname").ok())
+            .or_else(|| fs::read_to_string("/etc/hostname").ok())
+            .map(|h| h.trim().to_string())
+            .filter(|h| !h.is_empty())
+    }
+}
+
+impl Default for SystemPropertySource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PropertySource for SystemPropertySource {
+    fn name(&self) -> &str {
+        "system"
+    }
+
+    fn get(&self, key: &str) -> Option<ConfigValue> {
+        self.properties.get(key).cloned()
     }
 
     fn keys(&self) -> Vec<String> {
-        std::env::vars()
-            .filter(|(k, _)| k.starts_with(&self.prefix))
-            .map(|(k, _)| self.env_to_key(&k))
-            .collect()
+        self.properties.keys().cloned().collect()
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+}
+
+/// JSON 配置源
+///
+/// 将 JSON 对象展平为配置键，常用于通过单个环境变量（如 `CHIMERA_APPLICATION_JSON`）
+/// 传入一组配置，优先级高于普通环境变量
+pub struct JsonPropertySource {
+    name: String,
+    properties: HashMap<String, ConfigValue>,
+    priority: i32,
+}
+
+impl JsonPropertySource {
+    /// 从字符串解析 JSON 配置
+    pub fn from_str(content: &str, name: impl Into<String>) -> Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(content)
+            .context("Failed to parse JSON")?;
+
+        if !value.is_object() {
+            anyhow::bail!("JSON configuration must be an object");
+        }
+
+        let mut properties = HashMap::new();
+        Self::flatten_json(&value, String::new(), &mut properties);
+
+        Ok(Self {
+            name: name.into(),
+            properties,
+            priority: 105,
+        })
+    }
+
+    /// 从 `{prefix}APPLICATION_JSON` 环境变量加载
+    ///
+    /// 变量不存在时返回 `Ok(None)`，内容不是合法 JSON 对象时返回错误
+    pub fn from_env(prefix: impl AsRef<str>) -> Result<Option<Self>> {
+        let var = format!("{}{}", normalize_env_prefix(prefix.as_ref()), APPLICATION_JSON_SUFFIX);
+        match std::env::var(&var) {
+            Ok(content) => Self::from_str(&content, var.clone())
+                .map(Some)
+                .with_context(|| format!("Invalid JSON in environment variable {}", var)),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// 展平 JSON 结构
+    fn flatten_json(value: &serde_json::Value, prefix: String, result: &mut HashMap<String, ConfigValue>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, val) in map {
+                    let new_prefix = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    Self::flatten_json(val, new_prefix, result);
+                }
+            }
+            serde_json::Value::Null => {}
+            other => {
+                result.insert(prefix, Self::json_value_to_config(other));
+            }
+        }
+    }
+
+    /// 转换 JSON 值为 ConfigValue
+    fn json_value_to_config(value: &serde_json::Value) -> ConfigValue {
+        match value {
+            serde_json::Value::Null => ConfigValue::String(String::new()),
+            serde_json::Value::Bool(b) => ConfigValue::Bool(*b),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => ConfigValue::Int(i),
+                None => ConfigValue::Float(n.as_f64().unwrap_or_default()),
+            },
+            serde_json::Value::String(s) => ConfigValue::String(s.clone()),
+            serde_json::Value::Array(arr) => {
+                ConfigValue::Array(arr.iter().map(Self::json_value_to_config).collect())
+            }
+            serde_json::Value::Object(map) => ConfigValue::Object(
+                map.iter()
+                    .map(|(k, v)| (k.clone(), Self::json_value_to_config(v)))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// 设置优先级
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+impl PropertySource for JsonPropertySource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get(&self, key: &str) -> Option<ConfigValue> {
+        self.properties.get(key).cloned()
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.properties.keys().cloned().collect()
     }
 
     fn priority(&self) -> i32 {
@@ -270,10 +1112,65 @@ impl PropertySource for EnvironmentPropertySource {
     }
 }
 
+/// 配置文件解析错误
+///
+/// 记录出错的配置源与行列号（从 1 开始），供 `FailureAnalyzer` 生成诊断报告
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Failed to parse TOML '{source_name}' at line {line}, column {column}: {message}")]
+pub struct ConfigParseError {
+    /// 配置源名称（通常是文件路径）
+    pub source_name: String,
+    /// 出错行号
+    pub line: usize,
+    /// 出错列号
+    pub column: usize,
+    /// 解析器给出的错误信息
+    pub message: String,
+}
+
+impl ConfigParseError {
+    fn from_toml(source_name: &str, content: &str, error: &toml::de::Error) -> Self {
+        let offset = error.span().map(|span| span.start).unwrap_or(0).min(content.len());
+        let before = content.get(..offset).unwrap_or(content);
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().map(|l| l.chars().count()).unwrap_or(0) + 1;
+
+        Self {
+            source_name: source_name.to_string(),
+            line,
+            column,
+            message: error
+                .message()
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+}
+
 /// TOML 文件配置源
+///
+/// 支持在同一个文件中通过 `[chimera.profile.<表达式>]` 定义按 profile 激活的配置段：
+///
+/// ```toml
+/// [server]
+/// port = 8080
+///
+/// [chimera.profile.prod.server]
+/// port = 80
+///
+/// [chimera.profile."prod & !eu".metrics]
+/// enabled = true
+/// ```
+///
+/// 配置段默认不生效，调用 `with_active_profiles` 后匹配的配置段按文件中出现的顺序
+/// 覆盖文件中的普通配置
 pub struct TomlPropertySource {
     name: String,
     properties: HashMap<String, ConfigValue>,
+    profile_sections: Vec<(ProfileExpression, HashMap<String, ConfigValue>)>,
     priority: i32,
 }
 
@@ -289,8 +1186,26 @@ impl TomlPropertySource {
 
     /// 从字符串解析 TOML 配置
     pub fn from_str(content: &str, name: String) -> Result<Self> {
-        let value: toml::Value = toml::from_str(content)
-            .context("Failed to parse TOML")?;
+        let mut value: toml::Value = toml::from_str(content)
+            .map_err(|e| ConfigParseError::from_toml(&name, content, &e))?;
+
+        // 提取 [chimera.profile.<表达式>] 配置段
+        let mut profile_sections = Vec::new();
+        let [namespace, section_key] = PROFILE_SECTION_PATH;
+        if let Some(toml::Value::Table(chimera)) = value.get_mut(namespace) {
+            if matches!(chimera.get(section_key), Some(toml::Value::Table(_))) {
+                if let Some(toml::Value::Table(sections)) = chimera.remove(section_key) {
+                    for (expression, section) in sections {
+                        let parsed = ProfileExpression::parse(&expression).with_context(|| {
+                            format!("Invalid profile section [{}.{}.\"{}\"]", namespace, section_key, expression)
+                        })?;
+                        let mut section_properties = HashMap::new();
+                        Self::flatten_toml(&section, String::new(), &mut section_properties);
+                        profile_sections.push((parsed, section_properties));
+                    }
+                }
+            }
+        }
 
         let mut properties = HashMap::new();
         Self::flatten_toml(&value, String::new(), &mut properties);
@@ -298,10 +1213,23 @@ impl TomlPropertySource {
         Ok(Self {
             name,
             properties,
+            profile_sections,
             priority: 0, // 文件配置优先级最低
         })
     }
 
+    /// 根据激活的 profiles 合并匹配的配置段
+    pub fn with_active_profiles(mut self, active_profiles: &[String]) -> Self {
+        for (expression, section) in &self.profile_sections {
+            if expression.matches(active_profiles) {
+                tracing::debug!("Activating profile section {:?} in {}", expression, self.name);
+                self.properties
+                    .extend(section.iter().map(|(k, v)| (k.clone(), v.clone())));
+            }
+        }
+        self
+    }
+
     /// 展平 TOML 结构
     /// 例如: { database: { url: "xxx" } } -> { "database.url": "xxx" }
     fn flatten_toml(value: &toml::Value, prefix: String, result: &mut HashMap<String, ConfigValue>) {
@@ -430,4 +1358,148 @@ impl PropertySource for MapPropertySource {
     fn priority(&self) -> i32 {
         self.priority
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(value: &str) -> ConfigValue {
+        ConfigValue::String(value.to_string())
+    }
+
+    /// 以给定的变量（不含前缀，大写）创建环境变量配置源
+    fn env_source(variables: &[(&str, &str)]) -> EnvironmentPropertySource {
+        EnvironmentPropertySource {
+            prefix: "CHIMERA_".to_string(),
+            priority: 100,
+            variables: variables.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+        }
+    }
+
+    #[test]
+    fn converts_keys_to_relaxed_env_candidates() {
+        assert_eq!(EnvironmentPropertySource::key_to_env_candidates("server.port"), vec!["SERVER_PORT"]);
+        assert_eq!(
+            EnvironmentPropertySource::key_to_env_candidates("server.request-timeout"),
+            vec!["SERVER_REQUEST_TIMEOUT", "SERVER_REQUESTTIMEOUT"]
+        );
+        assert_eq!(
+            EnvironmentPropertySource::key_to_env_candidates("app.allow-ip-list[1]"),
+            vec!["APP_ALLOW_IP_LIST_1", "APP_ALLOWIPLIST_1"]
+        );
+    }
+
+    #[test]
+    fn reads_keys_through_any_relaxed_candidate() {
+        let source = env_source(&[("SERVER_REQUESTTIMEOUT", "30s"), ("CACHE_TTL", "60")]);
+        assert_eq!(source.get("server.request-timeout"), Some(string("30s")));
+        assert_eq!(source.get("cache.ttl"), Some(string("60")));
+        assert_eq!(source.get("server.port"), None);
+
+        // 两种写法同时存在时，按 `-` 映射为 `_` 的变量优先
+        let source = env_source(&[("SERVER_REQUESTTIMEOUT", "30s"), ("SERVER_REQUEST_TIMEOUT", "10s")]);
+        assert_eq!(source.get("server.request-timeout"), Some(string("10s")));
+    }
+
+    #[test]
+    fn reads_indexed_variables_as_lists_until_the_first_gap() {
+        let source = env_source(&[
+            ("APP_ALLOW_IP_LIST_0", "10.0.0.1"),
+            ("APP_ALLOW_IP_LIST_1", "10.0.0.2"),
+            ("APP_ALLOW_IP_LIST_3", "10.0.0.4"),
+        ]);
+
+        assert_eq!(
+            source.get("app.allow-ip-list"),
+            Some(ConfigValue::Array(vec![string("10.0.0.1"), string("10.0.0.2")]))
+        );
+        assert_eq!(source.get("app.allow-ip-list[1]"), Some(string("10.0.0.2")));
+        assert_eq!(source.get("app.allow-ip-list[2]"), None);
+    }
+
+    #[test]
+    fn converts_env_names_back_to_known_keys() {
+        let known = ["server.request-timeout", "app.allow-ip-list"];
+        let to_key = |name| EnvironmentPropertySource::env_to_key(name, &known);
+
+        assert_eq!(to_key("SERVER_REQUESTTIMEOUT"), "server.request-timeout");
+        assert_eq!(to_key("SERVER_REQUEST_TIMEOUT"), "server.request-timeout");
+        assert_eq!(to_key("APP_ALLOW_IP_LIST_0"), "app.allow-ip-list");
+        // 未声明元数据的变量退化为小写点分键，带索引的变量映射为列表本身的键
+        assert_eq!(to_key("DATABASE_URL"), "database.url");
+        assert_eq!(to_key("FEATURE_FLAGS_2"), "feature.flags");
+    }
+
+    #[test]
+    fn env_prefix_and_names_are_case_insensitive() {
+        std::env::set_var("CHIMERA_TEST_RELAXED_SERVER_PORT", "8080");
+        std::env::set_var("chimera_test_relaxed_server_port", "9090");
+        std::env::set_var("Chimera_Test_Relaxed_Server_Host", "localhost");
+
+        let source = EnvironmentPropertySource::with_prefix("chimera_test_relaxed");
+
+        assert_eq!(source.prefix(), "CHIMERA_TEST_RELAXED_");
+        // 仅大小写不同的同名变量，全大写的变量优先
+        assert_eq!(source.get("server.port"), Some(string("8080")));
+        assert_eq!(source.get("server.host"), Some(string("localhost")));
+    }
+
+    #[test]
+    fn environment_source_does_not_expose_encryption_keys() {
+        std::env::set_var("CHIMERA_TEST_ENCRYPT_ENCRYPT_KEY", "c2VjcmV0");
+        std::env::set_var("CHIMERA_TEST_ENCRYPT_ENCRYPT_KEY_FILE", "/run/secrets/key");
+        std::env::set_var("CHIMERA_TEST_ENCRYPT_ENCRYPT_ALGORITHM", "aes-256-gcm");
+
+        let source = EnvironmentPropertySource::with_prefix("CHIMERA_TEST_ENCRYPT");
+
+        assert!(source.get("encrypt.key").is_none());
+        assert!(source.get("encrypt.key.file").is_none());
+        assert!(!source.keys().iter().any(|name| name.starts_with("encrypt.key")));
+        assert_eq!(
+            source.get("encrypt.algorithm"),
+            Some(ConfigValue::String("aes-256-gcm".to_string()))
+        );
+    }
+
+    fn defaults(port: i64, host: &str) -> MapPropertySource {
+        MapPropertySource::new("defaults")
+            .with_property("server.port", ConfigValue::Int(port))
+            .with_property("server.host", string(host))
+            .with_property("app.url", string("http://${server.host}:${server.port}"))
+            .with_property("app.instance", string("${random.uuid}"))
+    }
+
+    #[test]
+    fn replacing_a_source_reports_keys_whose_resolved_value_changed() {
+        let env = Environment::new();
+        env.add_property_source(Box::new(
+            MapPropertySource::new("overrides")
+                .with_priority(1000)
+                .with_property("server.port", ConfigValue::Int(9090)),
+        ));
+        env.add_property_source(Box::new(
+            MapPropertySource::new("fallback")
+                .with_priority(0)
+                .with_property("app.name", string("demo")),
+        ));
+        env.add_property_source(Box::new(defaults(8080, "localhost").with_property("app.name", string("demo"))));
+
+        // server.port 被 overrides 覆盖，删除的 app.name 回落到 fallback 中的相同值，都不算变化
+        let changed = env.replace_property_source(Box::new(
+            defaults(8081, "example.com").with_property("app.mode", string("cluster")),
+        ));
+
+        assert_eq!(changed, vec!["app.mode", "app.url", "server.host"]);
+        assert_eq!(env.get_string("app.url").as_deref(), Some("http://example.com:9090"));
+        assert_eq!(env.get_string("app.name").as_deref(), Some("demo"));
+    }
+
+    #[test]
+    fn replacing_a_source_with_identical_values_reports_nothing() {
+        let env = Environment::new();
+        env.add_property_source(Box::new(defaults(8080, "localhost")));
+
+        assert!(env.replace_property_source(Box::new(defaults(8080, "localhost"))).is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-/// 环境变量前缀
+/// 默认环境变量前缀（可通过 `ChimeraApplication::env_prefix` 修改）
 pub const ENV_PREFIX: &str = "CHIMERA_";
 
 /// 默认应用名称（当配置文件未指定时使用）
@@ -16,5 +16,59 @@ pub const CONFIG_PROFILES_ACTIVE: &str = "chimera.profiles.active";
 /// 配置键：事件系统是否异步
 pub const CONFIG_EVENTS_ASYNC: &str = "chimera.events.async";
 
-/// 环境变量：激活的profiles
-pub const ENV_PROFILES_ACTIVE: &str = "CHIMERA_PROFILES_ACTIVE";
+/// 配置键：监听器返回的后续事件的最大链路深度
+pub const CONFIG_EVENTS_MAX_FOLLOW_UP_DEPTH: &str = "chimera.events.max-follow-up-depth";
+
+/// 配置键：是否允许同一监听器在一条链路中重复发布相同类型的后续事件
+pub const CONFIG_EVENTS_ALLOW_REPEATED_FOLLOW_UPS: &str = "chimera.events.allow-repeated-follow-ups";
+
+/// 配置键：是否启用事件发件箱
+pub const CONFIG_EVENTS_OUTBOX_ENABLED: &str = "chimera.events.outbox.enabled";
+
+/// 配置键：事件发件箱文件路径
+pub const CONFIG_EVENTS_OUTBOX_PATH: &str = "chimera.events.outbox.path";
+
+/// 配置键：事件发件箱最大尝试次数
+pub const CONFIG_EVENTS_OUTBOX_MAX_ATTEMPTS: &str = "chimera.events.outbox.max-attempts";
+
+/// 配置键：事件发件箱首次重试等待时间（毫秒）
+pub const CONFIG_EVENTS_OUTBOX_INITIAL_BACKOFF_MS: &str = "chimera.events.outbox.initial-backoff-ms";
+
+/// 配置键：事件发件箱最大重试等待时间（毫秒）
+pub const CONFIG_EVENTS_OUTBOX_MAX_BACKOFF_MS: &str = "chimera.events.outbox.max-backoff-ms";
+
+/// 配置键：事件发件箱重试等待时间的增长倍数
+pub const CONFIG_EVENTS_OUTBOX_BACKOFF_MULTIPLIER: &str = "chimera.events.outbox.backoff-multiplier";
+
+/// 配置键：优雅停机超时时间（秒，也可以带单位，如 "500ms"、"30s"）
+pub const CONFIG_LIFECYCLE_SHUTDOWN_TIMEOUT: &str = "chimera.lifecycle.shutdown-timeout";
+
+/// 配置键：插件配置（如 chimera.plugins.chimera-web.enabled = false）
+pub const CONFIG_PLUGINS: &str = "chimera.plugins";
+
+/// 运行时属性：Web 服务器实际监听的地址（由 Web 插件在绑定端口后写入）
+pub const LOCAL_SERVER_ADDRESS: &str = "local.server.address";
+
+/// 运行时属性：Web 服务器实际监听的端口（配置端口为 0 时为系统分配的端口）
+pub const LOCAL_SERVER_PORT: &str = "local.server.port";
+
+/// 配置键：是否启用配置热加载
+pub const CONFIG_RELOAD_ENABLED: &str = "chimera.config.reload.enabled";
+
+/// 配置键：配置热加载防抖时间（毫秒）
+pub const CONFIG_RELOAD_DEBOUNCE_MS: &str = "chimera.config.reload.debounce-ms";
+
+/// 配置键前缀：profile 组（如 chimera.profiles.group.prod = ["db-prod", "metrics"]）
+pub const CONFIG_PROFILES_GROUP_PREFIX: &str = "chimera.profiles.group.";
+
+/// 配置键：导入额外的配置文件或目录
+pub const CONFIG_IMPORT: &str = "chimera.config.import";
+
+/// 配置键：加密配置值的密钥文件路径
+pub const CONFIG_ENCRYPT_KEY_FILE: &str = "chimera.config.encrypt.key-file";
+
+/// 配置键：启动时是否警告未知配置键
+pub const CONFIG_WARN_UNKNOWN_KEYS: &str = "chimera.config.warn-unknown-keys";
+
+/// 配置键：配置元数据 JSON 输出路径
+pub const CONFIG_METADATA_OUTPUT: &str = "chimera.config.metadata-output";
@@ -2,15 +2,22 @@ use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::sync::Arc;
 use parking_lot::RwLock;
-use anyhow::{Context, anyhow};
+use anyhow::Context;
 
 use crate::bean_factory::{DefaultListableBeanFactory, BeanFactory, BeanFactoryExt, ListableBeanFactory, ConfigurableBeanFactory, ConfigurableListableBeanFactory};
 use crate::lifecycle::BeanPostProcessor;
+use crate::autoconfigure::ConditionEvaluationReport;
+use crate::component::{Component, ComponentRegistrar, ComponentScan};
+use crate::availability::{ApplicationAvailability, ReadinessState, ReadinessStateChangedEvent};
+use crate::shutdown::ShutdownHandle;
 use crate::{
     bean::{BeanDefinition, FunctionFactory},
     config::Environment,
     Result,
-    event::{ApplicationEventPublisher, ApplicationShutdownEvent, Event, EventListener},
+    event::{
+        ApplicationEventPublisher, ApplicationShutdownEvent, AsyncEventListener, EnvironmentChangeEvent,
+        Event, EventListener, FollowUpEvents, ListenerHandle,
+    },
     Scope,
 };
 
@@ -57,8 +64,24 @@ pub struct ApplicationContext {
     /// 应用名称（用于事件）
     app_name: RwLock<Option<String>>,
 
+    /// Bean 定义注册后置处理器列表（按优先级排序）
+    bean_definition_registry_post_processors:
+        RwLock<Vec<Arc<dyn crate::lifecycle::BeanDefinitionRegistryPostProcessor>>>,
+
     /// Bean 工厂后置处理器列表（按优先级排序）
     bean_factory_post_processors: RwLock<Vec<Arc<dyn crate::lifecycle::BeanFactoryPostProcessor>>>,
+
+    /// 停机句柄
+    shutdown_handle: ShutdownHandle,
+
+    /// 应用可用性状态
+    availability: ApplicationAvailability,
+
+    /// 自动配置的条件评估报告
+    condition_evaluation_report: RwLock<Option<Arc<ConditionEvaluationReport>>>,
+
+    /// 组件扫描设置
+    component_scan: RwLock<ComponentScan>,
 }
 
 impl ApplicationContext {
@@ -94,10 +117,57 @@ impl ApplicationContext {
             event_publisher: Arc::new(ApplicationEventPublisher::new(multicaster)),
             shutdown_hooks: RwLock::new(Vec::new()),
             app_name: RwLock::new(None),
+            bean_definition_registry_post_processors: RwLock::new(Vec::new()),
             bean_factory_post_processors: RwLock::new(Vec::new()),
+            shutdown_handle: ShutdownHandle::new(),
+            availability: ApplicationAvailability::new(),
+            condition_evaluation_report: RwLock::new(None),
+            component_scan: RwLock::new(ComponentScan::new()),
+        }
+    }
+
+    /// 获取停机句柄
+    ///
+    /// 调用 `shutdown()` 触发应用优雅停机，效果与收到 SIGTERM 相同
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown_handle.clone()
+    }
+
+    /// 获取应用可用性状态
+    pub fn availability(&self) -> ApplicationAvailability {
+        self.availability.clone()
+    }
+
+    /// 更新就绪状态，状态发生变化时发布 ReadinessStateChangedEvent
+    pub fn set_readiness_state(&self, state: ReadinessState) {
+        if self.availability.set_readiness_state(state) {
+            tracing::debug!("Readiness state changed to {:?}", state);
+            self.publish_event(Arc::new(ReadinessStateChangedEvent::new(state)));
         }
     }
 
+    /// 获取组件扫描设置
+    pub fn component_scan(&self) -> ComponentScan {
+        self.component_scan.read().clone()
+    }
+
+    /// 修改组件扫描设置
+    pub fn with_component_scan<F>(&self, f: F)
+    where
+        F: FnOnce(&mut ComponentScan),
+    {
+        f(&mut self.component_scan.write());
+    }
+
+    /// 获取自动配置的条件评估报告（处理自动配置之前为 None）
+    pub fn condition_evaluation_report(&self) -> Option<Arc<ConditionEvaluationReport>> {
+        self.condition_evaluation_report.read().clone()
+    }
+
+    pub(crate) fn set_condition_evaluation_report(&self, report: ConditionEvaluationReport) {
+        *self.condition_evaluation_report.write() = Some(Arc::new(report));
+    }
+
     /// 获取内部的 BeanFactory（用于 BeanFactoryPostProcessor）
     pub fn get_bean_factory(&self) -> &Arc<DefaultListableBeanFactory> {
         &self.bean_factory
@@ -139,9 +209,65 @@ impl ApplicationContext {
         self.event_publisher.publish_event(event);
     }
 
-    /// 注册事件监听器
-    pub fn register_listener(&self, listener: Arc<dyn EventListener>) {
-        self.event_publisher.add_listener(listener);
+    /// 发布事件并等待所有监听器执行完成
+    pub async fn publish_event_and_wait(&self, event: Arc<dyn Event>) -> Result<()> {
+        self.event_publisher.publish_event_and_wait(event).await
+    }
+
+    /// 处理配置变更
+    ///
+    /// 刷新所有 #[refresh_scope] Bean（包括 ConfigurationProperties Bean），
+    /// 然后发布 EnvironmentChangeEvent
+    pub fn refresh_environment(&self, source_name: &str, changed_keys: Vec<String>) {
+        if changed_keys.is_empty() {
+            tracing::debug!("Property source '{}' reloaded without changes", source_name);
+            return;
+        }
+
+        tracing::info!(
+            "Property source '{}' changed, {} key(s) affected: {:?}",
+            source_name,
+            changed_keys.len(),
+            changed_keys
+        );
+
+        let refreshed = self.bean_factory.refresh_scoped_beans();
+        if !refreshed.is_empty() {
+            tracing::info!("Refreshed {} refresh-scoped bean(s): {:?}", refreshed.len(), refreshed);
+        }
+
+        self.publish_event(Arc::new(EnvironmentChangeEvent::new(
+            source_name.to_string(),
+            changed_keys,
+        )));
+    }
+
+    /// 注册事件监听器，返回用于注销的句柄
+    pub fn register_listener(&self, listener: Arc<dyn EventListener>) -> ListenerHandle {
+        self.event_publisher.add_listener(listener)
+    }
+
+    /// 注册异步事件监听器
+    pub fn register_async_listener<L: AsyncEventListener>(&self, listener: Arc<L>) -> ListenerHandle {
+        self.event_publisher.add_async_listener(listener)
+    }
+
+    /// 以闭包注册指定事件类型的监听器
+    ///
+    /// ```ignore
+    /// let handle = context.on(|event: &UserRegisteredEvent| {
+    ///     println!("user registered: {}", event.username);
+    /// });
+    /// // 不再需要时注销
+    /// handle.unregister();
+    /// ```
+    pub fn on<E, R, F>(&self, handler: F) -> ListenerHandle
+    where
+        E: Event,
+        R: FollowUpEvents,
+        F: Fn(&E) -> R + Send + Sync + 'static,
+    {
+        self.event_publisher.on(handler)
     }
 
     /// 注册 shutdown hook
@@ -171,7 +297,10 @@ impl ApplicationContext {
     pub fn scan_bean_post_processors(self: &Arc<Self>) {
         use crate::lifecycle::BeanPostProcessorMarker;
 
-        let markers: Vec<_> = inventory::iter::<BeanPostProcessorMarker>().collect();
+        let scan = self.component_scan();
+        let markers: Vec<_> = inventory::iter::<BeanPostProcessorMarker>()
+            .filter(|marker| scan.includes(marker.bean_name))
+            .collect();
 
         if markers.is_empty() {
             tracing::debug!("No BeanPostProcessor markers found");
@@ -200,6 +329,38 @@ impl ApplicationContext {
         tracing::info!("BeanPostProcessor scan completed, registered {} processor(s)", count);
     }
 
+    /// 注册 BeanDefinitionRegistryPostProcessor
+    ///
+    /// 在组件扫描之后、创建任何 Bean 实例之前执行，按优先级顺序执行
+    pub fn add_bean_definition_registry_post_processor(
+        &self,
+        processor: Arc<dyn crate::lifecycle::BeanDefinitionRegistryPostProcessor>,
+    ) {
+        let mut processors = self.bean_definition_registry_post_processors.write();
+        processors.push(processor);
+        processors.sort_by_key(|p| p.order());
+    }
+
+    /// 调用所有 BeanDefinitionRegistryPostProcessor
+    ///
+    /// 在 `scan_components()` 之后、`scan_bean_methods()` 之前调用
+    pub fn invoke_bean_definition_registry_post_processors(self: &Arc<Self>) -> Result<()> {
+        let processors = self.bean_definition_registry_post_processors.read().clone();
+
+        if processors.is_empty() {
+            tracing::debug!("No BeanDefinitionRegistryPostProcessors to invoke");
+            return Ok(());
+        }
+
+        tracing::info!("Invoking {} BeanDefinitionRegistryPostProcessor(s)", processors.len());
+
+        for processor in processors.iter() {
+            processor.post_process_bean_definition_registry(self)
+                .context("BeanDefinitionRegistryPostProcessor failed")?;
+        }
+        Ok(())
+    }
+
     /// 注册 BeanFactoryPostProcessor
     ///
     /// BeanFactoryPostProcessor 会在 Bean 定义加载后、Bean 实例化之前执行，按优先级顺序执行
@@ -224,7 +385,10 @@ impl ApplicationContext {
     pub fn scan_bean_factory_post_processors(self: &Arc<Self>) {
         use crate::lifecycle::BeanFactoryPostProcessorMarker;
 
-        let markers: Vec<_> = inventory::iter::<BeanFactoryPostProcessorMarker>().collect();
+        let scan = self.component_scan();
+        let markers: Vec<_> = inventory::iter::<BeanFactoryPostProcessorMarker>()
+            .filter(|marker| scan.includes(marker.bean_name))
+            .collect();
 
         if markers.is_empty() {
             tracing::debug!("No BeanFactoryPostProcessor markers found");
@@ -371,7 +535,10 @@ impl ApplicationContext {
     fn invoke_smart_initializing_singletons(self: &Arc<Self>) -> Result<()> {
         use crate::lifecycle::SmartInitializingSingletonMarker;
 
-        let markers: Vec<_> = inventory::iter::<SmartInitializingSingletonMarker>().collect();
+        let scan = self.component_scan();
+        let markers: Vec<_> = inventory::iter::<SmartInitializingSingletonMarker>()
+            .filter(|marker| scan.includes(marker.bean_name))
+            .collect();
 
         if markers.is_empty() {
             tracing::debug!("No SmartInitializingSingleton markers found");
@@ -445,7 +612,7 @@ impl ApplicationContext {
 
         // 验证依赖图
         validate_dependency_graph(&dependency_map)
-            .map_err(|e| anyhow!("Dependency validation failed: {}", e))?;
+            .map_err(|e| anyhow::Error::new(e).context("Dependency validation failed"))?;
 
         tracing::info!(
             "Dependency validation passed for {} bean(s)",
@@ -547,6 +714,8 @@ impl ApplicationContext {
 pub struct ApplicationContextBuilder {
     context: ApplicationContext,
     async_events: bool,
+    component_scan: ComponentScan,
+    components: Vec<ComponentRegistrar>,
 }
 
 impl ApplicationContextBuilder {
@@ -554,6 +723,8 @@ impl ApplicationContextBuilder {
         Self {
             context: ApplicationContext::new(),
             async_events: false,
+            component_scan: ComponentScan::new(),
+            components: Vec::new(),
         }
     }
 
@@ -566,6 +737,50 @@ impl ApplicationContextBuilder {
         self
     }
 
+    /// 显式注册组件
+    ///
+    /// 构建时直接注册，不依赖组件扫描；配合 `component_scan(false)` 可以只注册选定的组件
+    ///
+    /// ```ignore
+    /// let context = ApplicationContext::builder()
+    ///     .component_scan(false)
+    ///     .with_component::<UserRepository>()
+    ///     .with_component::<UserService>()
+    ///     .build()?;
+    /// context.initialize()?;
+    /// ```
+    pub fn with_component<T: Component>(mut self) -> Self {
+        self.components.push(|context| context.register_component::<T>());
+        self
+    }
+
+    /// 显式注册配置类
+    ///
+    /// 其 `#[bean]` 方法在 `scan_bean_methods()` 时注册，组件扫描禁用时同样生效
+    pub fn with_configuration<T: Component>(mut self) -> Self {
+        self.components.push(|context| context.register_configuration::<T>());
+        self
+    }
+
+    /// 添加组件扫描过滤器，参数为组件的 Bean 名称，返回 false 时扫描跳过该组件
+    ///
+    /// 不影响 `with_component` / `with_configuration` 显式注册的组件
+    pub fn scan_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.component_scan.add_filter(Arc::new(filter));
+        self
+    }
+
+    /// 设置是否启用全局组件扫描（默认启用）
+    ///
+    /// 禁用后扫描阶段不再注册 inventory 中收集到的组件，只保留显式注册的组件
+    pub fn component_scan(mut self, enabled: bool) -> Self {
+        self.component_scan.set_enabled(enabled);
+        self
+    }
+
     /// 注册 Bean
     pub fn register(self, definition: BeanDefinition) -> Result<Self> {
         self.context.register(definition)?;
@@ -606,6 +821,11 @@ impl ApplicationContextBuilder {
         self
     }
 
+    /// 获取构建中的 Environment（用于在构建前读取配置或注册解密器）
+    pub(crate) fn environment(&self) -> &Environment {
+        &self.context.environment
+    }
+
     /// 添加配置源（可变引用版本，不消费 self）
     pub(crate) fn add_property_source_mut(&mut self, source: Box<dyn crate::PropertySource>) {
         self.context.environment.add_property_source(source);
@@ -640,6 +860,12 @@ impl ApplicationContextBuilder {
             Arc::new(self.context)
         };
 
+        // 应用组件扫描设置并注册显式指定的组件
+        context.with_component_scan(|scan| *scan = self.component_scan);
+        for register in &self.components {
+            register(&context)?;
+        }
+
         // 注意：核心组件注册已移到 initialize() 方法中
         // 这样可以确保所有用户组件都注册完成后再注册核心组件
         Ok(context)
@@ -0,0 +1,251 @@
+//! 配置值加密
+//!
+//! 配置文件中形如 `"{cipher}AES256GCM:<base64>"` 的值会在通过 `Environment` 读取时自动解密，
+//! 这样数据库密码等敏感信息可以以密文形式提交到配置文件中
+//!
+//! 密钥通过以下方式提供（按顺序查找）：
+//! 1. 环境变量 `CHIMERA_ENCRYPT_KEY`（base64 编码的 32 字节密钥）
+//! 2. 环境变量 `CHIMERA_ENCRYPT_KEY_FILE` 指向的密钥文件
+//! 3. 配置项 `chimera.config.encrypt.key-file` 指向的密钥文件
+//!
+//! ## 生成密钥与加密
+//!
+//! ```ignore
+//! use chimera_core::crypto;
+//!
+//! let key = crypto::generate_key();
+//! let encrypted = crypto::encrypt_value(&key, "s3cr3t")?;
+//! // encrypted = "{cipher}AES256GCM:..."
+//! ```
+
+use crate::config::Environment;
+use crate::constants::CONFIG_ENCRYPT_KEY_FILE;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use anyhow::{anyhow, bail, Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use std::path::Path;
+
+/// 加密值前缀
+pub const CIPHER_PREFIX: &str = "{cipher}";
+
+/// AES-256-GCM 算法标识
+pub const AES256GCM_ALGORITHM: &str = "AES256GCM";
+
+/// 密钥环境变量名后缀（与环境变量前缀拼接，例如 CHIMERA_ENCRYPT_KEY）
+pub const ENCRYPT_KEY_ENV_SUFFIX: &str = "ENCRYPT_KEY";
+
+/// 密钥文件环境变量名后缀（例如 CHIMERA_ENCRYPT_KEY_FILE）
+pub const ENCRYPT_KEY_FILE_ENV_SUFFIX: &str = "ENCRYPT_KEY_FILE";
+
+/// AES-GCM nonce 长度（字节）
+const NONCE_LEN: usize = 12;
+
+/// 配置值解密器
+///
+/// 可以实现此 trait 接入 KMS、Vault 等外部密钥服务，
+/// 并通过 `ChimeraApplication::decryptor` 或 `Environment::add_decryptor` 注册
+pub trait PropertyDecryptor: Send + Sync {
+    /// 解密器名称
+    fn name(&self) -> &str;
+
+    /// 是否能解密该值（值已去除 `{cipher}` 前缀）
+    fn can_decrypt(&self, value: &str) -> bool;
+
+    /// 解密（值已去除 `{cipher}` 前缀）
+    fn decrypt(&self, value: &str) -> Result<String>;
+}
+
+/// AES-256-GCM 解密器
+///
+/// 处理 `{cipher}AES256GCM:<base64(nonce || ciphertext)>` 格式的值
+pub struct AesGcmDecryptor {
+    cipher: Aes256Gcm,
+}
+
+impl AesGcmDecryptor {
+    /// 使用 base64 编码的 32 字节密钥创建
+    pub fn from_base64_key(key: &str) -> Result<Self> {
+        let bytes = BASE64
+            .decode(key.trim())
+            .context("Encryption key is not valid base64")?;
+        if bytes.len() != 32 {
+            bail!("Encryption key must be 32 bytes, got {}", bytes.len());
+        }
+
+        Ok(Self {
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&bytes)),
+        })
+    }
+
+    /// 从密钥文件创建（文件内容为 base64 编码的密钥）
+    pub fn from_key_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let key = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read encryption key file: {}", path.display()))?;
+        Self::from_base64_key(&key)
+    }
+
+    /// 从环境变量或配置中加载密钥
+    ///
+    /// 未配置密钥时返回 `Ok(None)`
+    pub fn from_environment(env: &Environment, env_prefix: &str) -> Result<Option<Self>> {
+        if let Ok(key) = std::env::var(format!("{}{}", env_prefix, ENCRYPT_KEY_ENV_SUFFIX)) {
+            return Self::from_base64_key(&key).map(Some);
+        }
+
+        let key_file = std::env::var(format!("{}{}", env_prefix, ENCRYPT_KEY_FILE_ENV_SUFFIX))
+            .ok()
+            .or_else(|| env.get_string(CONFIG_ENCRYPT_KEY_FILE));
+
+        match key_file {
+            Some(path) => Self::from_key_file(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn encrypt(&self, plaintext: &str) -> Result<String> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| anyhow!("Failed to encrypt value"))?;
+
+        let mut payload = nonce.to_vec();
+        payload.extend_from_slice(&ciphertext);
+        Ok(format!(
+            "{}{}:{}",
+            CIPHER_PREFIX,
+            AES256GCM_ALGORITHM,
+            BASE64.encode(payload)
+        ))
+    }
+}
+
+impl PropertyDecryptor for AesGcmDecryptor {
+    fn name(&self) -> &str {
+        AES256GCM_ALGORITHM
+    }
+
+    fn can_decrypt(&self, value: &str) -> bool {
+        value
+            .strip_prefix(AES256GCM_ALGORITHM)
+            .is_some_and(|rest| rest.starts_with(':'))
+    }
+
+    fn decrypt(&self, value: &str) -> Result<String> {
+        let encoded = value
+            .strip_prefix(AES256GCM_ALGORITHM)
+            .and_then(|rest| rest.strip_prefix(':'))
+            .ok_or_else(|| anyhow!("Not an {} value", AES256GCM_ALGORITHM))?;
+
+        let payload = BASE64
+            .decode(encoded.trim())
+            .context("Encrypted value is not valid base64")?;
+        if payload.len() <= NONCE_LEN {
+            bail!("Encrypted value is too short");
+        }
+
+        let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow!("Failed to decrypt value (wrong key or corrupted data)"))?;
+
+        String::from_utf8(plaintext).context("Decrypted value is not valid UTF-8")
+    }
+}
+
+/// 生成新的 base64 编码 AES-256 密钥
+pub fn generate_key() -> String {
+    BASE64.encode(Aes256Gcm::generate_key(OsRng))
+}
+
+/// 使用 base64 编码的密钥加密配置值
+///
+/// 返回可直接写入配置文件的 `{cipher}AES256GCM:...` 字符串
+pub fn encrypt_value(key: &str, plaintext: &str) -> Result<String> {
+    AesGcmDecryptor::from_base64_key(key)?.encrypt(plaintext)
+}
+
+/// 使用 base64 编码的密钥解密配置值（值需包含 `{cipher}` 前缀）
+pub fn decrypt_value(key: &str, value: &str) -> Result<String> {
+    let value = value
+        .strip_prefix(CIPHER_PREFIX)
+        .ok_or_else(|| anyhow!("Value does not start with {}", CIPHER_PREFIX))?;
+    AesGcmDecryptor::from_base64_key(key)?.decrypt(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ConfigValue, MapPropertySource};
+    use std::sync::Arc;
+
+    #[test]
+    fn encrypt_and_decrypt_round_trip() {
+        let key = generate_key();
+        let encrypted = encrypt_value(&key, "s3cr3t").unwrap();
+
+        assert!(encrypted.starts_with("{cipher}AES256GCM:"));
+        assert_eq!(decrypt_value(&key, &encrypted).unwrap(), "s3cr3t");
+    }
+
+    #[test]
+    fn encrypting_twice_uses_fresh_nonces() {
+        let key = generate_key();
+
+        assert_ne!(encrypt_value(&key, "value").unwrap(), encrypt_value(&key, "value").unwrap());
+    }
+
+    #[test]
+    fn decrypt_fails_with_wrong_key_or_tampered_value() {
+        let key = generate_key();
+        let encrypted = encrypt_value(&key, "s3cr3t").unwrap();
+
+        assert!(decrypt_value(&generate_key(), &encrypted).is_err());
+
+        let mut payload = BASE64
+            .decode(encrypted.strip_prefix("{cipher}AES256GCM:").unwrap())
+            .unwrap();
+        let last = payload.len() - 1;
+        payload[last] ^= 0x01;
+        let tampered = format!("{{cipher}}AES256GCM:{}", BASE64.encode(payload));
+        assert!(decrypt_value(&key, &tampered).is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_keys_and_values() {
+        let key = generate_key();
+
+        assert!(encrypt_value(&BASE64.encode([0u8; 16]), "value").is_err());
+        assert!(encrypt_value("not base64!", "value").is_err());
+        assert!(decrypt_value(&key, "AES256GCM:AAAA").is_err());
+        assert!(decrypt_value(&key, "{cipher}AES256GCM:AAAA").is_err());
+        assert!(decrypt_value(&key, "{cipher}OTHER:AAAA").is_err());
+    }
+
+    #[test]
+    fn environment_decrypts_cipher_values_and_reports_failures() {
+        let key = generate_key();
+        let env = Environment::new();
+        env.add_decryptor(Arc::new(AesGcmDecryptor::from_base64_key(&key).unwrap()));
+        env.add_property_source(Box::new(
+            MapPropertySource::new("test")
+                .with_property("db.password", ConfigValue::String(encrypt_value(&key, "s3cr3t").unwrap())),
+        ));
+
+        assert_eq!(env.get_string("db.password").as_deref(), Some("s3cr3t"));
+        assert!(env.validate_encrypted_values().is_ok());
+
+        let encrypted_with_other_key = encrypt_value(&generate_key(), "other").unwrap();
+        env.add_property_source(Box::new(
+            MapPropertySource::new("broken")
+                .with_property("api.token", ConfigValue::String(encrypted_with_other_key)),
+        ));
+
+        let error = env.validate_encrypted_values().unwrap_err().to_string();
+        assert!(error.contains("api.token"), "unexpected error: {}", error);
+    }
+}
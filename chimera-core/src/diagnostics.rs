@@ -0,0 +1,327 @@
+//! 启动失败分析
+//!
+//! 启动失败时，`FailureAnalyzer` 将错误链中可识别的错误转换为包含 "Description" 和 "Action"
+//! 两部分的结构化报告，帮助快速定位问题
+//!
+//! ## 使用示例
+//!
+//! ```ignore
+//! struct LicenseMissingAnalyzer;
+//!
+//! impl FailureAnalyzer for LicenseMissingAnalyzer {
+//!     fn analyze(
+//!         &self,
+//!         error: &anyhow::Error,
+//!         _context: Option<&Arc<ApplicationContext>>,
+//!     ) -> Option<FailureAnalysis> {
+//!         let e = find_cause::<LicenseMissingError>(error)?;
+//!         Some(FailureAnalysis::new(
+//!             format!("License file '{}' was not found.", e.path),
+//!             "Place a valid license file at the configured path.",
+//!         ))
+//!     }
+//! }
+//!
+//! submit_failure_analyzer!(LicenseMissingAnalyzer);
+//! ```
+
+use crate::config::ConfigParseError;
+use crate::context::{ApplicationContext, Container};
+use crate::error::{BeanCreationError, NoSuchBeanError};
+use crate::utils::dependency::DependencyValidationError;
+use crate::utils::naming::levenshtein;
+use std::fmt;
+use std::sync::Arc;
+
+/// 失败分析报告
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureAnalysis {
+    /// 问题描述
+    pub description: String,
+    /// 建议的处理方式
+    pub action: String,
+}
+
+impl FailureAnalysis {
+    /// 创建分析报告
+    pub fn new(description: impl Into<String>, action: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            action: action.into(),
+        }
+    }
+}
+
+impl fmt::Display for FailureAnalysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "***************************")?;
+        writeln!(f, "APPLICATION FAILED TO START")?;
+        writeln!(f, "***************************")?;
+        writeln!(f)?;
+        writeln!(f, "Description:")?;
+        writeln!(f)?;
+        writeln!(f, "{}", self.description)?;
+        writeln!(f)?;
+        writeln!(f, "Action:")?;
+        writeln!(f)?;
+        write!(f, "{}", self.action)
+    }
+}
+
+/// 失败分析器
+///
+/// 识别错误链中的特定错误并生成报告，无法识别时返回 None。
+/// 多个分析器按 `order` 升序依次尝试，使用第一个给出的报告
+pub trait FailureAnalyzer: Send + Sync {
+    /// 分析错误，`context` 在应用上下文已创建时提供
+    fn analyze(
+        &self,
+        error: &anyhow::Error,
+        context: Option<&Arc<ApplicationContext>>,
+    ) -> Option<FailureAnalysis>;
+
+    /// 执行顺序（数字越小越先执行）
+    fn order(&self) -> i32 {
+        0
+    }
+}
+
+/// 失败分析器注册信息
+pub struct FailureAnalyzerRegistry {
+    pub create: fn() -> Box<dyn FailureAnalyzer>,
+}
+
+inventory::collect!(FailureAnalyzerRegistry);
+
+/// 注册失败分析器的宏
+#[macro_export]
+macro_rules! submit_failure_analyzer {
+    ($analyzer_type:ty) => {
+        $crate::inventory::submit! {
+            $crate::diagnostics::FailureAnalyzerRegistry {
+                create: || Box::new(<$analyzer_type>::default())
+            }
+        }
+    };
+}
+
+/// 使用所有已注册的分析器分析错误
+pub fn analyze_failure(
+    error: &anyhow::Error,
+    context: Option<&Arc<ApplicationContext>>,
+) -> Option<FailureAnalysis> {
+    let mut analyzers: Vec<Box<dyn FailureAnalyzer>> = inventory::iter::<FailureAnalyzerRegistry>
+        .into_iter()
+        .map(|registry| (registry.create)())
+        .collect();
+    analyzers.sort_by_key(|analyzer| analyzer.order());
+
+    analyzers
+        .iter()
+        .find_map(|analyzer| analyzer.analyze(error, context))
+}
+
+/// 在错误链中查找指定类型的错误
+pub fn find_cause<T>(error: &anyhow::Error) -> Option<&T>
+where
+    T: std::error::Error + Send + Sync + 'static,
+{
+    error.chain().find_map(|cause| cause.downcast_ref::<T>())
+}
+
+/// 从候选项中找出与目标名称相近的名称（忽略大小写，按相似度排序）
+pub fn similar_names<'a>(
+    target: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<String> {
+    let target = target.to_lowercase();
+    let threshold = (target.chars().count() / 3).max(2);
+
+    let mut matches: Vec<(usize, String)> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let lower = candidate.to_lowercase();
+            let distance = levenshtein(&target, &lower);
+            let contains = lower.contains(&target) || target.contains(&lower);
+            (distance <= threshold || contains).then(|| (distance, candidate.to_string()))
+        })
+        .collect();
+    matches.sort();
+    matches.into_iter().map(|(_, name)| name).collect()
+}
+
+fn suggestion_action(
+    missing: &str,
+    context: Option<&Arc<ApplicationContext>>,
+    default_action: String,
+) -> String {
+    let bean_names = context.map(|ctx| ctx.get_bean_names()).unwrap_or_default();
+    let suggestions = similar_names(missing, bean_names.iter().map(String::as_str));
+
+    if suggestions.is_empty() {
+        return default_action;
+    }
+
+    let list = suggestions
+        .iter()
+        .map(|name| format!("\t- {}", name))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "{}\n\nThe following beans have similar names:\n{}",
+        default_action, list
+    )
+}
+
+/// 去掉类型路径和泛型参数，只保留类型名
+fn short_type_name(type_name: &str) -> &str {
+    let without_generics = type_name.split('<').next().unwrap_or(type_name);
+    without_generics.rsplit("::").next().unwrap_or(without_generics)
+}
+
+/// 依赖关系错误分析器（循环依赖、缺失依赖）
+#[derive(Default)]
+pub struct DependencyFailureAnalyzer;
+
+impl FailureAnalyzer for DependencyFailureAnalyzer {
+    fn analyze(
+        &self,
+        error: &anyhow::Error,
+        context: Option<&Arc<ApplicationContext>>,
+    ) -> Option<FailureAnalysis> {
+        match find_cause::<DependencyValidationError>(error)? {
+            DependencyValidationError::CircularDependency { cycle } => {
+                let mut description = String::from(
+                    "The dependencies of some of the beans in the application context form a cycle:\n\n",
+                );
+                // 环的首尾是同一个 Bean，只展示一次
+                let members = match cycle.split_last() {
+                    Some((last, rest)) if rest.first() == Some(last) => rest,
+                    _ => cycle.as_slice(),
+                };
+                description.push_str("┌─────┐\n");
+                for (i, bean) in members.iter().enumerate() {
+                    if i > 0 {
+                        description.push_str("↑     ↓\n");
+                    }
+                    description.push_str(&format!("|  {}\n", bean));
+                }
+                description.push_str("└─────┘");
+
+                Some(FailureAnalysis::new(
+                    description,
+                    "Relying upon circular dependencies is discouraged. Update your beans to \
+                     remove the dependency cycle, e.g. by extracting the shared logic into a new \
+                     bean or by publishing an event instead of calling the other bean directly.",
+                ))
+            }
+            DependencyValidationError::MissingDependency { bean, missing } => Some(
+                FailureAnalysis::new(
+                    format!(
+                        "Bean '{}' depends on a bean named '{}' that could not be found.",
+                        bean, missing
+                    ),
+                    suggestion_action(
+                        missing,
+                        context,
+                        format!(
+                            "Consider defining a bean named '{}' in your configuration, or fix \
+                             the dependency name declared on '{}'.",
+                            missing, bean
+                        ),
+                    ),
+                ),
+            ),
+        }
+    }
+
+    fn order(&self) -> i32 {
+        1000
+    }
+}
+
+/// 找不到 Bean 的错误分析器（如 `#[autowired]` 注入的类型没有注册）
+#[derive(Default)]
+pub struct NoSuchBeanFailureAnalyzer;
+
+impl FailureAnalyzer for NoSuchBeanFailureAnalyzer {
+    fn analyze(
+        &self,
+        error: &anyhow::Error,
+        context: Option<&Arc<ApplicationContext>>,
+    ) -> Option<FailureAnalysis> {
+        // 最内层的创建错误对应直接依赖缺失 Bean 的那个 Bean
+        let requester = error
+            .chain()
+            .filter_map(|cause| cause.downcast_ref::<BeanCreationError>())
+            .last();
+        let required_by = requester
+            .map(|cause| format!("Bean '{}' required ", cause.bean))
+            .unwrap_or_else(|| "The application required ".to_string());
+
+        let analysis = match find_cause::<NoSuchBeanError>(error)? {
+            NoSuchBeanError::Name(name) => FailureAnalysis::new(
+                format!("{}a bean named '{}' that could not be found.", required_by, name),
+                suggestion_action(
+                    name,
+                    context,
+                    format!("Consider defining a bean named '{}' in your configuration.", name),
+                ),
+            ),
+            NoSuchBeanError::Type(type_name) => FailureAnalysis::new(
+                format!(
+                    "{}a bean of type '{}' that could not be found.",
+                    required_by, type_name
+                ),
+                suggestion_action(
+                    short_type_name(type_name),
+                    context,
+                    format!(
+                        "Consider defining a bean of type '{}' (e.g. annotate it with #[derive(Component)]) \
+                         or registering it with #[bean] in a #[configuration].",
+                        type_name
+                    ),
+                ),
+            ),
+        };
+
+        Some(analysis)
+    }
+
+    fn order(&self) -> i32 {
+        1000
+    }
+}
+
+/// 配置文件解析错误分析器
+#[derive(Default)]
+pub struct ConfigParseFailureAnalyzer;
+
+impl FailureAnalyzer for ConfigParseFailureAnalyzer {
+    fn analyze(
+        &self,
+        error: &anyhow::Error,
+        _context: Option<&Arc<ApplicationContext>>,
+    ) -> Option<FailureAnalysis> {
+        let e = find_cause::<ConfigParseError>(error)?;
+
+        Some(FailureAnalysis::new(
+            format!(
+                "Failed to parse configuration file '{}':\n\n\tLine:    {}\n\tColumn:  {}\n\tReason:  {}",
+                e.source_name, e.line, e.column, e.message
+            ),
+            format!(
+                "Fix the TOML syntax at line {}, column {} of '{}'.",
+                e.line, e.column, e.source_name
+            ),
+        ))
+    }
+
+    fn order(&self) -> i32 {
+        1000
+    }
+}
+
+crate::submit_failure_analyzer!(DependencyFailureAnalyzer);
+crate::submit_failure_analyzer!(NoSuchBeanFailureAnalyzer);
+crate::submit_failure_analyzer!(ConfigParseFailureAnalyzer);
@@ -17,3 +17,37 @@
 /// ```
 pub use anyhow::Result;
 
+
+/// 找不到 Bean 的错误
+///
+/// 作为 anyhow 错误链中的源错误保留，供 `FailureAnalyzer` 识别
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum NoSuchBeanError {
+    /// 按名称查找失败
+    #[error("Bean not found: {0}")]
+    Name(String),
+    /// 按类型查找失败
+    #[error("Bean not found: No bean found for type '{0}'")]
+    Type(String),
+}
+
+/// Bean 创建失败的错误
+///
+/// 包装创建过程中的源错误，依赖链中每一层 Bean 各对应一个，供 `FailureAnalyzer` 识别请求方
+#[derive(Debug, thiserror::Error)]
+#[error("Failed to create bean '{bean}'")]
+pub struct BeanCreationError {
+    /// 创建失败的 Bean 名称
+    pub bean: String,
+    #[source]
+    source: anyhow::Error,
+}
+
+impl BeanCreationError {
+    pub fn new(bean: impl Into<String>, source: anyhow::Error) -> Self {
+        Self {
+            bean: bean.into(),
+            source,
+        }
+    }
+}
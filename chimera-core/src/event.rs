@@ -1,8 +1,19 @@
-use std::any::Any;
-use std::collections::HashMap;
+use std::any::{Any, TypeId};
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Weak;
 use std::sync::Arc;
-use std::time::SystemTime;
-use parking_lot::RwLock;
+use std::time::{Duration, Instant, SystemTime};
+use async_trait::async_trait;
+use futures::FutureExt;
+use parking_lot::{Mutex, RwLock};
+use serde::Serialize;
+use crate::config::Environment;
+use crate::context::ApplicationContext;
+use crate::diagnostics::FailureAnalysis;
+use crate::transaction::{TransactionPhase, TransactionSynchronization};
 
 /// 事件 trait - 所有事件都必须实现此 trait
 ///
@@ -22,6 +33,21 @@ pub trait Event: Any + Send + Sync {
 
     /// 转换为 Any 引用，用于类型转换
     fn as_any(&self) -> &dyn Any;
+
+    /// 声明的事件超类型
+    ///
+    /// 监听这些类型的监听器也会收到此事件，通常为标记 trait 的 trait object，
+    /// 例如 `vec![TypeId::of::<dyn UserEvent>()]`。同一事件类型的所有实例应返回相同的超类型
+    fn supertypes(&self) -> Vec<TypeId> {
+        Vec::new()
+    }
+}
+
+/// 获取事件的所有可匹配类型（具体类型和声明的超类型）
+pub fn event_type_ids(event: &dyn Event) -> Vec<TypeId> {
+    let mut types = vec![event.as_any().type_id()];
+    types.extend(event.supertypes());
+    types
 }
 
 /// 应用启动完成事件
@@ -95,309 +121,1838 @@ impl Event for ApplicationShutdownEvent {
     }
 }
 
-/// 事件监听器 trait
+/// 应用开始启动事件
 ///
-/// 类似 Spring 的 ApplicationListener
-/// 默认同步执行，支持异步扩展
-pub trait EventListener: Send + Sync {
-    /// 处理事件（同步）
-    fn on_event(&self, event: Arc<dyn Event>);
+/// 在 `ChimeraApplication::run` 开始时触发，此时配置和 ApplicationContext 都尚未创建，
+/// 只有通过 `ChimeraApplication::listener` 注册的早期监听器能收到
+#[derive(Debug, Clone)]
+pub struct ApplicationStartingEvent {
+    /// 事件时间戳
+    pub timestamp: SystemTime,
+}
 
-    /// 获取监听器名称（用于日志）
-    fn listener_name(&self) -> &str {
-        "AnonymousListener"
+impl ApplicationStartingEvent {
+    pub fn new() -> Self {
+        Self {
+            timestamp: SystemTime::now(),
+        }
     }
+}
 
-    /// 是否支持该事件类型（可选实现，默认支持所有事件）
-    fn supports_event(&self, event_name: &str) -> bool {
-        let _ = event_name;
-        true
+impl Default for ApplicationStartingEvent {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-/// 类型化事件监听器 trait
-///
-/// 提供类型安全的事件处理
-pub trait TypedEventListener<E: Event>: Send + Sync {
-    /// 处理特定类型的事件
-    fn on_event(&self, event: &E);
+impl Event for ApplicationStartingEvent {
+    fn event_name(&self) -> &str {
+        "ApplicationStartingEvent"
+    }
 
-    /// 获取监听器名称（用于日志）
-    fn listener_name(&self) -> &str {
-        "AnonymousTypedListener"
+    fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 }
 
-/// 类型化事件监听器适配器
+/// 环境准备完成事件
 ///
-/// 将 TypedEventListener<E> 适配为 EventListener
-pub struct TypedEventListenerAdapter<E: Event + 'static, L: TypedEventListener<E>> {
-    listener: Arc<L>,
-    _phantom: std::marker::PhantomData<E>,
+/// 在配置源加载完成、profiles 确定后触发，此时尚未执行初始化器和组件扫描
+#[derive(Clone)]
+pub struct ApplicationEnvironmentPreparedEvent {
+    /// 配置环境
+    pub environment: Arc<Environment>,
+    /// 激活的 profiles
+    pub active_profiles: Vec<String>,
+    /// 事件时间戳
+    pub timestamp: SystemTime,
 }
 
-impl<E: Event + 'static, L: TypedEventListener<E>> TypedEventListenerAdapter<E, L> {
-    pub fn new(listener: Arc<L>) -> Self {
+impl ApplicationEnvironmentPreparedEvent {
+    pub fn new(environment: Arc<Environment>, active_profiles: Vec<String>) -> Self {
         Self {
-            listener,
-            _phantom: std::marker::PhantomData,
+            environment,
+            active_profiles,
+            timestamp: SystemTime::now(),
         }
     }
 }
 
-impl<E: Event + 'static, L: TypedEventListener<E> + 'static> EventListener
-    for TypedEventListenerAdapter<E, L>
-{
-    fn on_event(&self, event: Arc<dyn Event>) {
-        // 尝试将事件转换为具体类型
-        if let Some(typed_event) = event.as_any().downcast_ref::<E>() {
-            self.listener.on_event(typed_event);
-        }
+impl Event for ApplicationEnvironmentPreparedEvent {
+    fn event_name(&self) -> &str {
+        "ApplicationEnvironmentPreparedEvent"
     }
 
-    fn listener_name(&self) -> &str {
-        self.listener.listener_name()
+    fn timestamp(&self) -> SystemTime {
+        self.timestamp
     }
 
-    fn supports_event(&self, event_name: &str) -> bool {
-        // 只支持特定类型的事件
-        // 这里我们通过类型名称来判断
-        let event_type_name = std::any::type_name::<E>();
-        // 提取类型名称的最后一部分（去掉路径）
-        let short_name = event_type_name
-            .split("::")
-            .last()
-            .unwrap_or(event_type_name);
-        event_name == short_name
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 }
 
-/// 错误处理器类型
-///
-/// 用于处理监听器执行过程中的错误
-pub type ErrorHandler = Arc<dyn Fn(&dyn EventListener, Arc<dyn Event>, &anyhow::Error) + Send + Sync>;
-
-/// 事件多播器 trait
+/// 上下文准备完成事件
 ///
-/// 类似 Spring 的 ApplicationEventMulticaster
-/// 负责将事件传播到所有注册的监听器
-pub trait ApplicationEventMulticaster: Send + Sync {
-    /// 添加监听器
-    fn add_listener(&self, listener: Arc<dyn EventListener>);
+/// 在初始化器和插件配置阶段执行完成后、组件扫描之前触发
+#[derive(Clone)]
+pub struct ApplicationContextPreparedEvent {
+    /// 应用上下文
+    pub context: Arc<ApplicationContext>,
+    /// 事件时间戳
+    pub timestamp: SystemTime,
+}
 
-    /// 移除监听器
-    fn remove_listener(&self, listener_name: &str);
+impl ApplicationContextPreparedEvent {
+    pub fn new(context: Arc<ApplicationContext>) -> Self {
+        Self {
+            context,
+            timestamp: SystemTime::now(),
+        }
+    }
+}
 
-    /// 移除所有监听器
-    fn remove_all_listeners(&self);
+impl Event for ApplicationContextPreparedEvent {
+    fn event_name(&self) -> &str {
+        "ApplicationContextPreparedEvent"
+    }
 
-    /// 广播事件到所有监听器
-    ///
-    /// 同步模式下，监听器抛出的异常会直接传递给发布线程（可能中断后续监听器执行）
-    /// 可通过设置 errorHandler 统一处理异常，避免单个监听器异常影响整体
-    fn multicast_event(&self, event: Arc<dyn Event>);
+    fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
 
-    /// 获取监听器数量
-    fn listener_count(&self) -> usize;
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
-/// 简单事件多播器实现
+/// Bean 初始化完成事件
 ///
-/// 默认同步执行，支持异步扩展
-pub struct SimpleApplicationEventMulticaster {
-    /// 事件监听器列表
-    listeners: RwLock<Vec<Arc<dyn EventListener>>>,
-    /// 监听器名称到索引的映射
-    listener_names: RwLock<HashMap<String, usize>>,
-    /// 错误处理器
-    error_handler: RwLock<Option<ErrorHandler>>,
-    /// 是否异步执行（如果为 true，会spawn到runtime）
-    async_mode: bool,
+/// 在所有非延迟加载的单例 Bean 初始化完成后、插件启动之前触发
+#[derive(Debug, Clone)]
+pub struct ApplicationBeansInitializedEvent {
+    /// 已注册的 Bean 数量
+    pub bean_count: usize,
+    /// 事件时间戳
+    pub timestamp: SystemTime,
 }
 
-impl SimpleApplicationEventMulticaster {
-    /// 创建同步模式的多播器
-    pub fn new() -> Self {
+impl ApplicationBeansInitializedEvent {
+    pub fn new(bean_count: usize) -> Self {
         Self {
-            listeners: RwLock::new(Vec::new()),
-            listener_names: RwLock::new(HashMap::new()),
-            error_handler: RwLock::new(None),
-            async_mode: false,
+            bean_count,
+            timestamp: SystemTime::now(),
         }
     }
+}
 
-    /// 创建异步模式的多播器
-    pub fn new_async() -> Self {
-        Self {
-            listeners: RwLock::new(Vec::new()),
-            listener_names: RwLock::new(HashMap::new()),
-            error_handler: RwLock::new(None),
-            async_mode: true,
-        }
+impl Event for ApplicationBeansInitializedEvent {
+    fn event_name(&self) -> &str {
+        "ApplicationBeansInitializedEvent"
     }
 
-    /// 设置错误处理器
-    pub fn set_error_handler<F>(&self, handler: F)
-    where
-        F: Fn(&dyn EventListener, Arc<dyn Event>, &anyhow::Error) + Send + Sync + 'static,
-    {
-        let mut error_handler = self.error_handler.write();
-        *error_handler = Some(Arc::new(handler));
+    fn timestamp(&self) -> SystemTime {
+        self.timestamp
     }
 
-    /// 移除错误处理器
-    pub fn remove_error_handler(&self) {
-        let mut error_handler = self.error_handler.write();
-        *error_handler = None;
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 }
 
-impl Default for SimpleApplicationEventMulticaster {
-    fn default() -> Self {
-        Self::new()
-    }
+/// 应用就绪事件
+///
+/// 在 ApplicationStartedEvent 之后触发，表示应用已可以处理请求
+#[derive(Debug, Clone)]
+pub struct ApplicationReadyEvent {
+    /// 应用名称
+    pub app_name: String,
+    /// 启动耗时（毫秒）
+    pub startup_time_ms: u128,
+    /// 事件时间戳
+    pub timestamp: SystemTime,
 }
 
-impl ApplicationEventMulticaster for SimpleApplicationEventMulticaster {
-    fn add_listener(&self, listener: Arc<dyn EventListener>) {
-        let mut listeners = self.listeners.write();
-        let mut names = self.listener_names.write();
-
-        let listener_name = listener.listener_name().to_string();
-        let index = listeners.len();
-
-        listeners.push(listener);
-        names.insert(listener_name.clone(), index);
+impl ApplicationReadyEvent {
+    pub fn new(app_name: String, startup_time_ms: u128) -> Self {
+        Self {
+            app_name,
+            startup_time_ms,
+            timestamp: SystemTime::now(),
+        }
+    }
+}
 
-        tracing::debug!("Added event listener: {}", listener_name);
+impl Event for ApplicationReadyEvent {
+    fn event_name(&self) -> &str {
+        "ApplicationReadyEvent"
     }
 
-    fn remove_listener(&self, listener_name: &str) {
-        let mut listeners = self.listeners.write();
-        let mut names = self.listener_names.write();
+    fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
 
-        if let Some(&index) = names.get(listener_name) {
-            listeners.remove(index);
-            names.remove(listener_name);
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
 
-            // 更新后续索引
-            for (_, idx) in names.iter_mut() {
-                if *idx > index {
-                    *idx -= 1;
-                }
-            }
+/// 应用启动失败事件
+///
+/// 在 `ChimeraApplication::run` 的任一阶段失败时触发，ApplicationContext 已创建时
+/// 所有监听器都能收到，否则只有早期监听器能收到
+#[derive(Debug, Clone)]
+pub struct ApplicationFailedEvent {
+    /// 启动失败的原因
+    ///
+    /// 框架发布的事件中为原始错误的副本：保留错误链中每一层的信息，但不能向下转型为具体的错误类型
+    /// （`ChimeraApplication::start` 返回的原始错误可以），需要诊断信息时使用 `analysis`
+    pub error: Arc<anyhow::Error>,
+    /// `FailureAnalyzer` 给出的诊断报告（无法识别错误时为 None）
+    pub analysis: Option<FailureAnalysis>,
+    /// 事件时间戳
+    pub timestamp: SystemTime,
+}
 
-            tracing::debug!("Removed event listener: {}", listener_name);
+impl ApplicationFailedEvent {
+    pub fn new(error: Arc<anyhow::Error>) -> Self {
+        Self {
+            error,
+            analysis: None,
+            timestamp: SystemTime::now(),
         }
     }
 
-    fn remove_all_listeners(&self) {
-        let mut listeners = self.listeners.write();
-        let mut names = self.listener_names.write();
-
-        listeners.clear();
-        names.clear();
-
-        tracing::debug!("Removed all event listeners");
+    /// 附加诊断报告
+    pub fn with_analysis(mut self, analysis: Option<FailureAnalysis>) -> Self {
+        self.analysis = analysis;
+        self
     }
+}
 
-    fn multicast_event(&self, event: Arc<dyn Event>) {
-        let listeners = self.listeners.read();
-        let event_name = event.event_name();
-
-        tracing::debug!(
-            "Multicasting event: {} to {} listener(s) (async_mode: {})",
-            event_name,
-            listeners.len(),
-            self.async_mode
-        );
+impl Event for ApplicationFailedEvent {
+    fn event_name(&self) -> &str {
+        "ApplicationFailedEvent"
+    }
 
-        // 克隆监听器列表，避免长时间持锁
-        let listeners_clone: Vec<_> = listeners
-            .iter()
-            .filter(|l| l.supports_event(event_name))
-            .map(Arc::clone)
-            .collect();
+    fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
 
-        drop(listeners);
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
 
-        // 获取错误处理器
-        let error_handler = self.error_handler.read().clone();
+/// 插件启动完成事件
+#[derive(Debug, Clone)]
+pub struct PluginStartedEvent {
+    /// 插件名称
+    pub plugin_name: String,
+    /// 事件时间戳
+    pub timestamp: SystemTime,
+}
 
-        if self.async_mode {
-            // 异步模式：spawn到runtime
-            for listener in listeners_clone {
-                let event_clone = Arc::clone(&event);
-                let error_handler_clone = error_handler.clone();
-
-                if let Ok(handle) = tokio::runtime::Handle::try_current() {
-                    handle.spawn(async move {
-                        if let Err(e) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                            listener.on_event(event_clone.clone());
-                        })) {
-                            let err = anyhow::anyhow!("Listener panicked: {:?}", e);
-                            if let Some(handler) = error_handler_clone {
-                                handler(listener.as_ref(), event_clone, &err);
-                            } else {
-                                tracing::error!(
-                                    "Listener '{}' panicked while handling event '{}': {:?}",
-                                    listener.listener_name(),
-                                    event_clone.event_name(),
-                                    err
-                                );
-                            }
-                        }
-                    });
-                } else {
-                    // 没有runtime，降级为同步执行
-                    tracing::warn!("No tokio runtime available, falling back to sync execution");
-                    self.invoke_listener(&listener, Arc::clone(&event), error_handler.as_ref());
-                }
-            }
-        } else {
-            // 同步模式：顺序执行
-            for listener in listeners_clone {
-                self.invoke_listener(&listener, Arc::clone(&event), error_handler.as_ref());
-            }
+impl PluginStartedEvent {
+    pub fn new(plugin_name: String) -> Self {
+        Self {
+            plugin_name,
+            timestamp: SystemTime::now(),
         }
     }
+}
 
-    fn listener_count(&self) -> usize {
-        self.listeners.read().len()
+impl Event for PluginStartedEvent {
+    fn event_name(&self) -> &str {
+        "PluginStartedEvent"
     }
-}
 
-impl SimpleApplicationEventMulticaster {
-    /// 调用单个监听器
-    fn invoke_listener(
-        &self,
-        listener: &Arc<dyn EventListener>,
-        event: Arc<dyn Event>,
-        error_handler: Option<&ErrorHandler>,
-    ) {
-        // 使用 catch_unwind 捕获 panic
-        if let Err(e) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-            listener.on_event(event.clone());
-        })) {
-            let err = anyhow::anyhow!("Listener panicked: {:?}", e);
-            if let Some(handler) = error_handler {
-                handler(listener.as_ref(), event, &err);
-            } else {
-                // 没有错误处理器，重新抛出
-                tracing::error!(
-                    "Listener '{}' panicked while handling event '{}': {:?}",
-                    listener.listener_name(),
-                    event.event_name(),
-                    err
-                );
-                std::panic::resume_unwind(e);
-            }
-        }
+    fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 }
 
-/// 事件发布器
-///
+/// 插件关闭完成事件
+#[derive(Debug, Clone)]
+pub struct PluginStoppedEvent {
+    /// 插件名称
+    pub plugin_name: String,
+    /// 事件时间戳
+    pub timestamp: SystemTime,
+}
+
+impl PluginStoppedEvent {
+    pub fn new(plugin_name: String) -> Self {
+        Self {
+            plugin_name,
+            timestamp: SystemTime::now(),
+        }
+    }
+}
+
+impl Event for PluginStoppedEvent {
+    fn event_name(&self) -> &str {
+        "PluginStoppedEvent"
+    }
+
+    fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// 配置变更事件
+///
+/// 在配置热加载替换了某个配置源后触发，携带发生变化的配置键
+#[derive(Debug, Clone)]
+pub struct EnvironmentChangeEvent {
+    /// 被替换的配置源名称
+    pub source_name: String,
+    /// 发生变化的配置键
+    pub keys: Vec<String>,
+    /// 事件时间戳
+    pub timestamp: SystemTime,
+}
+
+impl EnvironmentChangeEvent {
+    pub fn new(source_name: String, keys: Vec<String>) -> Self {
+        Self {
+            source_name,
+            keys,
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    /// 检查指定配置键（或以其为前缀的配置键）是否发生了变化
+    pub fn is_changed(&self, key_or_prefix: &str) -> bool {
+        self.keys.iter().any(|k| {
+            k == key_or_prefix
+                || k.strip_prefix(key_or_prefix)
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
+    }
+}
+
+impl Event for EnvironmentChangeEvent {
+    fn event_name(&self) -> &str {
+        "EnvironmentChangeEvent"
+    }
+
+    fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// 事件监听器 trait
+///
+/// 类似 Spring 的 ApplicationListener
+/// 默认同步执行，支持异步扩展
+pub trait EventListener: Send + Sync {
+    /// 处理事件（同步）
+    fn on_event(&self, event: Arc<dyn Event>);
+
+    /// 获取监听器名称（用于日志）
+    fn listener_name(&self) -> &str {
+        "AnonymousListener"
+    }
+
+    /// 是否支持该事件类型（可选实现，默认支持所有事件）
+    fn supports_event(&self, event_name: &str) -> bool {
+        let _ = event_name;
+        true
+    }
+
+    /// 监听的事件类型（具体事件类型或事件声明的超类型）
+    ///
+    /// 返回 None 表示监听所有事件，此时仅通过 `supports_event` 过滤
+    fn supported_event_types(&self) -> Option<Vec<TypeId>> {
+        None
+    }
+
+    /// 监听的事件类型名称（用于监听器信息查询，默认为空）
+    fn supported_event_type_names(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// 执行顺序（值越小越先执行，默认 0）
+    fn order(&self) -> i32 {
+        0
+    }
+
+    /// 执行方式（默认跟随多播器的同步/异步模式）
+    fn executor(&self) -> ListenerExecutor {
+        ListenerExecutor::Default
+    }
+
+    /// `handle_event` 是否需要等待异步操作
+    ///
+    /// 同步监听器在发布线程中直接执行完成；异步监听器的 Future 整体提交到 tokio runtime 执行。
+    /// 覆盖 `handle_event` 并在其中 `.await` 的监听器需要返回 true
+    fn is_async(&self) -> bool {
+        false
+    }
+
+    /// 绑定的事务阶段
+    ///
+    /// 返回 Some 时，事务中发布的事件会在事务到达该阶段时才交给此监听器
+    fn transaction_phase(&self) -> Option<TransactionPhase> {
+        None
+    }
+
+    /// 没有活动事务时是否仍然执行（仅对事务监听器有效，默认跳过）
+    fn fallback_execution(&self) -> bool {
+        false
+    }
+
+    /// 处理事件并返回结果
+    ///
+    /// 多播器通过此方法调用监听器，返回的错误会交给 ErrorHandler 处理，返回的事件会作为后续事件发布。
+    /// 默认调用同步的 `on_event`，异步监听器和方法监听器会覆盖此方法
+    fn handle_event(self: Arc<Self>, event: Arc<dyn Event>) -> EventHandlerFuture
+    where
+        Self: 'static,
+    {
+        Box::pin(async move {
+            self.on_event(event);
+            Ok(Vec::new())
+        })
+    }
+}
+
+/// 监听器执行方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ListenerExecutor {
+    /// 跟随多播器模式：同步模式下在发布线程执行，异步模式下提交到 tokio runtime
+    #[default]
+    Default,
+    /// 始终在发布线程执行
+    Inline,
+    /// 提交到 tokio runtime 执行
+    Spawn,
+    /// 提交到 tokio 阻塞线程池执行（适用于包含阻塞 IO 的监听器）
+    Blocking,
+}
+
+/// 异步事件监听器 trait
+///
+/// 监听方法可以 `.await`，返回的错误会交给多播器的 ErrorHandler 处理，
+/// 通过 `ApplicationContext::register_async_listener` 注册
+///
+/// ```ignore
+/// struct AuditWriter { db: Arc<Database> }
+///
+/// #[async_trait]
+/// impl AsyncEventListener for AuditWriter {
+///     async fn on_event(&self, event: Arc<dyn Event>) -> anyhow::Result<()> {
+///         self.db.insert_audit(event.event_name()).await?;
+///         Ok(())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait AsyncEventListener: Send + Sync + 'static {
+    /// 处理事件
+    async fn on_event(&self, event: Arc<dyn Event>) -> anyhow::Result<()>;
+
+    /// 获取监听器名称（用于日志）
+    fn listener_name(&self) -> &str {
+        "AnonymousAsyncListener"
+    }
+
+    /// 是否支持该事件类型（默认支持所有事件）
+    fn supports_event(&self, event_name: &str) -> bool {
+        let _ = event_name;
+        true
+    }
+
+    /// 监听的事件类型，None 表示监听所有事件
+    fn supported_event_types(&self) -> Option<Vec<TypeId>> {
+        None
+    }
+
+    /// 监听的事件类型名称（用于监听器信息查询）
+    fn supported_event_type_names(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// 执行顺序（值越小越先执行，默认 0）
+    fn order(&self) -> i32 {
+        0
+    }
+
+    /// 执行方式
+    fn executor(&self) -> ListenerExecutor {
+        ListenerExecutor::Default
+    }
+}
+
+/// 异步事件监听器适配器
+///
+/// 将 AsyncEventListener 适配为 EventListener
+pub struct AsyncEventListenerAdapter<L: AsyncEventListener> {
+    listener: Arc<L>,
+}
+
+impl<L: AsyncEventListener> AsyncEventListenerAdapter<L> {
+    pub fn new(listener: Arc<L>) -> Self {
+        Self { listener }
+    }
+}
+
+impl<L: AsyncEventListener> EventListener for AsyncEventListenerAdapter<L> {
+    fn on_event(&self, event: Arc<dyn Event>) {
+        let listener = Arc::clone(&self.listener);
+        let event_name = event.event_name().to_string();
+        run_detached(
+            self.listener.listener_name(),
+            event_name,
+            async move { listener.on_event(event).await },
+        );
+    }
+
+    fn listener_name(&self) -> &str {
+        self.listener.listener_name()
+    }
+
+    fn supports_event(&self, event_name: &str) -> bool {
+        self.listener.supports_event(event_name)
+    }
+
+    fn supported_event_types(&self) -> Option<Vec<TypeId>> {
+        self.listener.supported_event_types()
+    }
+
+    fn supported_event_type_names(&self) -> Vec<String> {
+        self.listener.supported_event_type_names()
+    }
+
+    fn order(&self) -> i32 {
+        self.listener.order()
+    }
+
+    fn executor(&self) -> ListenerExecutor {
+        self.listener.executor()
+    }
+
+    fn is_async(&self) -> bool {
+        true
+    }
+
+    fn handle_event(self: Arc<Self>, event: Arc<dyn Event>) -> EventHandlerFuture {
+        Box::pin(async move {
+            self.listener.on_event(event).await?;
+            Ok(Vec::new())
+        })
+    }
+}
+
+/// 在当前 tokio runtime 中执行监听器 Future，失败时记录日志
+///
+/// 没有 runtime 时无法执行异步监听器，记录错误
+fn run_detached<T>(
+    listener_name: &str,
+    event_name: String,
+    future: impl Future<Output = anyhow::Result<T>> + Send + 'static,
+) {
+    let listener_name = listener_name.to_string();
+    let handle = match tokio::runtime::Handle::try_current() {
+        Ok(handle) => handle,
+        Err(_) => {
+            tracing::error!(
+                "No tokio runtime available to run async listener '{}' for event '{}'",
+                listener_name,
+                event_name
+            );
+            return;
+        }
+    };
+
+    handle.spawn(async move {
+        if let Err(e) = future.await {
+            tracing::error!(
+                "Listener '{}' failed while handling event '{}': {:#}",
+                listener_name,
+                event_name,
+                e
+            );
+        }
+    });
+}
+
+/// 类型化事件监听器 trait
+///
+/// 提供类型安全的事件处理
+pub trait TypedEventListener<E: Event>: Send + Sync {
+    /// 处理特定类型的事件
+    fn on_event(&self, event: &E);
+
+    /// 获取监听器名称（用于日志）
+    fn listener_name(&self) -> &str {
+        "AnonymousTypedListener"
+    }
+}
+
+/// 类型化事件监听器适配器
+///
+/// 将 TypedEventListener<E> 适配为 EventListener
+pub struct TypedEventListenerAdapter<E: Event + 'static, L: TypedEventListener<E>> {
+    listener: Arc<L>,
+    _phantom: std::marker::PhantomData<E>,
+}
+
+impl<E: Event + 'static, L: TypedEventListener<E>> TypedEventListenerAdapter<E, L> {
+    pub fn new(listener: Arc<L>) -> Self {
+        Self {
+            listener,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<E: Event + 'static, L: TypedEventListener<E> + 'static> EventListener
+    for TypedEventListenerAdapter<E, L>
+{
+    fn on_event(&self, event: Arc<dyn Event>) {
+        // 尝试将事件转换为具体类型
+        if let Some(typed_event) = event.as_any().downcast_ref::<E>() {
+            self.listener.on_event(typed_event);
+        }
+    }
+
+    fn listener_name(&self) -> &str {
+        self.listener.listener_name()
+    }
+
+    fn supported_event_types(&self) -> Option<Vec<TypeId>> {
+        // 按 TypeId 匹配，不依赖事件名称
+        Some(vec![TypeId::of::<E>()])
+    }
+
+    fn supported_event_type_names(&self) -> Vec<String> {
+        vec![std::any::type_name::<E>().to_string()]
+    }
+}
+
+/// 异步事件处理函数返回的 Future
+///
+/// 成功时返回需要继续发布的后续事件
+pub type EventHandlerFuture = Pin<Box<dyn Future<Output = anyhow::Result<Vec<Arc<dyn Event>>>> + Send>>;
+
+/// 监听方法返回值到后续事件的转换
+///
+/// `#[event_listener]` 方法可以返回 `()`、某个事件、`Option<事件>`、`Vec<事件>`、
+/// `Arc<dyn Event>` 或它们的 `Result`，返回的事件会由多播器继续发布
+pub trait FollowUpEvents {
+    /// 转换为后续事件列表
+    fn into_follow_up_events(self) -> anyhow::Result<Vec<Arc<dyn Event>>>;
+}
+
+impl FollowUpEvents for () {
+    fn into_follow_up_events(self) -> anyhow::Result<Vec<Arc<dyn Event>>> {
+        Ok(Vec::new())
+    }
+}
+
+impl<E: Event> FollowUpEvents for E {
+    fn into_follow_up_events(self) -> anyhow::Result<Vec<Arc<dyn Event>>> {
+        Ok(vec![Arc::new(self)])
+    }
+}
+
+impl<E: Event> FollowUpEvents for Option<E> {
+    fn into_follow_up_events(self) -> anyhow::Result<Vec<Arc<dyn Event>>> {
+        Ok(self.map(|e| Arc::new(e) as Arc<dyn Event>).into_iter().collect())
+    }
+}
+
+impl<E: Event> FollowUpEvents for Vec<E> {
+    fn into_follow_up_events(self) -> anyhow::Result<Vec<Arc<dyn Event>>> {
+        Ok(self.into_iter().map(|e| Arc::new(e) as Arc<dyn Event>).collect())
+    }
+}
+
+impl FollowUpEvents for Arc<dyn Event> {
+    fn into_follow_up_events(self) -> anyhow::Result<Vec<Arc<dyn Event>>> {
+        Ok(vec![self])
+    }
+}
+
+impl FollowUpEvents for Vec<Arc<dyn Event>> {
+    fn into_follow_up_events(self) -> anyhow::Result<Vec<Arc<dyn Event>>> {
+        Ok(self)
+    }
+}
+
+impl<T: FollowUpEvents> FollowUpEvents for anyhow::Result<T> {
+    fn into_follow_up_events(self) -> anyhow::Result<Vec<Arc<dyn Event>>> {
+        self?.into_follow_up_events()
+    }
+}
+
+type SyncEventHandler<E> = Box<dyn Fn(&E) -> anyhow::Result<Vec<Arc<dyn Event>>> + Send + Sync>;
+type AsyncEventHandler = Box<dyn Fn(Arc<dyn Event>) -> EventHandlerFuture + Send + Sync>;
+type EventCondition<E> = Box<dyn Fn(&E) -> bool + Send + Sync>;
+
+/// 方法监听器的处理函数
+enum MethodHandler<E> {
+    /// 同步方法
+    Sync(SyncEventHandler<E>),
+    /// 异步方法（接收原始事件，在 Future 内部完成类型转换）
+    Async(AsyncEventHandler),
+}
+
+/// 方法级事件监听器
+///
+/// 由 `#[component]` impl 块中标记了 `#[event_listener]` 的方法生成，
+/// 只处理类型为 E 的事件，一个 Bean 可以通过多个方法监听多种事件
+///
+/// ```ignore
+/// #[component]
+/// impl OrderService {
+///     #[event_listener(order = 1)]
+///     fn on_created(&self, event: &OrderCreatedEvent) { }
+///
+///     #[event_listener(condition = "event.amount > 100")]
+///     async fn on_paid(&self, event: &OrderPaidEvent) -> Result<()> { Ok(()) }
+/// }
+/// ```
+pub struct MethodEventListener<E: Event> {
+    name: String,
+    order: i32,
+    executor: ListenerExecutor,
+    transaction_phase: Option<TransactionPhase>,
+    fallback_execution: bool,
+    condition: Option<EventCondition<E>>,
+    handler: MethodHandler<E>,
+}
+
+impl<E: Event> MethodEventListener<E> {
+    /// 创建同步方法监听器
+    ///
+    /// 处理函数返回的事件会作为后续事件继续发布
+    pub fn new<F>(name: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(&E) -> anyhow::Result<Vec<Arc<dyn Event>>> + Send + Sync + 'static,
+    {
+        Self {
+            name: name.into(),
+            order: 0,
+            executor: ListenerExecutor::Default,
+            transaction_phase: None,
+            fallback_execution: false,
+            condition: None,
+            handler: MethodHandler::Sync(Box::new(handler)),
+        }
+    }
+
+    /// 创建异步方法监听器
+    pub fn new_async<F>(name: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(Arc<dyn Event>) -> EventHandlerFuture + Send + Sync + 'static,
+    {
+        Self {
+            name: name.into(),
+            order: 0,
+            executor: ListenerExecutor::Default,
+            transaction_phase: None,
+            fallback_execution: false,
+            condition: None,
+            handler: MethodHandler::Async(Box::new(handler)),
+        }
+    }
+
+    /// 设置执行顺序
+    pub fn with_order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// 设置执行方式
+    pub fn with_executor(mut self, executor: ListenerExecutor) -> Self {
+        self.executor = executor;
+        self
+    }
+
+    /// 绑定到事务阶段
+    pub fn with_transaction_phase(mut self, phase: TransactionPhase) -> Self {
+        self.transaction_phase = Some(phase);
+        self
+    }
+
+    /// 设置没有活动事务时是否仍然执行
+    pub fn with_fallback_execution(mut self, fallback_execution: bool) -> Self {
+        self.fallback_execution = fallback_execution;
+        self
+    }
+
+    /// 设置执行条件，条件不满足时跳过该事件
+    pub fn with_condition<F>(mut self, condition: F) -> Self
+    where
+        F: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        self.condition = Some(Box::new(condition));
+        self
+    }
+
+    /// 转换事件类型并检查条件，不需要处理时返回 None
+    fn accept<'a>(&self, event: &'a dyn Event) -> Option<&'a E> {
+        let typed_event = event.as_any().downcast_ref::<E>()?;
+
+        if let Some(condition) = &self.condition {
+            if !condition(typed_event) {
+                tracing::trace!(
+                    "Listener '{}' skipped event '{}': condition not met",
+                    self.name,
+                    event.event_name()
+                );
+                return None;
+            }
+        }
+
+        Some(typed_event)
+    }
+}
+
+impl<E: Event> EventListener for MethodEventListener<E> {
+    fn on_event(&self, event: Arc<dyn Event>) {
+        let typed_event = match self.accept(event.as_ref()) {
+            Some(typed_event) => typed_event,
+            None => return,
+        };
+
+        match &self.handler {
+            // 直接调用时没有多播器，返回的后续事件不会被发布
+            MethodHandler::Sync(handler) => {
+                if let Err(e) = handler(typed_event) {
+                    tracing::error!(
+                        "Listener '{}' failed while handling event '{}': {:#}",
+                        self.name,
+                        event.event_name(),
+                        e
+                    );
+                }
+            }
+            MethodHandler::Async(handler) => {
+                let event_name = event.event_name().to_string();
+                run_detached(&self.name, event_name, handler(Arc::clone(&event)));
+            }
+        }
+    }
+
+    fn listener_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supported_event_types(&self) -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<E>()])
+    }
+
+    fn supported_event_type_names(&self) -> Vec<String> {
+        vec![std::any::type_name::<E>().to_string()]
+    }
+
+    fn order(&self) -> i32 {
+        self.order
+    }
+
+    fn executor(&self) -> ListenerExecutor {
+        self.executor
+    }
+
+    fn transaction_phase(&self) -> Option<TransactionPhase> {
+        self.transaction_phase
+    }
+
+    fn fallback_execution(&self) -> bool {
+        self.fallback_execution
+    }
+
+    fn is_async(&self) -> bool {
+        matches!(self.handler, MethodHandler::Async(_))
+    }
+
+    fn handle_event(self: Arc<Self>, event: Arc<dyn Event>) -> EventHandlerFuture {
+        Box::pin(async move {
+            let typed_event = match self.accept(event.as_ref()) {
+                Some(typed_event) => typed_event,
+                None => return Ok(Vec::new()),
+            };
+
+            match &self.handler {
+                MethodHandler::Sync(handler) => handler(typed_event),
+                MethodHandler::Async(handler) => handler(Arc::clone(&event)).await,
+            }
+        })
+    }
+}
+
+/// 错误处理器类型
+///
+/// 用于处理监听器执行过程中的错误
+pub type ErrorHandler = Arc<dyn Fn(&dyn EventListener, Arc<dyn Event>, &anyhow::Error) + Send + Sync>;
+
+/// 后续事件的默认最大链路深度
+pub const DEFAULT_MAX_FOLLOW_UP_DEPTH: usize = 8;
+
+/// 事件多播器 trait
+///
+/// 类似 Spring 的 ApplicationEventMulticaster
+/// 负责将事件传播到所有注册的监听器
+#[async_trait]
+pub trait ApplicationEventMulticaster: Send + Sync {
+    /// 添加监听器，返回用于注销该监听器的句柄
+    fn add_listener(&self, listener: Arc<dyn EventListener>) -> ListenerHandle;
+
+    /// 按名称移除监听器（同名的监听器全部移除）
+    fn remove_listener(&self, listener_name: &str);
+
+    /// 移除所有监听器
+    fn remove_all_listeners(&self);
+
+    /// 广播事件到所有监听器
+    ///
+    /// 同步模式下，监听器抛出的异常会直接传递给发布线程（可能中断后续监听器执行）
+    /// 可通过设置 errorHandler 统一处理异常，避免单个监听器异常影响整体
+    fn multicast_event(&self, event: Arc<dyn Event>);
+
+    /// 广播事件并等待所有监听器执行完成
+    ///
+    /// 监听器按 order 依次执行，单个监听器失败不影响后续监听器，
+    /// 任一监听器失败时返回错误（默认实现直接调用 `multicast_event`）
+    async fn multicast_event_and_wait(&self, event: Arc<dyn Event>) -> anyhow::Result<()> {
+        self.multicast_event(event);
+        Ok(())
+    }
+
+    /// 设置监听器返回的后续事件的最大链路深度
+    fn set_max_follow_up_depth(&self, max_depth: usize);
+
+    /// 设置是否允许同一监听器在一条链路中再次发布相同类型的后续事件
+    ///
+    /// 默认不允许（视为循环），允许后仅由最大链路深度终止循环
+    fn set_allow_repeated_follow_ups(&self, allow: bool);
+
+    /// 获取监听器数量
+    fn listener_count(&self) -> usize;
+
+    /// 获取所有监听器的信息（按执行顺序，默认实现返回空）
+    fn listener_descriptors(&self) -> Vec<ListenerDescriptor> {
+        Vec::new()
+    }
+
+    /// 获取事件发布和监听器执行的统计信息（默认实现返回空）
+    fn metrics(&self) -> EventMetricsSnapshot {
+        EventMetricsSnapshot::default()
+    }
+
+    /// 清空统计信息
+    fn reset_metrics(&self) {}
+}
+
+/// 监听器句柄
+///
+/// 由 `add_listener` 返回，通过注册时分配的 ID 注销监听器，同名监听器互不影响。
+/// 句柄被丢弃时监听器保持注册；需要随作用域自动注销时使用 `into_guard`
+#[derive(Clone)]
+pub struct ListenerHandle {
+    id: u64,
+    name: String,
+    unregister: Arc<dyn Fn(u64) -> bool + Send + Sync>,
+}
+
+impl ListenerHandle {
+    /// 创建句柄（供 ApplicationEventMulticaster 实现使用）
+    pub fn new<F>(id: u64, name: impl Into<String>, unregister: F) -> Self
+    where
+        F: Fn(u64) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            id,
+            name: name.into(),
+            unregister: Arc::new(unregister),
+        }
+    }
+
+    /// 监听器 ID
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 监听器名称
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 注销监听器，返回监听器是否仍处于注册状态并被移除
+    pub fn unregister(&self) -> bool {
+        (self.unregister)(self.id)
+    }
+
+    /// 转换为守卫，守卫被丢弃时自动注销监听器
+    pub fn into_guard(self) -> ListenerGuard {
+        ListenerGuard { handle: Some(self) }
+    }
+}
+
+impl std::fmt::Debug for ListenerHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListenerHandle")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+/// 监听器守卫
+///
+/// 被丢弃时自动注销监听器，适用于只在某个作用域内有效的临时监听器
+#[derive(Debug)]
+pub struct ListenerGuard {
+    handle: Option<ListenerHandle>,
+}
+
+impl ListenerGuard {
+    /// 获取句柄
+    pub fn handle(&self) -> &ListenerHandle {
+        self.handle.as_ref().expect("listener guard already released")
+    }
+
+    /// 解除守卫，监听器保持注册
+    pub fn release(mut self) -> ListenerHandle {
+        self.handle.take().expect("listener guard already released")
+    }
+}
+
+impl Drop for ListenerGuard {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.unregister();
+        }
+    }
+}
+
+/// 监听器信息
+#[derive(Debug, Clone, Serialize)]
+pub struct ListenerDescriptor {
+    /// 监听器 ID
+    pub id: u64,
+    /// 监听器名称
+    pub name: String,
+    /// 执行顺序
+    pub order: i32,
+    /// 实际执行方式（inline、spawn、blocking）
+    pub executor: String,
+    /// 是否异步执行（不阻塞发布线程）
+    pub asynchronous: bool,
+    /// 监听的事件类型，为空表示监听所有事件
+    pub event_types: Vec<String>,
+    /// 绑定的事务阶段
+    pub transaction_phase: Option<String>,
+}
+
+/// 事件统计信息快照
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EventMetricsSnapshot {
+    /// 各事件的发布统计（按阻塞发布线程的总耗时降序）
+    pub events: Vec<EventTypeMetrics>,
+    /// 各监听器的执行统计（按总耗时降序）
+    pub listeners: Vec<ListenerMetrics>,
+}
+
+/// 单个事件的发布统计
+#[derive(Debug, Clone, Serialize)]
+pub struct EventTypeMetrics {
+    /// 事件名称
+    pub event: String,
+    /// 发布次数（包含后续事件）
+    pub published: u64,
+    /// 发布线程等待监听器执行的总耗时（毫秒）
+    pub publisher_blocked_total_ms: f64,
+    /// 发布线程单次等待的最大耗时（毫秒）
+    pub publisher_blocked_max_ms: f64,
+}
+
+/// 单个监听器的执行统计
+#[derive(Debug, Clone, Serialize)]
+pub struct ListenerMetrics {
+    /// 监听器 ID（与 `ListenerDescriptor::id` 对应）
+    pub id: u64,
+    /// 监听器名称
+    pub listener: String,
+    /// 执行次数
+    pub invocations: u64,
+    /// 失败次数（包括 panic）
+    pub failures: u64,
+    /// 总耗时（毫秒）
+    pub total_ms: f64,
+    /// 平均耗时（毫秒）
+    pub mean_ms: f64,
+    /// 最大耗时（毫秒）
+    pub max_ms: f64,
+}
+
+/// 累计的耗时统计
+#[derive(Default)]
+struct TimerStats {
+    count: u64,
+    failures: u64,
+    total: Duration,
+    max: Duration,
+}
+
+impl TimerStats {
+    fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total += duration;
+        self.max = self.max.max(duration);
+    }
+}
+
+/// 事件统计
+#[derive(Default)]
+struct EventMetrics {
+    /// 事件类型 -> 事件名称和发布次数、阻塞发布线程的耗时
+    ///
+    /// 按事件类型统计，名称相同的不同事件类型分别统计
+    events: Mutex<HashMap<TypeId, (String, TimerStats)>>,
+    /// 监听器 ID -> 监听器名称和执行耗时
+    ///
+    /// 按注册 ID 统计，同名监听器（例如默认名称的监听器或同一事件类型的多个闭包监听器）分别统计
+    listeners: Mutex<HashMap<u64, (String, TimerStats)>>,
+}
+
+impl EventMetrics {
+    fn record_published(&self, event: &dyn Event, blocked: Duration) {
+        let mut events = self.events.lock();
+        let (_, stats) = events
+            .entry(event.as_any().type_id())
+            .or_insert_with(|| (event.event_name().to_string(), TimerStats::default()));
+        stats.record(blocked);
+    }
+
+    fn record_listener(&self, id: u64, listener: &dyn EventListener, duration: Duration, success: bool) {
+        let mut listeners = self.listeners.lock();
+        let (_, stats) = listeners
+            .entry(id)
+            .or_insert_with(|| (listener.listener_name().to_string(), TimerStats::default()));
+        stats.record(duration);
+        if !success {
+            stats.failures += 1;
+        }
+    }
+
+    fn snapshot(&self) -> EventMetricsSnapshot {
+        let millis = |d: Duration| d.as_secs_f64() * 1000.0;
+
+        let mut events: Vec<_> = self
+            .events
+            .lock()
+            .values()
+            .map(|(event, stats)| EventTypeMetrics {
+                event: event.clone(),
+                published: stats.count,
+                publisher_blocked_total_ms: millis(stats.total),
+                publisher_blocked_max_ms: millis(stats.max),
+            })
+            .collect();
+        events.sort_by(|a, b| b.publisher_blocked_total_ms.total_cmp(&a.publisher_blocked_total_ms));
+
+        let mut listeners: Vec<_> = self
+            .listeners
+            .lock()
+            .iter()
+            .map(|(id, (listener, stats))| ListenerMetrics {
+                id: *id,
+                listener: listener.clone(),
+                invocations: stats.count,
+                failures: stats.failures,
+                total_ms: millis(stats.total),
+                mean_ms: if stats.count == 0 { 0.0 } else { millis(stats.total) / stats.count as f64 },
+                max_ms: millis(stats.max),
+            })
+            .collect();
+        listeners.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+
+        EventMetricsSnapshot { events, listeners }
+    }
+
+    fn reset(&self) {
+        self.events.lock().clear();
+        self.listeners.lock().clear();
+    }
+}
+
+/// 简单事件多播器实现
+///
+/// 默认同步执行，支持异步扩展。监听器按 order 排序后执行：
+/// - 在发布线程执行的监听器（同步模式下的默认方式）依次执行
+/// - 提交到 runtime 的监听器（异步模式下的默认方式）在同一个后台任务中依次执行
+///
+/// 事件按 TypeId 路由：监听器通过 `supported_event_types` 声明的类型与事件的具体类型
+/// 及其声明的超类型匹配，每种事件类型匹配到的监听器会被缓存，监听器变化时缓存失效
+///
+/// 监听器返回的后续事件会在该监听器执行完成后立即发布。同一监听器在一条链路中
+/// 再次发布相同类型的事件（循环）或链路深度超过上限时，后续事件不会被发布，错误交给 ErrorHandler
+pub struct SimpleApplicationEventMulticaster {
+    inner: Arc<MulticasterInner>,
+}
+
+/// 已注册的监听器
+#[derive(Clone)]
+struct RegisteredListener {
+    id: u64,
+    listener: Arc<dyn EventListener>,
+}
+
+/// 多播器内部状态（后台任务需要持有它来发布后续事件）
+struct MulticasterInner {
+    /// 事件监听器列表（按注册顺序）
+    listeners: RwLock<Vec<RegisteredListener>>,
+    /// 下一个监听器 ID
+    next_listener_id: AtomicU64,
+    /// 事件类型到已排序监听器的缓存
+    listener_cache: RwLock<HashMap<TypeId, Vec<RegisteredListener>>>,
+    /// 错误处理器
+    error_handler: RwLock<Option<ErrorHandler>>,
+    /// 是否异步执行（如果为 true，会spawn到runtime）
+    async_mode: bool,
+    /// 后续事件的最大链路深度
+    max_follow_up_depth: AtomicUsize,
+    /// 是否允许同一监听器在链路中重复发布相同类型的事件
+    allow_repeated_follow_ups: AtomicBool,
+    /// 事件发布和监听器执行统计
+    metrics: EventMetrics,
+}
+
+/// 事件链路：从最初发布的事件到当前事件经过的每一步
+#[derive(Clone)]
+struct EventChain {
+    steps: Vec<ChainStep>,
+}
+
+/// 链路中的一步：发布该事件的监听器（最初发布的事件没有）和事件类型
+#[derive(Clone)]
+struct ChainStep {
+    listener_id: Option<u64>,
+    event_type: TypeId,
+    event_name: String,
+}
+
+impl EventChain {
+    fn root(event: &dyn Event) -> Self {
+        Self {
+            steps: vec![ChainStep {
+                listener_id: None,
+                event_type: event.as_any().type_id(),
+                event_name: event.event_name().to_string(),
+            }],
+        }
+    }
+
+    /// 将监听器返回的后续事件加入链路
+    ///
+    /// 同一监听器已在链路中发布过相同类型的事件（循环，除非允许重复）或超过最大深度时返回错误
+    fn extend(&self, listener_id: u64, event: &dyn Event, max_depth: usize, allow_repeats: bool) -> anyhow::Result<Self> {
+        let event_type = event.as_any().type_id();
+        let path = || {
+            self.steps
+                .iter()
+                .map(|step| step.event_name.as_str())
+                .chain(std::iter::once(event.event_name()))
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        };
+
+        if !allow_repeats
+            && self
+                .steps
+                .iter()
+                .any(|step| step.listener_id == Some(listener_id) && step.event_type == event_type)
+        {
+            anyhow::bail!("Follow-up event cycle detected: {}", path());
+        }
+        if self.steps.len() > max_depth {
+            anyhow::bail!("Maximum follow-up event depth ({}) exceeded: {}", max_depth, path());
+        }
+
+        let mut steps = self.steps.clone();
+        steps.push(ChainStep {
+            listener_id: Some(listener_id),
+            event_type,
+            event_name: event.event_name().to_string(),
+        });
+        Ok(Self { steps })
+    }
+}
+
+impl SimpleApplicationEventMulticaster {
+    /// 创建同步模式的多播器
+    pub fn new() -> Self {
+        Self::with_mode(false)
+    }
+
+    /// 创建异步模式的多播器
+    pub fn new_async() -> Self {
+        Self::with_mode(true)
+    }
+
+    fn with_mode(async_mode: bool) -> Self {
+        Self {
+            inner: Arc::new(MulticasterInner {
+                listeners: RwLock::new(Vec::new()),
+                next_listener_id: AtomicU64::new(1),
+                listener_cache: RwLock::new(HashMap::new()),
+                error_handler: RwLock::new(None),
+                async_mode,
+                max_follow_up_depth: AtomicUsize::new(DEFAULT_MAX_FOLLOW_UP_DEPTH),
+                allow_repeated_follow_ups: AtomicBool::new(false),
+                metrics: EventMetrics::default(),
+            }),
+        }
+    }
+
+    /// 设置错误处理器
+    pub fn set_error_handler<F>(&self, handler: F)
+    where
+        F: Fn(&dyn EventListener, Arc<dyn Event>, &anyhow::Error) + Send + Sync + 'static,
+    {
+        let mut error_handler = self.inner.error_handler.write();
+        *error_handler = Some(Arc::new(handler));
+    }
+
+    /// 移除错误处理器
+    pub fn remove_error_handler(&self) {
+        let mut error_handler = self.inner.error_handler.write();
+        *error_handler = None;
+    }
+}
+
+impl Default for SimpleApplicationEventMulticaster {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ApplicationEventMulticaster for SimpleApplicationEventMulticaster {
+    fn add_listener(&self, listener: Arc<dyn EventListener>) -> ListenerHandle {
+        let id = self.inner.next_listener_id.fetch_add(1, Ordering::Relaxed);
+        let listener_name = listener.listener_name().to_string();
+
+        self.inner.listeners.write().push(RegisteredListener { id, listener });
+        self.inner.listener_cache.write().clear();
+
+        tracing::debug!("Added event listener: {} (id: {})", listener_name, id);
+
+        // 句柄只持有弱引用，不延长多播器的生命周期
+        let inner: Weak<MulticasterInner> = Arc::downgrade(&self.inner);
+        ListenerHandle::new(id, listener_name, move |id| {
+            inner.upgrade().is_some_and(|inner| inner.remove_by_id(id))
+        })
+    }
+
+    fn remove_listener(&self, listener_name: &str) {
+        let mut listeners = self.inner.listeners.write();
+        let before = listeners.len();
+        listeners.retain(|registered| registered.listener.listener_name() != listener_name);
+
+        if listeners.len() != before {
+            self.inner.listener_cache.write().clear();
+            tracing::debug!(
+                "Removed {} event listener(s) named: {}",
+                before - listeners.len(),
+                listener_name
+            );
+        }
+    }
+
+    fn remove_all_listeners(&self) {
+        let mut listeners = self.inner.listeners.write();
+
+        listeners.clear();
+        self.inner.listener_cache.write().clear();
+
+        tracing::debug!("Removed all event listeners");
+    }
+
+    fn multicast_event(&self, event: Arc<dyn Event>) {
+        let chain = EventChain::root(event.as_ref());
+        self.inner.multicast(event, chain);
+    }
+
+    async fn multicast_event_and_wait(&self, event: Arc<dyn Event>) -> anyhow::Result<()> {
+        let event_name = event.event_name().to_string();
+        let chain = EventChain::root(event.as_ref());
+        let failures = Arc::clone(&self.inner).multicast_and_wait(event, chain).await;
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(
+                "{} listener(s) failed while handling event '{}': {}",
+                failures.len(),
+                event_name,
+                failures.join("; ")
+            ))
+        }
+    }
+
+    fn set_max_follow_up_depth(&self, max_depth: usize) {
+        self.inner.max_follow_up_depth.store(max_depth, Ordering::Relaxed);
+    }
+
+    fn set_allow_repeated_follow_ups(&self, allow: bool) {
+        self.inner.allow_repeated_follow_ups.store(allow, Ordering::Relaxed);
+    }
+
+    fn listener_count(&self) -> usize {
+        self.inner.listeners.read().len()
+    }
+
+    fn listener_descriptors(&self) -> Vec<ListenerDescriptor> {
+        let listeners = self.inner.listeners.read();
+        let mut listeners: Vec<_> = listeners.iter().collect();
+        listeners.sort_by_key(|registered| registered.listener.order());
+
+        listeners
+            .into_iter()
+            .map(|RegisteredListener { id, listener }| {
+                let executor = self.inner.resolve_executor(listener.as_ref());
+                ListenerDescriptor {
+                    id: *id,
+                    name: listener.listener_name().to_string(),
+                    order: listener.order(),
+                    executor: format!("{:?}", executor).to_lowercase(),
+                    asynchronous: executor != ListenerExecutor::Inline,
+                    event_types: listener.supported_event_type_names(),
+                    transaction_phase: listener.transaction_phase().map(|phase| format!("{:?}", phase)),
+                }
+            })
+            .collect()
+    }
+
+    fn metrics(&self) -> EventMetricsSnapshot {
+        self.inner.metrics.snapshot()
+    }
+
+    fn reset_metrics(&self) {
+        self.inner.metrics.reset();
+    }
+}
+
+impl MulticasterInner {
+    /// 按 ID 移除监听器
+    fn remove_by_id(&self, id: u64) -> bool {
+        let mut listeners = self.listeners.write();
+        let Some(index) = listeners.iter().position(|registered| registered.id == id) else {
+            return false;
+        };
+
+        let removed = listeners.remove(index);
+        self.listener_cache.write().clear();
+        tracing::debug!("Removed event listener: {} (id: {})", removed.listener.listener_name(), id);
+        true
+    }
+
+    /// 获取匹配事件的监听器（按 order 排序，类型匹配结果按事件类型缓存）
+    fn retrieve_listeners(&self, event: &dyn Event) -> Vec<RegisteredListener> {
+        let event_type = event.as_any().type_id();
+        let cached = self.listener_cache.read().get(&event_type).cloned();
+
+        let matched = match cached {
+            Some(cached) => cached,
+            None => {
+                // 持有 listeners 读锁直到写入缓存，避免与监听器变更交错产生过期缓存
+                let listeners = self.listeners.read();
+                let event_types = event_type_ids(event);
+
+                let mut matched: Vec<_> = listeners
+                    .iter()
+                    .filter(|registered| match registered.listener.supported_event_types() {
+                        Some(types) => types.iter().any(|t| event_types.contains(t)),
+                        None => true,
+                    })
+                    .cloned()
+                    .collect();
+
+                // 按 order 排序（稳定排序，相同 order 保持注册顺序）
+                matched.sort_by_key(|registered| registered.listener.order());
+
+                self.listener_cache.write().insert(event_type, matched.clone());
+                matched
+            }
+        };
+
+        // 按类型匹配后，再通过 supports_event 过滤
+        matched
+            .into_iter()
+            .filter(|registered| registered.listener.supports_event(event.event_name()))
+            .collect()
+    }
+
+    /// 确定监听器的实际执行方式
+    fn resolve_executor(&self, listener: &dyn EventListener) -> ListenerExecutor {
+        match listener.executor() {
+            ListenerExecutor::Default if self.async_mode => ListenerExecutor::Spawn,
+            ListenerExecutor::Default => ListenerExecutor::Inline,
+            executor => executor,
+        }
+    }
+
+    /// 广播事件（不等待提交到runtime的监听器）
+    fn multicast(self: &Arc<Self>, event: Arc<dyn Event>, chain: EventChain) {
+        let started = Instant::now();
+        let listeners = self.retrieve_listeners(event.as_ref());
+
+        tracing::debug!(
+            "Multicasting event: {} to {} listener(s) (async_mode: {})",
+            event.event_name(),
+            listeners.len(),
+            self.async_mode
+        );
+
+        // 获取错误处理器
+        let error_handler = self.error_handler.read().clone();
+        let runtime = tokio::runtime::Handle::try_current().ok();
+
+        // 需要提交到runtime的监听器，在同一个后台任务中按顺序执行
+        let mut deferred = Vec::new();
+        for RegisteredListener { id, listener } in listeners {
+            if self.defer_to_transaction(id, &listener, &event, &chain, error_handler.as_ref()) {
+                continue;
+            }
+
+            match (self.resolve_executor(listener.as_ref()), runtime.is_some()) {
+                (_, false) if listener.is_async() => {
+                    let err = anyhow::anyhow!("No tokio runtime available to run async listener");
+                    self.metrics.record_listener(id, listener.as_ref(), Duration::ZERO, false);
+                    report_error(&listener, &event, &err, error_handler.as_ref());
+                }
+                (ListenerExecutor::Spawn | ListenerExecutor::Blocking, false) => {
+                    // 没有runtime，降级为同步执行
+                    tracing::warn!("No tokio runtime available, falling back to sync execution");
+                    self.invoke_listener(id, &listener, &event, &chain, error_handler.as_ref());
+                }
+                (ListenerExecutor::Blocking, true) => deferred.push((id, listener, ListenerExecutor::Blocking)),
+                // 异步监听器的 Future 整体交给 runtime 执行，不在发布线程中轮询
+                (ListenerExecutor::Spawn, true) => deferred.push((id, listener, ListenerExecutor::Inline)),
+                (_, true) if listener.is_async() => deferred.push((id, listener, ListenerExecutor::Inline)),
+                _ => self.invoke_listener(id, &listener, &event, &chain, error_handler.as_ref()),
+            }
+        }
+
+        self.metrics.record_published(event.as_ref(), started.elapsed());
+
+        if let (Some(handle), false) = (runtime, deferred.is_empty()) {
+            let inner = Arc::clone(self);
+            handle.spawn(async move {
+                for (id, listener, executor) in deferred {
+                    match inner.run_listener(id, &listener, &event, executor).await {
+                        Ok(follow_ups) => {
+                            inner.publish_follow_ups(id, &listener, &event, follow_ups, &chain, error_handler.as_ref());
+                        }
+                        Err(e) => report_error(&listener, &event, &e, error_handler.as_ref()),
+                    }
+                }
+            });
+        }
+    }
+
+    /// 广播事件并依次等待所有监听器（包括后续事件的监听器）执行完成，返回失败信息
+    fn multicast_and_wait(
+        self: Arc<Self>,
+        event: Arc<dyn Event>,
+        chain: EventChain,
+    ) -> Pin<Box<dyn Future<Output = Vec<String>> + Send>> {
+        Box::pin(async move {
+            let started = Instant::now();
+            let listeners = self.retrieve_listeners(event.as_ref());
+
+            tracing::debug!(
+                "Multicasting event: {} to {} listener(s) and waiting for completion",
+                event.event_name(),
+                listeners.len()
+            );
+
+            let error_handler = self.error_handler.read().clone();
+            let max_depth = self.max_follow_up_depth.load(Ordering::Relaxed);
+            let allow_repeats = self.allow_repeated_follow_ups.load(Ordering::Relaxed);
+            let mut failures = Vec::new();
+
+            for RegisteredListener { id, listener } in listeners {
+                if self.defer_to_transaction(id, &listener, &event, &chain, error_handler.as_ref()) {
+                    continue;
+                }
+
+                let executor = self.resolve_executor(listener.as_ref());
+                let follow_ups = match self.run_listener(id, &listener, &event, executor).await {
+                    Ok(follow_ups) => follow_ups,
+                    Err(e) => {
+                        report_error(&listener, &event, &e, error_handler.as_ref());
+                        failures.push(format!("{}: {:#}", listener.listener_name(), e));
+                        continue;
+                    }
+                };
+
+                for follow_up in follow_ups {
+                    match chain.extend(id, follow_up.as_ref(), max_depth, allow_repeats) {
+                        Ok(next) => {
+                            failures.extend(Arc::clone(&self).multicast_and_wait(follow_up, next).await);
+                        }
+                        Err(e) => {
+                            report_error(&listener, &event, &e, error_handler.as_ref());
+                            failures.push(format!("{}: {:#}", listener.listener_name(), e));
+                        }
+                    }
+                }
+            }
+
+            // 包含后续事件的执行时间，即调用方等待的总时间
+            self.metrics.record_published(event.as_ref(), started.elapsed());
+            failures
+        })
+    }
+
+    /// 处理事务监听器
+    ///
+    /// 当前任务处于事务中时，登记到事务同步注册表并在对应阶段执行；没有事务时按 fallback_execution 决定。
+    /// 返回 true 表示该监听器无需立即执行
+    fn defer_to_transaction(
+        self: &Arc<Self>,
+        id: u64,
+        listener: &Arc<dyn EventListener>,
+        event: &Arc<dyn Event>,
+        chain: &EventChain,
+        error_handler: Option<&ErrorHandler>,
+    ) -> bool {
+        let phase = match listener.transaction_phase() {
+            Some(phase) => phase,
+            None => return false,
+        };
+
+        let synchronization = match TransactionSynchronization::current() {
+            Some(synchronization) => synchronization,
+            None if listener.fallback_execution() => return false,
+            None => {
+                tracing::debug!(
+                    "No active transaction, skipping transactional listener '{}' for event '{}'",
+                    listener.listener_name(),
+                    event.event_name()
+                );
+                return true;
+            }
+        };
+
+        tracing::debug!(
+            "Deferring event '{}' for listener '{}' until {:?}",
+            event.event_name(),
+            listener.listener_name(),
+            phase
+        );
+
+        let inner = Arc::clone(self);
+        let listener = Arc::clone(listener);
+        let event = Arc::clone(event);
+        let chain = chain.clone();
+        let error_handler = error_handler.cloned();
+        synchronization.register(
+            phase,
+            Box::new(move || {
+                Box::pin(async move {
+                    let executor = inner.resolve_executor(listener.as_ref());
+                    match inner.run_listener(id, &listener, &event, executor).await {
+                        Ok(follow_ups) => {
+                            inner.publish_follow_ups(id, &listener, &event, follow_ups, &chain, error_handler.as_ref());
+                            Ok(())
+                        }
+                        Err(e) => {
+                            report_error(&listener, &event, &e, error_handler.as_ref());
+                            Err(e)
+                        }
+                    }
+                })
+            }),
+        );
+        true
+    }
+
+    /// 发布监听器返回的后续事件
+    fn publish_follow_ups(
+        self: &Arc<Self>,
+        id: u64,
+        listener: &Arc<dyn EventListener>,
+        event: &Arc<dyn Event>,
+        follow_ups: Vec<Arc<dyn Event>>,
+        chain: &EventChain,
+        error_handler: Option<&ErrorHandler>,
+    ) {
+        let max_depth = self.max_follow_up_depth.load(Ordering::Relaxed);
+        let allow_repeats = self.allow_repeated_follow_ups.load(Ordering::Relaxed);
+        for follow_up in follow_ups {
+            match chain.extend(id, follow_up.as_ref(), max_depth, allow_repeats) {
+                Ok(next) => {
+                    tracing::debug!(
+                        "Publishing follow-up event '{}' returned by listener '{}'",
+                        follow_up.event_name(),
+                        listener.listener_name()
+                    );
+                    self.multicast(follow_up, next);
+                }
+                Err(e) => report_error(listener, event, &e, error_handler),
+            }
+        }
+    }
+
+    /// 按执行方式运行监听器并记录耗时
+    async fn run_listener(
+        &self,
+        id: u64,
+        listener: &Arc<dyn EventListener>,
+        event: &Arc<dyn Event>,
+        executor: ListenerExecutor,
+    ) -> anyhow::Result<Vec<Arc<dyn Event>>> {
+        let started = Instant::now();
+        let result = run_listener(Arc::clone(listener), Arc::clone(event), executor).await;
+        self.metrics.record_listener(id, listener.as_ref(), started.elapsed(), result.is_ok());
+        result
+    }
+
+    /// 在发布线程中调用单个同步监听器
+    ///
+    /// 同步监听器的 `handle_event` 不会等待异步操作，直接执行完成；
+    /// 未完成说明监听器需要等待却没有声明 `is_async`，作为错误报告
+    fn invoke_listener(
+        self: &Arc<Self>,
+        id: u64,
+        listener: &Arc<dyn EventListener>,
+        event: &Arc<dyn Event>,
+        chain: &EventChain,
+        error_handler: Option<&ErrorHandler>,
+    ) {
+        // 使用 catch_unwind 捕获 panic
+        let started = Instant::now();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            Arc::clone(listener).handle_event(Arc::clone(event)).now_or_never()
+        }));
+
+        match result {
+            Ok(Some(Ok(follow_ups))) => {
+                self.metrics.record_listener(id, listener.as_ref(), started.elapsed(), true);
+                self.publish_follow_ups(id, listener, event, follow_ups, chain, error_handler);
+            }
+            Ok(Some(Err(err))) => {
+                self.metrics.record_listener(id, listener.as_ref(), started.elapsed(), false);
+                report_error(listener, event, &err, error_handler);
+            }
+            Ok(None) => {
+                self.metrics.record_listener(id, listener.as_ref(), started.elapsed(), false);
+                let err = anyhow::anyhow!(
+                    "Listener did not complete synchronously; listeners that await must override EventListener::is_async"
+                );
+                report_error(listener, event, &err, error_handler);
+            }
+            Err(payload) => {
+                self.metrics.record_listener(id, listener.as_ref(), started.elapsed(), false);
+                let err = anyhow::anyhow!("Listener panicked: {}", panic_message(payload.as_ref()));
+                if let Some(handler) = error_handler {
+                    handler(listener.as_ref(), Arc::clone(event), &err);
+                } else {
+                    // 没有错误处理器，重新抛出
+                    tracing::error!(
+                        "Listener '{}' panicked while handling event '{}': {:?}",
+                        listener.listener_name(),
+                        event.event_name(),
+                        err
+                    );
+                    std::panic::resume_unwind(payload);
+                }
+            }
+        }
+    }
+}
+
+/// 按执行方式运行监听器并等待完成，panic 会被转换为错误
+async fn run_listener(
+    listener: Arc<dyn EventListener>,
+    event: Arc<dyn Event>,
+    executor: ListenerExecutor,
+) -> anyhow::Result<Vec<Arc<dyn Event>>> {
+    let future = listener.handle_event(event);
+    let runtime = tokio::runtime::Handle::try_current();
+
+    match (executor, runtime) {
+        (ListenerExecutor::Spawn, Ok(handle)) => handle
+            .spawn(catch_listener_panic(future))
+            .await
+            .map_err(|e| anyhow::anyhow!("Listener task failed: {}", e))?,
+        (ListenerExecutor::Blocking, Ok(handle)) => {
+            let blocking_handle = handle.clone();
+            handle
+                .spawn_blocking(move || blocking_handle.block_on(catch_listener_panic(future)))
+                .await
+                .map_err(|e| anyhow::anyhow!("Listener task failed: {}", e))?
+        }
+        _ => catch_listener_panic(future).await,
+    }
+}
+
+/// 将监听器执行中的 panic 转换为错误
+async fn catch_listener_panic(future: EventHandlerFuture) -> anyhow::Result<Vec<Arc<dyn Event>>> {
+    std::panic::AssertUnwindSafe(future)
+        .catch_unwind()
+        .await
+        .unwrap_or_else(|payload| {
+            Err(anyhow::anyhow!("Listener panicked: {}", panic_message(payload.as_ref())))
+        })
+}
+
+/// 提取 panic 信息
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// 将监听器错误交给错误处理器，没有错误处理器时记录日志
+fn report_error(
+    listener: &Arc<dyn EventListener>,
+    event: &Arc<dyn Event>,
+    err: &anyhow::Error,
+    error_handler: Option<&ErrorHandler>,
+) {
+    if let Some(handler) = error_handler {
+        handler(listener.as_ref(), Arc::clone(event), err);
+    } else {
+        tracing::error!(
+            "Listener '{}' failed while handling event '{}': {:#}",
+            listener.listener_name(),
+            event.event_name(),
+            err
+        );
+    }
+}
+
+/// 事件发布器
+///
 /// 类似 Spring 的 ApplicationEventPublisher
 /// 简化的发布接口，内部使用 ApplicationEventMulticaster
 ///
@@ -419,17 +1974,45 @@ impl ApplicationEventPublisher {
         self.multicaster.multicast_event(event);
     }
 
+    /// 发布事件并等待所有监听器执行完成
+    ///
+    /// 任一监听器失败时返回错误
+    pub async fn publish_event_and_wait(&self, event: Arc<dyn Event>) -> anyhow::Result<()> {
+        self.multicaster.multicast_event_and_wait(event).await
+    }
+
     /// 获取多播器
     pub fn multicaster(&self) -> &Arc<dyn ApplicationEventMulticaster> {
         &self.multicaster
     }
 
     /// 添加监听器
-    pub fn add_listener(&self, listener: Arc<dyn EventListener>) {
-        self.multicaster.add_listener(listener);
+    pub fn add_listener(&self, listener: Arc<dyn EventListener>) -> ListenerHandle {
+        self.multicaster.add_listener(listener)
+    }
+
+    /// 添加异步监听器
+    pub fn add_async_listener<L: AsyncEventListener>(&self, listener: Arc<L>) -> ListenerHandle {
+        self.multicaster.add_listener(Arc::new(AsyncEventListenerAdapter::new(listener)))
+    }
+
+    /// 以闭包注册指定事件类型的监听器
+    ///
+    /// 闭包返回值与 `#[event_listener]` 方法相同：`()`、`Result<()>`，或作为后续事件发布的事件
+    pub fn on<E, R, F>(&self, handler: F) -> ListenerHandle
+    where
+        E: Event,
+        R: FollowUpEvents,
+        F: Fn(&E) -> R + Send + Sync + 'static,
+    {
+        let name = format!("on<{}>", std::any::type_name::<E>());
+        let listener = MethodEventListener::<E>::new(name, move |event: &E| {
+            handler(event).into_follow_up_events()
+        });
+        self.add_listener(Arc::new(listener))
     }
 
-    /// 移除监听器
+    /// 按名称移除监听器（同名的监听器全部移除）
     pub fn remove_listener(&self, listener_name: &str) {
         self.multicaster.remove_listener(listener_name);
     }
@@ -438,6 +2021,442 @@ impl ApplicationEventPublisher {
     pub fn listener_count(&self) -> usize {
         self.multicaster.listener_count()
     }
+
+    /// 获取所有监听器的信息
+    pub fn listeners(&self) -> Vec<ListenerDescriptor> {
+        self.multicaster.listener_descriptors()
+    }
+
+    /// 按事件类型分组的监听器信息（监听所有事件的监听器归入 "*"）
+    pub fn listeners_by_event_type(&self) -> BTreeMap<String, Vec<ListenerDescriptor>> {
+        let mut grouped: BTreeMap<String, Vec<ListenerDescriptor>> = BTreeMap::new();
+        for listener in self.listeners() {
+            if listener.event_types.is_empty() {
+                grouped.entry("*".to_string()).or_default().push(listener);
+            } else {
+                for event_type in &listener.event_types {
+                    grouped.entry(event_type.clone()).or_default().push(listener.clone());
+                }
+            }
+        }
+        grouped
+    }
+
+    /// 获取事件发布和监听器执行的统计信息
+    pub fn metrics(&self) -> EventMetricsSnapshot {
+        self.multicaster.metrics()
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Ping {
+        hop: u32,
+    }
+
+    struct Pong;
+
+    /// 与 Ping 同名的另一个事件类型
+    struct LegacyPing;
+
+    /// 标记 trait，作为 Serve 声明的超类型
+    trait Rally {}
+
+    struct Serve;
+
+    impl Rally for Serve {}
+
+    macro_rules! test_event {
+        ($ty:ty, $name:literal $(, supertypes = [$($supertype:ty),*])?) => {
+            impl Event for $ty {
+                fn event_name(&self) -> &str {
+                    $name
+                }
+
+                fn timestamp(&self) -> SystemTime {
+                    SystemTime::UNIX_EPOCH
+                }
+
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+
+                $(
+                    fn supertypes(&self) -> Vec<TypeId> {
+                        vec![$(TypeId::of::<$supertype>()),*]
+                    }
+                )?
+            }
+        };
+    }
+
+    test_event!(Ping, "Ping");
+    test_event!(Pong, "Pong");
+    test_event!(LegacyPing, "Ping");
+    test_event!(Serve, "Serve", supertypes = [dyn Rally]);
+
+    /// 按 `supported_event_types` 路由、记录收到的事件名称的监听器
+    struct TypeListener {
+        types: Option<Vec<TypeId>>,
+        received: Mutex<Vec<String>>,
+    }
+
+    impl TypeListener {
+        fn new(types: Option<Vec<TypeId>>) -> Arc<Self> {
+            Arc::new(Self {
+                types,
+                received: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn received(&self) -> Vec<String> {
+            self.received.lock().clone()
+        }
+    }
+
+    impl EventListener for TypeListener {
+        fn on_event(&self, event: Arc<dyn Event>) {
+            self.received.lock().push(event.event_name().to_string());
+        }
+
+        fn supported_event_types(&self) -> Option<Vec<TypeId>> {
+            self.types.clone()
+        }
+    }
+
+    /// 注册 Ping -> Pong -> Ping 的监听器，返回 Ping 监听器的调用计数
+    fn ping_pong(multicaster: &SimpleApplicationEventMulticaster, max_ping_hop: u32) -> Arc<AtomicUsize> {
+        let pings = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&pings);
+        multicaster.add_listener(Arc::new(MethodEventListener::<Ping>::new("ping", move |ping: &Ping| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            (ping.hop < max_ping_hop).then_some(Pong).into_follow_up_events()
+        })));
+
+        let hops = Arc::new(AtomicUsize::new(0));
+        multicaster.add_listener(Arc::new(MethodEventListener::<Pong>::new("pong", move |_: &Pong| {
+            let hop = hops.fetch_add(1, Ordering::SeqCst) as u32 + 1;
+            Ping { hop }.into_follow_up_events()
+        })));
+
+        pings
+    }
+
+    /// 记录 `名称:hop` 的 Ping 监听器
+    fn recording_listener(name: &'static str, log: &Arc<Mutex<Vec<String>>>) -> MethodEventListener<Ping> {
+        let log = Arc::clone(log);
+        MethodEventListener::new(name, move |ping: &Ping| {
+            log.lock().push(format!("{}:{}", name, ping.hop));
+            Ok(Vec::new())
+        })
+    }
+
+    #[test]
+    fn listeners_run_by_order_then_registration_order() {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        multicaster.add_listener(Arc::new(recording_listener("late", &log).with_order(10)));
+        multicaster.add_listener(Arc::new(recording_listener("first-default", &log)));
+        multicaster.add_listener(Arc::new(recording_listener("early", &log).with_order(-5)));
+        multicaster.add_listener(Arc::new(recording_listener("second-default", &log)));
+
+        multicaster.multicast_event(Arc::new(Ping { hop: 0 }));
+
+        assert_eq!(*log.lock(), ["early:0", "first-default:0", "second-default:0", "late:0"]);
+    }
+
+    #[tokio::test]
+    async fn conditions_skip_events_before_the_handler_runs() {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        multicaster.add_listener(Arc::new(
+            recording_listener("even", &log).with_condition(|ping: &Ping| ping.hop % 2 == 0),
+        ));
+        let captured = Arc::clone(&log);
+        multicaster.add_listener(Arc::new(
+            MethodEventListener::<Ping>::new_async("odd", move |event| {
+                let captured = Arc::clone(&captured);
+                Box::pin(async move {
+                    let ping = event.as_any().downcast_ref::<Ping>().unwrap();
+                    captured.lock().push(format!("odd:{}", ping.hop));
+                    Ok(Vec::new())
+                })
+            })
+            .with_condition(|ping: &Ping| ping.hop % 2 == 1),
+        ));
+
+        for hop in 0..4 {
+            multicaster.multicast_event_and_wait(Arc::new(Ping { hop })).await.unwrap();
+        }
+
+        assert_eq!(*log.lock(), ["even:0", "odd:1", "even:2", "odd:3"]);
+    }
+
+    #[test]
+    fn conditions_apply_when_listeners_are_invoked_directly() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let listener = recording_listener("large", &log).with_condition(|ping: &Ping| ping.hop > 1);
+
+        listener.on_event(Arc::new(Ping { hop: 1 }));
+        listener.on_event(Arc::new(Ping { hop: 2 }));
+
+        assert_eq!(*log.lock(), ["large:2"]);
+    }
+
+    #[test]
+    fn routes_events_by_concrete_type_and_declared_supertypes() {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        let pings = TypeListener::new(Some(vec![TypeId::of::<Ping>()]));
+        let rallies = TypeListener::new(Some(vec![TypeId::of::<dyn Rally>()]));
+        let everything = TypeListener::new(None);
+        multicaster.add_listener(pings.clone());
+        multicaster.add_listener(rallies.clone());
+        multicaster.add_listener(everything.clone());
+
+        multicaster.multicast_event(Arc::new(Ping { hop: 0 }));
+        multicaster.multicast_event(Arc::new(Serve));
+        multicaster.multicast_event(Arc::new(Pong));
+        // 同名的其他类型不会路由到 Ping 的监听器
+        multicaster.multicast_event(Arc::new(LegacyPing));
+
+        assert_eq!(pings.received(), ["Ping"]);
+        assert_eq!(rallies.received(), ["Serve"]);
+        assert_eq!(everything.received(), ["Ping", "Serve", "Pong", "Ping"]);
+    }
+
+    #[test]
+    fn listener_cache_is_kept_per_event_type_and_cleared_on_changes() {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        let first = TypeListener::new(Some(vec![TypeId::of::<Ping>()]));
+        multicaster.add_listener(first.clone());
+
+        multicaster.multicast_event(Arc::new(Ping { hop: 0 }));
+        multicaster.multicast_event(Arc::new(Serve));
+        {
+            let cache = multicaster.inner.listener_cache.read();
+            assert_eq!(cache.len(), 2);
+            assert_eq!(cache[&TypeId::of::<Ping>()].len(), 1);
+            assert!(cache[&TypeId::of::<Serve>()].is_empty());
+        }
+
+        // 新增监听器后缓存失效，下一次发布重新匹配
+        let second = TypeListener::new(Some(vec![TypeId::of::<Ping>()]));
+        let handle = multicaster.add_listener(second.clone());
+        assert!(multicaster.inner.listener_cache.read().is_empty());
+        multicaster.multicast_event(Arc::new(Ping { hop: 1 }));
+
+        assert!(handle.unregister());
+        assert!(multicaster.inner.listener_cache.read().is_empty());
+        multicaster.multicast_event(Arc::new(Ping { hop: 2 }));
+
+        assert_eq!(first.received(), ["Ping", "Ping", "Ping"]);
+        assert_eq!(second.received(), ["Ping"]);
+    }
+
+    #[test]
+    fn handles_unregister_only_their_own_listener() {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let first = multicaster.add_listener(Arc::new(recording_listener("audit", &log)));
+        let second = multicaster.add_listener(Arc::new(recording_listener("audit", &log)));
+        assert_ne!(first.id(), second.id());
+        assert_eq!(first.name(), "audit");
+
+        assert!(first.unregister());
+        assert!(!first.unregister());
+        assert_eq!(multicaster.listener_count(), 1);
+
+        multicaster.multicast_event(Arc::new(Ping { hop: 0 }));
+        assert_eq!(*log.lock(), ["audit:0"]);
+
+        // 句柄不延长多播器的生命周期
+        drop(multicaster);
+        assert!(!second.unregister());
+    }
+
+    #[test]
+    fn guards_unregister_on_drop_unless_released() {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        {
+            let _guard = multicaster.add_listener(Arc::new(recording_listener("scoped", &log))).into_guard();
+            multicaster.multicast_event(Arc::new(Ping { hop: 0 }));
+        }
+        multicaster.multicast_event(Arc::new(Ping { hop: 1 }));
+        assert_eq!(*log.lock(), ["scoped:0"]);
+        assert_eq!(multicaster.listener_count(), 0);
+
+        let guard = multicaster.add_listener(Arc::new(recording_listener("kept", &log))).into_guard();
+        let handle = guard.release();
+        multicaster.multicast_event(Arc::new(Ping { hop: 2 }));
+        assert_eq!(*log.lock(), ["scoped:0", "kept:2"]);
+
+        assert!(handle.unregister());
+        assert_eq!(multicaster.listener_count(), 0);
+    }
+
+    /// 把监听器名称和执行线程发送到通道的监听器
+    fn thread_listener(
+        name: &'static str,
+        executor: ListenerExecutor,
+        tx: &tokio::sync::mpsc::UnboundedSender<(&'static str, std::thread::ThreadId)>,
+    ) -> Arc<MethodEventListener<Ping>> {
+        let tx = tx.clone();
+        Arc::new(
+            MethodEventListener::new(name, move |_: &Ping| {
+                tx.send((name, std::thread::current().id())).unwrap();
+                Ok(Vec::new())
+            })
+            .with_executor(executor),
+        )
+    }
+
+    #[tokio::test]
+    async fn executors_choose_where_listeners_run() {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        multicaster.add_listener(thread_listener("inline", ListenerExecutor::Inline, &tx));
+        multicaster.add_listener(thread_listener("spawn", ListenerExecutor::Spawn, &tx));
+        multicaster.add_listener(thread_listener("blocking", ListenerExecutor::Blocking, &tx));
+        let publisher = std::thread::current().id();
+
+        multicaster.multicast_event(Arc::new(Ping { hop: 0 }));
+
+        // 只有 Inline 在发布线程中执行完成，其余监听器提交到 runtime 后按顺序执行
+        assert_eq!(rx.try_recv().unwrap(), ("inline", publisher));
+        assert!(rx.try_recv().is_err());
+        assert_eq!(rx.recv().await.unwrap(), ("spawn", publisher));
+        let (name, thread) = rx.recv().await.unwrap();
+        assert_eq!(name, "blocking");
+        assert_ne!(thread, publisher);
+    }
+
+    #[tokio::test]
+    async fn default_executor_follows_the_multicaster_mode() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let sync = SimpleApplicationEventMulticaster::new();
+        sync.add_listener(thread_listener("sync", ListenerExecutor::Default, &tx));
+        sync.multicast_event(Arc::new(Ping { hop: 0 }));
+        assert_eq!(rx.try_recv().unwrap().0, "sync");
+
+        let asynchronous = SimpleApplicationEventMulticaster::new_async();
+        asynchronous.add_listener(thread_listener("async", ListenerExecutor::Default, &tx));
+        asynchronous.multicast_event(Arc::new(Ping { hop: 0 }));
+        assert!(rx.try_recv().is_err());
+        assert_eq!(rx.recv().await.unwrap().0, "async");
+
+        // 显式指定的执行方式不受异步模式影响
+        asynchronous.add_listener(thread_listener("inline", ListenerExecutor::Inline, &tx));
+        asynchronous.multicast_event(Arc::new(Ping { hop: 1 }));
+        assert_eq!(rx.try_recv().unwrap().0, "inline");
+        assert_eq!(rx.recv().await.unwrap().0, "async");
+    }
+
+    #[test]
+    fn without_a_runtime_listeners_fall_back_to_the_publishing_thread() {
+        let multicaster = SimpleApplicationEventMulticaster::new_async();
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let captured = Arc::clone(&errors);
+        multicaster.set_error_handler(move |listener, _, err| {
+            captured.lock().push(format!("{}: {}", listener.listener_name(), err));
+        });
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        multicaster.add_listener(thread_listener("default", ListenerExecutor::Default, &tx));
+        multicaster.add_listener(thread_listener("spawn", ListenerExecutor::Spawn, &tx));
+        multicaster.add_listener(thread_listener("blocking", ListenerExecutor::Blocking, &tx));
+        multicaster.add_listener(Arc::new(MethodEventListener::<Ping>::new_async("awaiting", |_| {
+            Box::pin(async { Ok(Vec::new()) })
+        })));
+        let publisher = std::thread::current().id();
+
+        multicaster.multicast_event(Arc::new(Ping { hop: 0 }));
+
+        assert_eq!(rx.try_recv().unwrap(), ("default", publisher));
+        assert_eq!(rx.try_recv().unwrap(), ("spawn", publisher));
+        assert_eq!(rx.try_recv().unwrap(), ("blocking", publisher));
+        assert_eq!(*errors.lock(), ["awaiting: No tokio runtime available to run async listener"]);
+    }
+
+    #[tokio::test]
+    async fn same_listener_republishing_same_event_type_is_a_cycle() {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        let pings = ping_pong(&multicaster, u32::MAX);
+
+        let err = multicaster
+            .multicast_event_and_wait(Arc::new(Ping { hop: 0 }))
+            .await
+            .unwrap_err();
 
+        assert!(err.to_string().contains("cycle detected: Ping -> Pong -> Ping -> Pong"), "{}", err);
+        assert_eq!(pings.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn event_type_may_reappear_when_published_by_another_listener() {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        let pings = ping_pong(&multicaster, 1);
+
+        multicaster
+            .multicast_event_and_wait(Arc::new(Ping { hop: 0 }))
+            .await
+            .unwrap();
+
+        // Ping(0) -> Pong -> Ping(1)，第二个 Ping 来自 pong 监听器，不是循环
+        assert_eq!(pings.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn allowed_repeats_are_stopped_by_the_depth_limit() {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        multicaster.set_allow_repeated_follow_ups(true);
+        multicaster.set_max_follow_up_depth(4);
+        let pings = ping_pong(&multicaster, u32::MAX);
+
+        let err = multicaster
+            .multicast_event_and_wait(Arc::new(Ping { hop: 0 }))
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("Maximum follow-up event depth (4) exceeded"), "{}", err);
+        assert_eq!(pings.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn cycles_are_reported_to_the_error_handler_in_sync_mode() {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let captured = Arc::clone(&errors);
+        multicaster.set_error_handler(move |listener, _, err| {
+            captured.lock().push(format!("{}: {}", listener.listener_name(), err));
+        });
+        let pings = ping_pong(&multicaster, u32::MAX);
+
+        multicaster.multicast_event(Arc::new(Ping { hop: 0 }));
+
+        assert_eq!(pings.load(Ordering::SeqCst), 2);
+        let errors = errors.lock();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("ping: Follow-up event cycle detected"), "{}", errors[0]);
+    }
+
+    #[test]
+    fn metrics_are_kept_per_event_type_even_with_equal_names() {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        multicaster.multicast_event(Arc::new(Ping { hop: 0 }));
+        multicaster.multicast_event(Arc::new(Ping { hop: 1 }));
+        multicaster.multicast_event(Arc::new(LegacyPing));
+
+        let mut published: Vec<_> = multicaster
+            .metrics()
+            .events
+            .into_iter()
+            .map(|metrics| (metrics.event, metrics.published))
+            .collect();
+        published.sort();
+
+        assert_eq!(published, vec![("Ping".to_string(), 1), ("Ping".to_string(), 2)]);
+    }
+}
@@ -7,18 +7,28 @@
 // - 自动装配（通过宏）
 
 pub mod app;
+pub mod autoconfigure;
+pub mod availability;
 pub mod bean;
 pub mod bean_factory;
 pub mod component;
 pub mod config;
 pub mod constants;
 pub mod context;
+pub mod crypto;
+pub mod diagnostics;
 pub mod error;
 pub mod event;
 pub mod lifecycle;
 pub mod logging;
+pub mod metadata;
+pub mod outbox;
 pub mod plugin;
+pub mod profiles;
+pub mod reload;
 pub mod scope;
+pub mod shutdown;
+pub mod transaction;
 pub mod utils;
 
 // Helper trait for init/destroy callbacks
@@ -41,42 +51,64 @@ impl IntoResult for Result<()> {
 
 // 重新导出常用类型
 pub use app::{ChimeraApplication, RunningApplication};
+pub use autoconfigure::{AutoConfigurationRegistry, Condition, ConditionEvaluationReport};
+pub use availability::{ApplicationAvailability, ReadinessState, ReadinessStateChangedEvent};
 pub use bean::{Bean, BeanDefinition, FactoryBean};
 pub use bean_factory::{
     BeanFactory, BeanFactoryExt, ConfigurableBeanFactory, ConfigurableListableBeanFactory,
     DefaultListableBeanFactory, ListableBeanFactory,
 };
 pub use component::Component;
-pub use component::{ComponentRegistry, ConfigurationPropertiesRegistry, EventListenerRegistry};
+pub use component::{ComponentRegistry, ComponentScan, ConfigurationPropertiesRegistry, EventListenerRegistry, ScanFilter};
 pub use config::{
-    ConfigValue, Environment, EnvironmentPropertySource, MapPropertySource, PropertySource,
-    TomlPropertySource,
+    ConfigParseError, ConfigTreePropertySource, ConfigValue, Environment, EnvironmentPropertySource,
+    JsonPropertySource, MapPropertySource, PropertySource, RandomValuePropertySource,
+    SystemPropertySource, TomlPropertySource,
 };
 pub use constants::*;
 pub use context::{ApplicationContext, ApplicationContextBuilder, Container, ShutdownHook};
-pub use error::Result;
+pub use crypto::{AesGcmDecryptor, PropertyDecryptor};
+pub use diagnostics::{analyze_failure, FailureAnalysis, FailureAnalyzer};
+pub use error::{BeanCreationError, NoSuchBeanError, Result};
 pub use event::{
-    ApplicationEventMulticaster, ApplicationEventPublisher, ApplicationShutdownEvent,
-    ApplicationStartedEvent, ErrorHandler, Event, EventListener,
+    ApplicationBeansInitializedEvent, ApplicationContextPreparedEvent,
+    ApplicationEnvironmentPreparedEvent, ApplicationEventMulticaster, ApplicationEventPublisher,
+    ApplicationFailedEvent, ApplicationReadyEvent, ApplicationShutdownEvent, ApplicationStartedEvent,
+    ApplicationStartingEvent, AsyncEventListener, AsyncEventListenerAdapter, EnvironmentChangeEvent,
+    ErrorHandler, Event, EventHandlerFuture, EventListener, EventMetricsSnapshot, EventTypeMetrics,
+    FollowUpEvents, ListenerDescriptor, ListenerExecutor, ListenerGuard, ListenerHandle,
+    ListenerMetrics, MethodEventListener, PluginStartedEvent, PluginStoppedEvent,
     SimpleApplicationEventMulticaster, TypedEventListener, TypedEventListenerAdapter,
 };
 pub use lifecycle::{
-    BeanFactoryPostProcessor, BeanFactoryPostProcessorMarker, BeanPostProcessor,
+    BeanDefinitionRegistryPostProcessor, BeanFactoryPostProcessor, BeanFactoryPostProcessorMarker, BeanPostProcessor,
     BeanPostProcessorMarker, SmartInitializingSingleton, SmartInitializingSingletonMarker,
 };
 pub use logging::{LogFormat, LogLevel, LoggingConfig};
+pub use metadata::ConfigPropertyMetadata;
+pub use outbox::{
+    DurableEvent, EventOutbox, FileOutboxStore, InMemoryOutboxStore, OutboxRecord, OutboxStore,
+    RetryPolicy,
+};
+pub use profiles::ProfileExpression;
+pub use reload::ConfigReloader;
 pub use scope::Scope;
+pub use shutdown::ShutdownHandle;
+pub use transaction::{
+    Transaction, TransactionManager, TransactionPhase, TransactionSynchronization, TransactionTemplate,
+};
 
 // 导出 async_trait 和 inventory，供宏使用
 pub use async_trait;
 pub use inventory;
 
 // 导出插件相关
-pub use plugin::{ApplicationPlugin, PluginRegistry, PluginSubmission, load_plugins};
+pub use plugin::{ApplicationPlugin, PluginInfo, PluginRegistry, PluginReport, PluginSubmission, load_plugins};
 
 /// Prelude 模块，包含常用的 traits 和类型
 pub mod prelude {
     pub use crate::app::{ChimeraApplication, RunningApplication};
+    pub use crate::availability::ReadinessState;
     pub use crate::bean::{Bean, BeanDefinition, FactoryBean};
     pub use crate::bean_factory::{
         BeanFactory, BeanFactoryExt, ConfigurableBeanFactory, ConfigurableListableBeanFactory,
@@ -84,23 +116,33 @@ pub mod prelude {
     };
     pub use crate::component::Component;
     pub use crate::config::{
-        self, ConfigValue, Environment, EnvironmentPropertySource, MapPropertySource,
-        PropertySource, TomlPropertySource,
+        self, ConfigTreePropertySource, ConfigValue, Environment, EnvironmentPropertySource,
+        JsonPropertySource, MapPropertySource, PropertySource, RandomValuePropertySource,
+        SystemPropertySource, TomlPropertySource,
     };
     pub use crate::context::{ApplicationContext, Container};
+    pub use crate::diagnostics::{FailureAnalysis, FailureAnalyzer};
     pub use crate::error::Result;
     pub use crate::event::{
-        ApplicationEventMulticaster, ApplicationEventPublisher, ApplicationShutdownEvent,
-        ApplicationStartedEvent, Event, EventListener, SimpleApplicationEventMulticaster,
-        TypedEventListener, TypedEventListenerAdapter,
+        ApplicationBeansInitializedEvent, ApplicationContextPreparedEvent,
+        ApplicationEnvironmentPreparedEvent, ApplicationEventMulticaster, ApplicationEventPublisher,
+        ApplicationFailedEvent, ApplicationReadyEvent, ApplicationShutdownEvent,
+        ApplicationStartedEvent, ApplicationStartingEvent, AsyncEventListener,
+        EnvironmentChangeEvent, Event, EventListener, ListenerExecutor, ListenerGuard,
+        ListenerHandle, MethodEventListener, PluginStartedEvent, PluginStoppedEvent,
+        SimpleApplicationEventMulticaster, TypedEventListener, TypedEventListenerAdapter,
     };
     pub use crate::lifecycle::{
-        BeanFactoryPostProcessor, BeanPostProcessor, SmartInitializingSingleton,
+        BeanDefinitionRegistryPostProcessor, BeanFactoryPostProcessor, BeanPostProcessor, SmartInitializingSingleton,
         SmartInitializingSingletonMarker,
     };
     pub use crate::logging::{LogFormat, LogLevel, LoggingConfig};
+    pub use crate::outbox::{DurableEvent, EventOutbox};
     pub use crate::plugin::{ApplicationPlugin, PluginRegistry, load_plugins};
+    pub use crate::reload::ConfigReloader;
     pub use crate::scope::Scope;
+    pub use crate::shutdown::ShutdownHandle;
+    pub use crate::transaction::{TransactionManager, TransactionPhase, TransactionTemplate};
     pub use crate::utils;
     // Re-export anyhow for convenience
     pub use anyhow::{anyhow, Context};
@@ -76,6 +76,25 @@ pub trait BeanFactoryPostProcessor: Send + Sync {
     }
 }
 
+/// BeanDefinitionRegistryPostProcessor - Bean 定义注册后置处理器
+///
+/// 在组件扫描之后、`#[bean]` 方法和自动配置处理之前执行。此时还没有创建任何 Bean 实例
+/// （配置类在注册 `#[bean]` 方法时才会实例化并注入依赖），替换组件的 Bean 定义后，
+/// 所有依赖它的 Bean（包括配置类）都会注入替换后的实例
+///
+/// 通过 `ApplicationContext::add_bean_definition_registry_post_processor` 注册（通常在初始化器中）
+pub trait BeanDefinitionRegistryPostProcessor: Send + Sync {
+    /// 修改已注册的 Bean 定义
+    fn post_process_bean_definition_registry(&self, context: &crate::ApplicationContext) -> Result<()>;
+
+    /// 获取处理器的优先级（数字越小优先级越高）
+    ///
+    /// 默认为 1000
+    fn order(&self) -> i32 {
+        1000
+    }
+}
+
 /// BeanPostProcessor 获取函数类型
 pub type BeanPostProcessorGetter = fn(&Arc<ApplicationContext>) -> Result<Arc<dyn BeanPostProcessor>>;
 
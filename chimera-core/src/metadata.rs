@@ -0,0 +1,293 @@
+//! 配置元数据
+//!
+//! `#[derive(ConfigurationProperties)]` 和 `#[value]` 宏会为每个配置键生成元数据
+//! （键、类型、默认值、来自文档注释的描述、是否废弃），通过 inventory 在运行时收集。
+//!
+//! 元数据可用于：
+//! - 导出 JSON 文件供 IDE 补全和文档生成（`write_json`）
+//! - 启动时检测配置文件中疑似拼写错误的未知配置键（`find_unknown_keys`）
+//!
+//! ## 导出元数据
+//!
+//! ```ignore
+//! // 在辅助二进制或测试中调用
+//! chimera_core::metadata::write_json("target/chimera-configuration-metadata.json")?;
+//! ```
+//!
+//! 也可以配置 `chimera.config.metadata-output = "path/to/metadata.json"`，应用启动时自动写出
+
+use crate::config::Environment;
+use crate::constants::*;
+use crate::utils::naming::levenshtein;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// 配置属性元数据
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigPropertyMetadata {
+    /// 完整配置键
+    pub key: &'static str,
+
+    /// 类型名称
+    pub type_name: &'static str,
+
+    /// 默认值
+    pub default_value: Option<&'static str>,
+
+    /// 描述（来自文档注释）
+    pub description: &'static str,
+
+    /// 声明该配置的类型
+    pub source_type: &'static str,
+
+    /// 是否已废弃
+    pub deprecated: bool,
+
+    /// 废弃说明
+    pub deprecation_note: Option<&'static str>,
+}
+
+inventory::collect!(ConfigPropertyMetadata);
+
+impl ConfigPropertyMetadata {
+    /// 创建框架内置配置的元数据
+    pub const fn framework(
+        key: &'static str,
+        type_name: &'static str,
+        default_value: Option<&'static str>,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            key,
+            type_name,
+            default_value,
+            description,
+            source_type: "chimera",
+            deprecated: false,
+            deprecation_note: None,
+        }
+    }
+}
+
+/// 未知配置键
+#[derive(Debug, Clone, Serialize)]
+pub struct UnknownConfigKey {
+    /// 配置键
+    pub key: String,
+
+    /// 所在配置源
+    pub source: String,
+
+    /// 最接近的已知配置键
+    pub suggestion: Option<String>,
+}
+
+/// 获取所有配置元数据（按键排序，重复的键只保留一个）
+pub fn all() -> Vec<&'static ConfigPropertyMetadata> {
+    let mut items: Vec<_> = inventory::iter::<ConfigPropertyMetadata>().collect();
+    items.sort_by(|a, b| a.key.cmp(b.key));
+    items.dedup_by(|a, b| a.key == b.key);
+    items
+}
+
+/// 根据键查找配置元数据
+pub fn find(key: &str) -> Option<&'static ConfigPropertyMetadata> {
+    inventory::iter::<ConfigPropertyMetadata>().find(|m| m.key == key)
+}
+
+/// 将所有配置元数据序列化为 JSON
+pub fn to_json() -> serde_json::Value {
+    serde_json::json!({ "properties": all() })
+}
+
+/// 将所有配置元数据写入 JSON 文件
+pub fn write_json(path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+    }
+
+    let content = serde_json::to_string_pretty(&to_json())?;
+    std::fs::write(path, content)
+        .with_context(|| format!("Failed to write configuration metadata: {}", path.display()))?;
+    Ok(())
+}
+
+/// 检测配置源中疑似拼写错误的未知配置键
+///
+/// 环境变量和系统信息配置源不参与检测。一个未知键被认为是疑似拼写错误，当且仅当：
+/// - 与某个已知键足够接近（编辑距离较小），或
+/// - 它所在的配置组（父级前缀）中存在已知键，例如 `database.ulr` 与 `database.url`
+pub fn find_unknown_keys(env: &Environment) -> Vec<UnknownConfigKey> {
+    let metadata = all();
+    if metadata.is_empty() {
+        return Vec::new();
+    }
+
+    let known: Vec<&str> = metadata.iter().map(|m| m.key).collect();
+    let known_groups: Vec<&str> = known.iter().filter_map(|k| parent_key(k)).collect();
+
+    let mut unknown = Vec::new();
+    let mut seen = HashSet::new();
+
+    for (source, keys) in env.keys_by_source() {
+        if source == "environment" || source == "system" {
+            continue;
+        }
+
+        for key in keys {
+            if seen.contains(&key) || is_known(&key, &known) {
+                continue;
+            }
+            seen.insert(key.clone());
+
+            let suggestion = suggest(&key, &known);
+            let in_known_group = parent_key(&key).is_some_and(|p| known_groups.contains(&p));
+
+            if suggestion.is_some() || in_known_group {
+                unknown.push(UnknownConfigKey {
+                    key,
+                    source: source.clone(),
+                    suggestion,
+                });
+            }
+        }
+    }
+
+    unknown.sort_by(|a, b| a.key.cmp(&b.key));
+    unknown
+}
+
+/// 启动时检查配置：警告未知配置键和废弃配置键
+pub fn report(env: &Environment) {
+    for item in find_unknown_keys(env) {
+        match &item.suggestion {
+            Some(suggestion) => tracing::warn!(
+                "Unknown configuration key '{}' in {} (did you mean '{}'?)",
+                item.key,
+                item.source,
+                suggestion
+            ),
+            None => tracing::warn!("Unknown configuration key '{}' in {}", item.key, item.source),
+        }
+    }
+
+    for item in all().into_iter().filter(|m| m.deprecated) {
+        if env.get(item.key).is_some() {
+            match item.deprecation_note {
+                Some(note) => tracing::warn!("Configuration key '{}' is deprecated: {}", item.key, note),
+                None => tracing::warn!("Configuration key '{}' is deprecated", item.key),
+            }
+        }
+    }
+}
+
+/// 判断键是否已知（包括 Map/Object 类型配置下的子键）
+fn is_known(key: &str, known: &[&str]) -> bool {
+    known.iter().any(|k| {
+        *k == key || (key.starts_with(k) && key.as_bytes().get(k.len()) == Some(&b'.'))
+    })
+}
+
+/// 获取父级键，例如 database.url -> database
+fn parent_key(key: &str) -> Option<&str> {
+    key.rfind('.').map(|pos| &key[..pos])
+}
+
+/// 查找最接近的已知键
+fn suggest(key: &str, known: &[&str]) -> Option<String> {
+    let max_distance = (key.len() / 5).clamp(1, 3);
+    known
+        .iter()
+        .map(|k| (levenshtein(key, k), *k))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, k)| k.to_string())
+}
+
+// ========== 框架内置配置 ==========
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_APP_NAME, "String", Some(DEFAULT_APP_NAME), "应用名称")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_APP_VERSION, "String", None, "应用版本")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_PROFILES_ACTIVE, "Vec<String>", None, "激活的 profiles")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework("chimera.profiles.group", "Map<String, Vec<String>>", None, "profile 组，激活组时同时激活组内成员")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_EVENTS_ASYNC, "bool", Some("false"), "事件系统是否异步")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_EVENTS_MAX_FOLLOW_UP_DEPTH, "usize", Some("8"), "监听器返回的后续事件的最大链路深度")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_EVENTS_OUTBOX_ENABLED, "bool", Some("false"), "是否启用事件发件箱（持久化事件并在启动时重放未完成的事件）")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_EVENTS_OUTBOX_PATH, "String", Some("data/event-outbox.jsonl"), "事件发件箱文件路径")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_EVENTS_OUTBOX_MAX_ATTEMPTS, "u32", Some("3"), "事件发件箱最大尝试次数，超过后转入死信")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_EVENTS_OUTBOX_INITIAL_BACKOFF_MS, "u64", Some("100"), "事件发件箱首次重试等待时间（毫秒）")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_EVENTS_OUTBOX_MAX_BACKOFF_MS, "u64", Some("10000"), "事件发件箱最大重试等待时间（毫秒）")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_EVENTS_OUTBOX_BACKOFF_MULTIPLIER, "f64", Some("2.0"), "事件发件箱重试等待时间的增长倍数")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_LIFECYCLE_SHUTDOWN_TIMEOUT, "Duration", Some("30s"), "优雅停机超时时间（等待进行中的请求等工作完成的最长时间）")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_PLUGINS, "Map<String, Table>", None, "按插件名称配置插件，chimera.plugins.<name>.enabled = false 禁用对应插件")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_IMPORT, "Vec<String>", None, "导入额外的配置文件、目录或配置树（支持 optional: 和 configtree: 前缀）")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_RELOAD_ENABLED, "bool", Some("false"), "是否启用配置热加载")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_RELOAD_DEBOUNCE_MS, "u64", Some("500"), "配置热加载防抖时间（毫秒）")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_ENCRYPT_KEY_FILE, "String", None, "加密配置值的密钥文件路径")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_WARN_UNKNOWN_KEYS, "bool", Some("true"), "启动时是否警告疑似拼写错误的未知配置键")
+}
+
+inventory::submit! {
+    ConfigPropertyMetadata::framework(CONFIG_METADATA_OUTPUT, "String", None, "启动时写出配置元数据 JSON 的文件路径")
+}
@@ -0,0 +1,909 @@
+//! 事件发件箱
+//!
+//! 为不能因进程崩溃而丢失的事件提供持久化保证：`EventOutbox` 位于 `ApplicationEventPublisher`
+//! 之前，先把可序列化的事件写入本地存储，再分发给监听器。所有监听器成功后事件被标记为完成；
+//! 失败时按重试策略重试，超过最大次数后转入死信存储。应用启动时会在后台重放上次未完成的事件
+//!
+//! 投递语义为至少一次：重试和重放会把事件重新发给所有监听器，监听器需要保证幂等（见 `DurableEvent`）
+//!
+//! ## 使用示例
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct OrderPlacedEvent { order_id: u64 }
+//!
+//! impl Event for OrderPlacedEvent { /* ... */ }
+//! impl DurableEvent for OrderPlacedEvent {}
+//!
+//! // 注册事件类型，启动时重放需要据此反序列化
+//! submit_durable_event!(OrderPlacedEvent);
+//!
+//! // 配置 chimera.events.outbox.enabled = true 后注入使用
+//! outbox.publish(OrderPlacedEvent { order_id: 42 }).await?;
+//! ```
+
+use crate::config::Environment;
+use crate::constants::*;
+use crate::event::{ApplicationEventPublisher, Event};
+use crate::Result;
+use anyhow::Context;
+use async_trait::async_trait;
+use parking_lot::{Mutex, RwLock};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 默认的发件箱文件路径
+pub const DEFAULT_OUTBOX_PATH: &str = "data/event-outbox.jsonl";
+
+/// 可持久化的事件
+///
+/// 事件需要支持 serde 序列化，`durable_type` 作为持久化时的类型标识，重放时据此找到反序列化方式。
+/// 默认使用类型的完整路径，类型移动或改名后需要覆盖此方法以兼容已持久化的事件
+///
+/// # 投递语义
+///
+/// 发件箱提供至少一次（at-least-once）投递：每次重试（以及启动时的重放）都会把事件重新发布给
+/// 所有监听器，不记录单个监听器的完成状态。之前已经成功处理过该事件的监听器会再次收到它，
+/// 因此监听器需要是幂等的，例如按事件中的业务 ID 去重
+pub trait DurableEvent: Event + Serialize + DeserializeOwned + Sized {
+    /// 持久化类型标识
+    fn durable_type() -> &'static str {
+        std::any::type_name::<Self>()
+    }
+}
+
+/// 可持久化事件类型注册信息
+///
+/// 通过 `submit_durable_event!` 宏提交，`EventOutbox` 创建时自动收集
+pub struct DurableEventRegistry {
+    /// 持久化类型标识
+    pub event_type: fn() -> &'static str,
+    /// 反序列化函数
+    pub decoder: fn(serde_json::Value) -> Result<Arc<dyn Event>>,
+}
+
+inventory::collect!(DurableEventRegistry);
+
+/// 注册可持久化事件类型的宏
+#[macro_export]
+macro_rules! submit_durable_event {
+    ($event_type:ty) => {
+        $crate::inventory::submit! {
+            $crate::outbox::DurableEventRegistry {
+                event_type: <$event_type as $crate::outbox::DurableEvent>::durable_type,
+                decoder: $crate::outbox::decode_event::<$event_type>,
+            }
+        }
+    };
+}
+
+/// 将持久化的事件内容反序列化为事件
+pub fn decode_event<E: DurableEvent>(payload: serde_json::Value) -> Result<Arc<dyn Event>> {
+    let event: E = serde_json::from_value(payload)
+        .with_context(|| format!("Failed to deserialize durable event '{}'", E::durable_type()))?;
+    Ok(Arc::new(event))
+}
+
+type EventDecoder = fn(serde_json::Value) -> Result<Arc<dyn Event>>;
+
+/// 发件箱记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxRecord {
+    /// 记录 ID
+    pub id: String,
+    /// 持久化类型标识
+    pub event_type: String,
+    /// 序列化后的事件内容
+    pub payload: serde_json::Value,
+    /// 已尝试分发的次数
+    pub attempts: u32,
+    /// 创建时间（Unix 毫秒）
+    pub created_at: u64,
+    /// 最近一次失败的原因
+    pub last_error: Option<String>,
+}
+
+impl OutboxRecord {
+    fn new(event_type: &str, payload: serde_json::Value) -> Self {
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            event_type: event_type.to_string(),
+            payload,
+            attempts: 0,
+            created_at,
+            last_error: None,
+        }
+    }
+}
+
+/// 发件箱存储
+///
+/// 内置本地文件存储 `FileOutboxStore` 和内存存储 `InMemoryOutboxStore`，
+/// 也可以基于 SQLite 等数据库实现此 trait
+#[async_trait]
+pub trait OutboxStore: Send + Sync {
+    /// 追加待分发的记录
+    async fn append(&self, record: &OutboxRecord) -> Result<()>;
+
+    /// 记录一次分发失败
+    async fn record_failure(&self, id: &str, attempts: u32, error: &str) -> Result<()>;
+
+    /// 标记记录已完成
+    async fn complete(&self, id: &str) -> Result<()>;
+
+    /// 将记录转入死信存储
+    async fn dead_letter(&self, id: &str) -> Result<()>;
+
+    /// 获取所有未完成的记录（按写入顺序）
+    async fn pending(&self) -> Result<Vec<OutboxRecord>>;
+
+    /// 获取打开存储时已经存在、目前仍未完成的记录（按写入顺序）
+    ///
+    /// 这些是上次运行遗留的记录，`EventOutbox::replay` 只重放它们；
+    /// 本次运行中 `publish` 写入的记录由发布方自己分发，不应包含在内
+    async fn recovered(&self) -> Result<Vec<OutboxRecord>>;
+
+    /// 获取死信存储中的记录
+    async fn dead_letters(&self) -> Result<Vec<OutboxRecord>>;
+}
+
+/// 内存发件箱存储
+///
+/// 不具备持久化能力，适用于测试或开发环境
+#[derive(Default)]
+pub struct InMemoryOutboxStore {
+    pending: Mutex<Vec<OutboxRecord>>,
+    dead_letters: Mutex<Vec<OutboxRecord>>,
+}
+
+impl InMemoryOutboxStore {
+    /// 创建内存存储
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OutboxStore for InMemoryOutboxStore {
+    async fn append(&self, record: &OutboxRecord) -> Result<()> {
+        self.pending.lock().push(record.clone());
+        Ok(())
+    }
+
+    async fn record_failure(&self, id: &str, attempts: u32, error: &str) -> Result<()> {
+        if let Some(record) = self.pending.lock().iter_mut().find(|r| r.id == id) {
+            record.attempts = attempts;
+            record.last_error = Some(error.to_string());
+        }
+        Ok(())
+    }
+
+    async fn complete(&self, id: &str) -> Result<()> {
+        self.pending.lock().retain(|r| r.id != id);
+        Ok(())
+    }
+
+    async fn dead_letter(&self, id: &str) -> Result<()> {
+        let mut pending = self.pending.lock();
+        if let Some(index) = pending.iter().position(|r| r.id == id) {
+            let record = pending.remove(index);
+            self.dead_letters.lock().push(record);
+        }
+        Ok(())
+    }
+
+    async fn pending(&self) -> Result<Vec<OutboxRecord>> {
+        Ok(self.pending.lock().clone())
+    }
+
+    async fn recovered(&self) -> Result<Vec<OutboxRecord>> {
+        // 内存存储不会跨进程保留记录
+        Ok(Vec::new())
+    }
+
+    async fn dead_letters(&self) -> Result<Vec<OutboxRecord>> {
+        Ok(self.dead_letters.lock().clone())
+    }
+}
+
+/// 发件箱日志条目（JSON Lines 格式，每行一条）
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum OutboxLogEntry {
+    Append { record: OutboxRecord },
+    Failure { id: String, attempts: u32, error: String },
+    Complete { id: String },
+    DeadLetter { id: String },
+}
+
+/// 本地文件发件箱存储
+///
+/// 以 JSON Lines 格式追加写入日志文件，每次写入后同步到磁盘；打开时回放日志恢复未完成的记录，
+/// 并压缩日志只保留未完成的记录。死信记录写入同目录下的 `<文件名>.dead-letter.jsonl`
+///
+/// 文件读写在 tokio 阻塞线程池中执行，不会阻塞异步工作线程
+pub struct FileOutboxStore {
+    dead_letter_path: PathBuf,
+    state: Arc<Mutex<FileOutboxState>>,
+}
+
+struct FileOutboxState {
+    log: File,
+    pending: Vec<OutboxRecord>,
+    /// 打开时从日志中恢复的记录 ID
+    recovered: HashSet<String>,
+}
+
+impl FileOutboxStore {
+    /// 打开（或创建）发件箱文件
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create outbox directory: {}", parent.display()))?;
+        }
+
+        let pending = if path.exists() {
+            Self::replay_log(&path)?
+        } else {
+            Vec::new()
+        };
+
+        // 压缩日志：只保留未完成的记录
+        let compacted = path.with_extension("jsonl.tmp");
+        {
+            let mut file = File::create(&compacted)
+                .with_context(|| format!("Failed to create outbox file: {}", compacted.display()))?;
+            for record in &pending {
+                let entry = OutboxLogEntry::Append { record: record.clone() };
+                writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+            }
+            file.sync_all()?;
+        }
+        std::fs::rename(&compacted, &path)
+            .with_context(|| format!("Failed to replace outbox file: {}", path.display()))?;
+
+        let log = OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open outbox file: {}", path.display()))?;
+
+        let file_name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "event-outbox".to_string());
+        let dead_letter_path = path.with_file_name(format!("{}.dead-letter.jsonl", file_name));
+
+        if !pending.is_empty() {
+            tracing::info!(
+                "Recovered {} incomplete event(s) from outbox: {}",
+                pending.len(),
+                path.display()
+            );
+        }
+
+        let recovered = pending.iter().map(|record| record.id.clone()).collect();
+        Ok(Self {
+            dead_letter_path,
+            state: Arc::new(Mutex::new(FileOutboxState { log, pending, recovered })),
+        })
+    }
+
+    /// 死信文件路径
+    pub fn dead_letter_path(&self) -> &Path {
+        &self.dead_letter_path
+    }
+
+    /// 回放日志，得到未完成的记录
+    fn replay_log(path: &Path) -> Result<Vec<OutboxRecord>> {
+        let file = File::open(path)
+            .with_context(|| format!("Failed to open outbox file: {}", path.display()))?;
+
+        let mut pending: Vec<OutboxRecord> = Vec::new();
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            // 崩溃时最后一行可能只写入了一部分，跳过无法解析的行
+            let entry: OutboxLogEntry = match serde_json::from_str(&line) {
+                Ok(entry) => entry,
+                Err(e) => {
+                    tracing::warn!(
+                        "Skipping malformed outbox entry at {}:{}: {}",
+                        path.display(),
+                        index + 1,
+                        e
+                    );
+                    continue;
+                }
+            };
+
+            match entry {
+                OutboxLogEntry::Append { record } => pending.push(record),
+                OutboxLogEntry::Failure { id, attempts, error } => {
+                    if let Some(record) = pending.iter_mut().find(|r| r.id == id) {
+                        record.attempts = attempts;
+                        record.last_error = Some(error);
+                    }
+                }
+                OutboxLogEntry::Complete { id } | OutboxLogEntry::DeadLetter { id } => {
+                    pending.retain(|r| r.id != id);
+                }
+            }
+        }
+
+        Ok(pending)
+    }
+
+    fn write_entry(log: &mut File, entry: &OutboxLogEntry) -> Result<()> {
+        writeln!(log, "{}", serde_json::to_string(entry)?)?;
+        log.sync_data()?;
+        Ok(())
+    }
+
+    /// 在阻塞线程池中持有锁并操作存储状态
+    async fn with_state<T, F>(&self, operation: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut FileOutboxState) -> Result<T> + Send + 'static,
+    {
+        let state = Arc::clone(&self.state);
+        tokio::task::spawn_blocking(move || operation(&mut state.lock()))
+            .await
+            .context("Outbox store task failed")?
+    }
+
+    /// 追加一条死信记录
+    fn write_dead_letter(path: &Path, record: &OutboxRecord) -> Result<()> {
+        let mut dead_letters = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open dead letter file: {}", path.display()))?;
+        writeln!(dead_letters, "{}", serde_json::to_string(record)?)?;
+        dead_letters.sync_data()?;
+        Ok(())
+    }
+
+    /// 读取死信文件
+    fn read_dead_letters(path: &Path) -> Result<Vec<OutboxRecord>> {
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let file = File::open(path)?;
+        let mut records = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(&line) {
+                Ok(record) => records.push(record),
+                Err(e) => tracing::warn!("Skipping malformed dead letter entry: {}", e),
+            }
+        }
+        Ok(records)
+    }
+}
+
+#[async_trait]
+impl OutboxStore for FileOutboxStore {
+    async fn append(&self, record: &OutboxRecord) -> Result<()> {
+        let record = record.clone();
+        self.with_state(move |state| {
+            Self::write_entry(&mut state.log, &OutboxLogEntry::Append { record: record.clone() })?;
+            state.pending.push(record);
+            Ok(())
+        })
+        .await
+    }
+
+    async fn record_failure(&self, id: &str, attempts: u32, error: &str) -> Result<()> {
+        let id = id.to_string();
+        let error = error.to_string();
+        self.with_state(move |state| {
+            Self::write_entry(
+                &mut state.log,
+                &OutboxLogEntry::Failure {
+                    id: id.clone(),
+                    attempts,
+                    error: error.clone(),
+                },
+            )?;
+            if let Some(record) = state.pending.iter_mut().find(|r| r.id == id) {
+                record.attempts = attempts;
+                record.last_error = Some(error);
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    async fn complete(&self, id: &str) -> Result<()> {
+        let id = id.to_string();
+        self.with_state(move |state| {
+            Self::write_entry(&mut state.log, &OutboxLogEntry::Complete { id: id.clone() })?;
+            state.pending.retain(|r| r.id != id);
+            Ok(())
+        })
+        .await
+    }
+
+    async fn dead_letter(&self, id: &str) -> Result<()> {
+        let id = id.to_string();
+        let dead_letter_path = self.dead_letter_path.clone();
+        self.with_state(move |state| {
+            let Some(index) = state.pending.iter().position(|r| r.id == id) else {
+                return Ok(());
+            };
+
+            // 先写死信文件再从发件箱移除，崩溃时最多重复一条死信
+            Self::write_dead_letter(&dead_letter_path, &state.pending[index])?;
+            Self::write_entry(&mut state.log, &OutboxLogEntry::DeadLetter { id })?;
+            state.pending.remove(index);
+            Ok(())
+        })
+        .await
+    }
+
+    async fn pending(&self) -> Result<Vec<OutboxRecord>> {
+        self.with_state(|state| Ok(state.pending.clone())).await
+    }
+
+    async fn recovered(&self) -> Result<Vec<OutboxRecord>> {
+        self.with_state(|state| {
+            Ok(state
+                .pending
+                .iter()
+                .filter(|record| state.recovered.contains(&record.id))
+                .cloned()
+                .collect())
+        })
+        .await
+    }
+
+    async fn dead_letters(&self) -> Result<Vec<OutboxRecord>> {
+        let dead_letter_path = self.dead_letter_path.clone();
+        tokio::task::spawn_blocking(move || Self::read_dead_letters(&dead_letter_path))
+            .await
+            .context("Outbox store task failed")?
+    }
+}
+
+/// 发件箱重试策略
+///
+/// 第 n 次重试前等待 `initial_backoff * multiplier^(n-1)`，不超过 `max_backoff`
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// 最大尝试次数（包含第一次分发）
+    pub max_attempts: u32,
+    /// 首次重试前的等待时间
+    pub initial_backoff: Duration,
+    /// 等待时间的增长倍数
+    pub multiplier: f64,
+    /// 最大等待时间
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            multiplier: 2.0,
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 创建指定最大尝试次数的重试策略
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// 不重试，第一次失败即转入死信
+    pub fn no_retry() -> Self {
+        Self::new(1)
+    }
+
+    /// 设置等待时间
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// 设置等待时间的增长倍数
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// 从配置读取重试策略（chimera.events.outbox.*）
+    pub fn from_environment(environment: &Environment) -> Self {
+        let default = Self::default();
+        let millis = |key: &str, default: Duration| {
+            environment
+                .get_i64(key)
+                .map(|ms| Duration::from_millis(ms.max(0) as u64))
+                .unwrap_or(default)
+        };
+
+        Self::new(
+            environment
+                .get_i64(CONFIG_EVENTS_OUTBOX_MAX_ATTEMPTS)
+                .map(|n| n.clamp(1, u32::MAX as i64) as u32)
+                .unwrap_or(default.max_attempts),
+        )
+        .with_backoff(
+            millis(CONFIG_EVENTS_OUTBOX_INITIAL_BACKOFF_MS, default.initial_backoff),
+            millis(CONFIG_EVENTS_OUTBOX_MAX_BACKOFF_MS, default.max_backoff),
+        )
+        .with_multiplier(
+            environment
+                .get_f64(CONFIG_EVENTS_OUTBOX_BACKOFF_MULTIPLIER)
+                .unwrap_or(default.multiplier),
+        )
+    }
+
+    /// 第 `attempt` 次失败后、下一次重试前的等待时间
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.powi(attempt.saturating_sub(1) as i32);
+        self.initial_backoff.mul_f64(factor).min(self.max_backoff)
+    }
+}
+
+/// 发件箱重放结果
+#[derive(Debug, Clone, Default)]
+pub struct OutboxReplayReport {
+    /// 分发成功的事件数
+    pub delivered: usize,
+    /// 转入死信的事件数
+    pub dead_lettered: usize,
+    /// 因事件类型未注册而跳过的事件数（保留在发件箱中）
+    pub skipped: usize,
+    /// 因存储错误未能处理的事件数（保留在发件箱中，下次启动时再次重放）
+    pub failed: usize,
+}
+
+/// 单个事件的分发结果
+enum Delivery {
+    /// 所有监听器处理成功
+    Delivered,
+    /// 重试耗尽，已转入死信存储
+    DeadLettered(anyhow::Error),
+}
+
+/// 事件发件箱
+///
+/// 持久化事件后再通过 `ApplicationEventPublisher::publish_event_and_wait` 分发，
+/// 启用 `chimera.events.outbox.enabled` 后框架会注册为 Bean，并在启动完成后于后台重放未完成的事件。
+/// 投递语义为至少一次，见 `DurableEvent`
+#[derive(Clone)]
+pub struct EventOutbox {
+    publisher: Arc<ApplicationEventPublisher>,
+    store: Arc<dyn OutboxStore>,
+    retry_policy: RetryPolicy,
+    decoders: Arc<RwLock<HashMap<String, EventDecoder>>>,
+}
+
+impl EventOutbox {
+    /// 创建发件箱，并收集通过 `submit_durable_event!` 注册的事件类型
+    pub fn new(publisher: Arc<ApplicationEventPublisher>, store: Arc<dyn OutboxStore>) -> Self {
+        let decoders = inventory::iter::<DurableEventRegistry>
+            .into_iter()
+            .map(|registry| ((registry.event_type)().to_string(), registry.decoder))
+            .collect();
+
+        Self {
+            publisher,
+            store,
+            retry_policy: RetryPolicy::default(),
+            decoders: Arc::new(RwLock::new(decoders)),
+        }
+    }
+
+    /// 设置重试策略
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// 注册事件类型（重放时用于反序列化）
+    pub fn register_event_type<E: DurableEvent>(&self) {
+        self.decoders
+            .write()
+            .insert(E::durable_type().to_string(), decode_event::<E>);
+    }
+
+    /// 获取存储
+    pub fn store(&self) -> &Arc<dyn OutboxStore> {
+        &self.store
+    }
+
+    /// 获取重试策略
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// 持久化并发布事件
+    ///
+    /// 事件写入存储后才会分发，所有监听器成功后标记为完成，返回记录 ID；
+    /// 重试耗尽时事件转入死信存储并返回错误
+    pub async fn publish<E: DurableEvent>(&self, event: E) -> Result<String> {
+        self.register_event_type::<E>();
+
+        let payload = serde_json::to_value(&event)
+            .with_context(|| format!("Failed to serialize durable event '{}'", E::durable_type()))?;
+        let record = OutboxRecord::new(E::durable_type(), payload);
+        self.store.append(&record).await?;
+
+        let id = record.id.clone();
+        match self.deliver(record, Arc::new(event)).await? {
+            Delivery::Delivered => Ok(id),
+            Delivery::DeadLettered(e) => Err(e),
+        }
+    }
+
+    /// 重放上次运行遗留的未完成事件（见 `OutboxStore::recovered`）
+    ///
+    /// 逐条分发（包括重试等待），单条记录的存储错误只会记录日志并保留该记录，不会中断重放。
+    /// 本次运行中 `publish` 写入的事件由 `publish` 自己分发，不会被重放，因此重放可以与发布并发执行
+    pub async fn replay(&self) -> Result<OutboxReplayReport> {
+        let mut report = OutboxReplayReport::default();
+
+        for record in self.store.recovered().await? {
+            let id = record.id.clone();
+            match self.replay_record(record).await {
+                Ok(Some(Delivery::Delivered)) => report.delivered += 1,
+                Ok(Some(Delivery::DeadLettered(_))) => report.dead_lettered += 1,
+                Ok(None) => report.skipped += 1,
+                Err(e) => {
+                    tracing::error!("Failed to replay outbox event {}: {:#}", id, e);
+                    report.failed += 1;
+                }
+            }
+        }
+
+        if report.delivered + report.dead_lettered + report.skipped + report.failed > 0 {
+            tracing::info!(
+                "Replayed event outbox: {} delivered, {} dead-lettered, {} skipped, {} failed",
+                report.delivered,
+                report.dead_lettered,
+                report.skipped,
+                report.failed
+            );
+        }
+
+        Ok(report)
+    }
+
+    /// 重放单条记录，事件类型未注册时返回 None
+    async fn replay_record(&self, record: OutboxRecord) -> Result<Option<Delivery>> {
+        let decoder = self.decoders.read().get(&record.event_type).copied();
+        let Some(decoder) = decoder else {
+            tracing::warn!(
+                "Cannot replay outbox event {}: event type '{}' is not registered",
+                record.id,
+                record.event_type
+            );
+            return Ok(None);
+        };
+
+        let event = match decoder(record.payload.clone()) {
+            Ok(event) => event,
+            Err(e) => {
+                tracing::error!("Cannot replay outbox event {}: {:#}", record.id, e);
+                self.store.record_failure(&record.id, record.attempts, &format!("{:#}", e)).await?;
+                self.store.dead_letter(&record.id).await?;
+                return Ok(Some(Delivery::DeadLettered(e)));
+            }
+        };
+
+        self.deliver(record, event).await.map(Some)
+    }
+
+    /// 获取死信记录
+    pub async fn dead_letters(&self) -> Result<Vec<OutboxRecord>> {
+        self.store.dead_letters().await
+    }
+
+    /// 分发事件，失败时按重试策略重试
+    ///
+    /// 每次尝试都会发给所有监听器；存储错误以 `Err` 返回，重试耗尽返回 `Delivery::DeadLettered`
+    async fn deliver(&self, record: OutboxRecord, event: Arc<dyn Event>) -> Result<Delivery> {
+        let mut attempts = record.attempts;
+
+        loop {
+            attempts += 1;
+            match self.publisher.publish_event_and_wait(Arc::clone(&event)).await {
+                Ok(()) => {
+                    self.store.complete(&record.id).await?;
+                    return Ok(Delivery::Delivered);
+                }
+                Err(e) => {
+                    let error = format!("{:#}", e);
+                    self.store.record_failure(&record.id, attempts, &error).await?;
+
+                    if attempts >= self.retry_policy.max_attempts {
+                        tracing::error!(
+                            "Outbox event {} ('{}') failed after {} attempt(s), moved to dead letter store: {}",
+                            record.id,
+                            record.event_type,
+                            attempts,
+                            error
+                        );
+                        self.store.dead_letter(&record.id).await?;
+                        return Ok(Delivery::DeadLettered(e.context(format!(
+                            "Outbox event {} moved to dead letter store after {} attempt(s)",
+                            record.id, attempts
+                        ))));
+                    }
+
+                    let backoff = self.retry_policy.backoff(attempts);
+                    tracing::warn!(
+                        "Outbox event {} ('{}') failed (attempt {}), retrying in {:?}: {}",
+                        record.id,
+                        record.event_type,
+                        attempts,
+                        backoff,
+                        error
+                    );
+                    tokio::time::sleep(backoff).await;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{ApplicationEventMulticaster, MethodEventListener, SimpleApplicationEventMulticaster};
+    use std::any::Any;
+
+    #[derive(Serialize, Deserialize)]
+    struct OrderPlaced {
+        order_id: u64,
+    }
+
+    impl Event for OrderPlaced {
+        fn event_name(&self) -> &str {
+            "OrderPlaced"
+        }
+
+        fn timestamp(&self) -> SystemTime {
+            SystemTime::UNIX_EPOCH
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    impl DurableEvent for OrderPlaced {}
+
+    /// 创建临时发件箱文件路径
+    fn temp_outbox_path() -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("chimera-outbox-{}", uuid::Uuid::new_v4()))
+            .join("outbox.jsonl")
+    }
+
+    /// 创建发件箱，监听器处理每个事件前等待一段时间，并记录收到的订单 ID
+    fn outbox_with_slow_listener(store: Arc<dyn OutboxStore>) -> (EventOutbox, Arc<Mutex<Vec<u64>>>) {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let multicaster = Arc::new(SimpleApplicationEventMulticaster::new());
+        let captured = Arc::clone(&received);
+        multicaster.add_listener(Arc::new(MethodEventListener::<OrderPlaced>::new_async(
+            "slow",
+            move |event| {
+                let captured = Arc::clone(&captured);
+                Box::pin(async move {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    let order = event.as_any().downcast_ref::<OrderPlaced>().unwrap();
+                    captured.lock().push(order.order_id);
+                    Ok(Vec::new())
+                })
+            },
+        )));
+
+        let publisher = Arc::new(ApplicationEventPublisher::new(multicaster));
+        let outbox = EventOutbox::new(publisher, store);
+        outbox.register_event_type::<OrderPlaced>();
+        (outbox, received)
+    }
+
+    #[tokio::test]
+    async fn file_store_recovers_only_records_left_by_previous_run() {
+        let path = temp_outbox_path();
+        {
+            let store = FileOutboxStore::open(&path).unwrap();
+            for order_id in [1, 2] {
+                let record = OutboxRecord::new("order", serde_json::json!({ "order_id": order_id }));
+                store.append(&record).await.unwrap();
+            }
+            let completed = store.pending().await.unwrap()[0].id.clone();
+            store.complete(&completed).await.unwrap();
+        }
+
+        let store = FileOutboxStore::open(&path).unwrap();
+        store
+            .append(&OutboxRecord::new("order", serde_json::json!({ "order_id": 3 })))
+            .await
+            .unwrap();
+
+        let recovered = store.recovered().await.unwrap();
+        assert_eq!(recovered.len(), 1);
+        assert_eq!(recovered[0].payload["order_id"], 2);
+        assert_eq!(store.pending().await.unwrap().len(), 2);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn replay_overlapping_a_live_publish_delivers_each_event_once() {
+        let path = temp_outbox_path();
+        {
+            let store = FileOutboxStore::open(&path).unwrap();
+            let record = OutboxRecord::new(
+                OrderPlaced::durable_type(),
+                serde_json::to_value(OrderPlaced { order_id: 1 }).unwrap(),
+            );
+            store.append(&record).await.unwrap();
+        }
+
+        let store: Arc<dyn OutboxStore> = Arc::new(FileOutboxStore::open(&path).unwrap());
+        let (outbox, received) = outbox_with_slow_listener(Arc::clone(&store));
+
+        // 发布先写入存储，在监听器执行期间开始重放
+        let publishing = {
+            let outbox = outbox.clone();
+            tokio::spawn(async move { outbox.publish(OrderPlaced { order_id: 2 }).await })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let report = outbox.replay().await.unwrap();
+        publishing.await.unwrap().unwrap();
+
+        assert_eq!(report.delivered, 1);
+        let mut received = received.lock().clone();
+        received.sort();
+        assert_eq!(received, [1, 2]);
+        assert!(store.pending().await.unwrap().is_empty());
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn in_memory_store_has_nothing_to_replay() {
+        let store = Arc::new(InMemoryOutboxStore::new());
+        store
+            .append(&OutboxRecord::new("order", serde_json::json!({ "order_id": 1 })))
+            .await
+            .unwrap();
+
+        let (outbox, received) = outbox_with_slow_listener(store);
+        let report = outbox.replay().await.unwrap();
+
+        assert_eq!(report.delivered + report.skipped, 0);
+        assert!(received.lock().is_empty());
+    }
+}
@@ -1,11 +1,25 @@
 //! 插件机制
 //!
 //! 提供应用插件的定义和管理，支持模块化扩展
+//!
+//! 插件按依赖关系排序：`depends_on` 声明必需的插件（缺失或被禁用时启动失败），
+//! `after` 只声明顺序（对应插件不存在时忽略），没有依赖关系的插件按 `priority` 排序。
+//! 配置 `chimera.plugins.<name>.enabled = false` 可以禁用指定插件
 
+use crate::config::{ConfigValue, Environment, MapPropertySource};
+use crate::constants::CONFIG_PLUGINS;
 use crate::prelude::*;
+use anyhow::bail;
 use async_trait::async_trait;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+/// 插件默认配置源的优先级（低于所有其他配置源）
+pub const PLUGIN_DEFAULTS_PRIORITY: i32 = -1000;
+
 /// 应用插件 trait
 ///
 /// 实现此 trait 以创建可插拔的应用模块
@@ -19,6 +33,30 @@ pub trait ApplicationPlugin: Send + Sync {
         100
     }
 
+    /// 插件版本（用于启动报告）
+    fn version(&self) -> Option<&str> {
+        None
+    }
+
+    /// 必需的插件，这些插件会先于本插件配置和启动
+    ///
+    /// 依赖的插件没有注册或被禁用时启动失败
+    fn depends_on(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// 需要排在本插件之前的插件（对应插件不存在时忽略）
+    fn after(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// 插件提供的默认配置
+    ///
+    /// 以优先级最低的配置源加入环境，任何配置文件或环境变量都可以覆盖
+    fn default_properties(&self) -> HashMap<String, ConfigValue> {
+        HashMap::new()
+    }
+
     /// 配置阶段 - 在组件扫描之前执行
     ///
     /// 用于注册额外的 Bean、配置源等
@@ -52,6 +90,9 @@ pub trait ApplicationPlugin: Send + Sync {
 /// 插件注册表
 pub struct PluginRegistry {
     plugins: Vec<Box<dyn ApplicationPlugin>>,
+    disabled: Vec<String>,
+    /// 已经开始启动的插件数量（关闭时只关闭这些插件）
+    started: AtomicUsize,
 }
 
 impl PluginRegistry {
@@ -59,6 +100,8 @@ impl PluginRegistry {
     pub fn new() -> Self {
         Self {
             plugins: Vec::new(),
+            disabled: Vec::new(),
+            started: AtomicUsize::new(0),
         }
     }
 
@@ -73,6 +116,60 @@ impl PluginRegistry {
         self.plugins.sort_by_key(|p| p.priority());
     }
 
+    /// 解析插件：移除被禁用的插件，并按依赖关系和优先级排序
+    pub fn resolve(&mut self, environment: &Environment) -> Result<()> {
+        let (enabled, disabled): (Vec<_>, Vec<_>) = std::mem::take(&mut self.plugins)
+            .into_iter()
+            .partition(|plugin| {
+                let key = format!("{}.{}.enabled", CONFIG_PLUGINS, plugin.name());
+                environment.get_bool(&key).unwrap_or(true)
+            });
+
+        for plugin in &disabled {
+            tracing::info!("Plugin '{}' is disabled by configuration", plugin.name());
+        }
+        self.disabled = disabled.iter().map(|p| p.name().to_string()).collect();
+        self.plugins = sort_topologically(enabled, &self.disabled)?;
+        Ok(())
+    }
+
+    /// 将插件的默认配置加入环境（每个插件一个优先级最低的配置源）
+    pub fn apply_default_properties(&self, environment: &Environment) {
+        for plugin in &self.plugins {
+            let properties = plugin.default_properties();
+            if properties.is_empty() {
+                continue;
+            }
+
+            let mut source = MapPropertySource::new(format!("plugin-defaults:{}", plugin.name()))
+                .with_priority(PLUGIN_DEFAULTS_PRIORITY);
+            for (key, value) in properties {
+                source = source.with_property(key, value);
+            }
+            tracing::debug!("Applied default properties of plugin '{}'", plugin.name());
+            environment.add_property_source(Box::new(source));
+        }
+    }
+
+    /// 插件报告：已加载的插件（按执行顺序）和被禁用的插件
+    pub fn report(&self) -> PluginReport {
+        PluginReport {
+            loaded: self
+                .plugins
+                .iter()
+                .map(|plugin| PluginInfo {
+                    name: plugin.name().to_string(),
+                    version: plugin.version().map(str::to_string),
+                    priority: plugin.priority(),
+                    depends_on: plugin.depends_on().into_iter().map(str::to_string).collect(),
+                    after: plugin.after().into_iter().map(str::to_string).collect(),
+                    keep_alive: plugin.keep_alive(),
+                })
+                .collect(),
+            disabled: self.disabled.clone(),
+        }
+    }
+
     /// 获取所有插件
     pub fn plugins(&self) -> &[Box<dyn ApplicationPlugin>] {
         &self.plugins
@@ -88,21 +185,30 @@ impl PluginRegistry {
     }
 
     /// 执行启动阶段
+    ///
+    /// 某个插件启动失败时立即返回错误，此前的插件（包括启动失败的插件）由 `shutdown_all` 关闭
     pub async fn startup_all(&self, context: &Arc<ApplicationContext>) -> Result<()> {
         for plugin in &self.plugins {
             tracing::info!("Starting plugin: {}", plugin.name());
+            self.started.fetch_add(1, Ordering::SeqCst);
             plugin.on_startup(context).await?;
+            context.publish_event(Arc::new(PluginStartedEvent::new(plugin.name().to_string())));
         }
         Ok(())
     }
 
-    /// 执行关闭阶段
+    /// 执行关闭阶段（只关闭已经开始启动的插件）
     pub async fn shutdown_all(&self, context: &Arc<ApplicationContext>) -> Result<()> {
+        let started = self.started.swap(0, Ordering::SeqCst).min(self.plugins.len());
+
         // 逆序关闭
-        for plugin in self.plugins.iter().rev() {
+        for plugin in self.plugins[..started].iter().rev() {
             tracing::info!("Shutting down plugin: {}", plugin.name());
-            if let Err(e) = plugin.on_shutdown(context).await {
-                tracing::error!("Failed to shutdown plugin {}: {}", plugin.name(), e);
+            match plugin.on_shutdown(context).await {
+                Ok(()) => {
+                    context.publish_event(Arc::new(PluginStoppedEvent::new(plugin.name().to_string())));
+                }
+                Err(e) => tracing::error!("Failed to shutdown plugin {}: {}", plugin.name(), e),
             }
         }
         Ok(())
@@ -120,6 +226,139 @@ impl Default for PluginRegistry {
     }
 }
 
+/// 插件信息
+#[derive(Debug, Clone, Serialize)]
+pub struct PluginInfo {
+    /// 插件名称
+    pub name: String,
+    /// 插件版本
+    pub version: Option<String>,
+    /// 插件优先级
+    pub priority: i32,
+    /// 必需的插件
+    pub depends_on: Vec<String>,
+    /// 需要排在之前的插件
+    pub after: Vec<String>,
+    /// 是否保持应用运行
+    pub keep_alive: bool,
+}
+
+/// 插件报告
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PluginReport {
+    /// 已加载的插件（按执行顺序）
+    pub loaded: Vec<PluginInfo>,
+    /// 被配置禁用的插件
+    pub disabled: Vec<String>,
+}
+
+impl std::fmt::Display for PluginReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Loaded {} plugin(s)", self.loaded.len())?;
+        for (index, plugin) in self.loaded.iter().enumerate() {
+            write!(
+                f,
+                "\n  {}. {} {} (priority: {}",
+                index + 1,
+                plugin.name,
+                plugin.version.as_deref().unwrap_or("(unknown version)"),
+                plugin.priority
+            )?;
+            if !plugin.depends_on.is_empty() {
+                write!(f, ", depends on: {}", plugin.depends_on.join(", "))?;
+            }
+            if !plugin.after.is_empty() {
+                write!(f, ", after: {}", plugin.after.join(", "))?;
+            }
+            write!(f, ")")?;
+        }
+        if !self.disabled.is_empty() {
+            write!(f, "\nDisabled plugin(s): {}", self.disabled.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// 按依赖关系对插件拓扑排序，没有依赖关系的插件按优先级（其次按注册顺序）排列
+fn sort_topologically(
+    plugins: Vec<Box<dyn ApplicationPlugin>>,
+    disabled: &[String],
+) -> Result<Vec<Box<dyn ApplicationPlugin>>> {
+    let index_by_name: HashMap<String, usize> = plugins
+        .iter()
+        .enumerate()
+        .map(|(index, plugin)| (plugin.name().to_string(), index))
+        .collect();
+
+    // edges[a] 包含必须排在 a 之后的插件
+    let mut edges: Vec<HashSet<usize>> = vec![HashSet::new(); plugins.len()];
+    let mut in_degree = vec![0usize; plugins.len()];
+
+    for (index, plugin) in plugins.iter().enumerate() {
+        for dependency in plugin.depends_on() {
+            match index_by_name.get(dependency) {
+                Some(&dep_index) => {
+                    if edges[dep_index].insert(index) {
+                        in_degree[index] += 1;
+                    }
+                }
+                None if disabled.iter().any(|name| name == dependency) => bail!(
+                    "Plugin '{}' depends on plugin '{}' which is disabled",
+                    plugin.name(),
+                    dependency
+                ),
+                None => bail!(
+                    "Plugin '{}' depends on plugin '{}' which is not registered",
+                    plugin.name(),
+                    dependency
+                ),
+            }
+        }
+        for predecessor in plugin.after() {
+            if let Some(&pre_index) = index_by_name.get(predecessor) {
+                if edges[pre_index].insert(index) {
+                    in_degree[index] += 1;
+                }
+            }
+        }
+    }
+
+    let mut ready: BinaryHeap<Reverse<(i32, usize)>> = plugins
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| in_degree[*index] == 0)
+        .map(|(index, plugin)| Reverse((plugin.priority(), index)))
+        .collect();
+
+    let mut order = Vec::with_capacity(plugins.len());
+    while let Some(Reverse((_, index))) = ready.pop() {
+        order.push(index);
+        for &next in &edges[index] {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                ready.push(Reverse((plugins[next].priority(), next)));
+            }
+        }
+    }
+
+    if order.len() < plugins.len() {
+        let mut cycle: Vec<&str> = plugins
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| in_degree[*index] > 0)
+            .map(|(_, plugin)| plugin.name())
+            .collect();
+        cycle.sort_unstable();
+        bail!("Circular plugin dependency detected among: {}", cycle.join(", "));
+    }
+
+    let mut slots: Vec<Option<Box<dyn ApplicationPlugin>>> = plugins.into_iter().map(Some).collect();
+    Ok(order
+        .into_iter()
+        .filter_map(|index| slots[index].take())
+        .collect())
+}
+
 /// 用于全局收集插件的宏
 #[macro_export]
 macro_rules! submit_plugin {
@@ -150,3 +389,174 @@ pub fn load_plugins() -> PluginRegistry {
     registry.sort_by_priority();
     registry
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestPlugin {
+        name: &'static str,
+        priority: i32,
+        depends_on: Vec<&'static str>,
+        after: Vec<&'static str>,
+    }
+
+    impl TestPlugin {
+        fn new(name: &'static str, priority: i32) -> Self {
+            Self {
+                name,
+                priority,
+                depends_on: Vec::new(),
+                after: Vec::new(),
+            }
+        }
+
+        fn depends_on(mut self, name: &'static str) -> Self {
+            self.depends_on.push(name);
+            self
+        }
+
+        fn after(mut self, name: &'static str) -> Self {
+            self.after.push(name);
+            self
+        }
+    }
+
+    #[async_trait]
+    impl ApplicationPlugin for TestPlugin {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn priority(&self) -> i32 {
+            self.priority
+        }
+
+        fn depends_on(&self) -> Vec<&str> {
+            self.depends_on.clone()
+        }
+
+        fn after(&self) -> Vec<&str> {
+            self.after.clone()
+        }
+    }
+
+    fn sort(plugins: Vec<TestPlugin>, disabled: &[&str]) -> Result<Vec<String>> {
+        let plugins = plugins
+            .into_iter()
+            .map(|plugin| Box::new(plugin) as Box<dyn ApplicationPlugin>)
+            .collect();
+        let disabled: Vec<String> = disabled.iter().map(|name| name.to_string()).collect();
+        Ok(sort_topologically(plugins, &disabled)?
+            .iter()
+            .map(|plugin| plugin.name().to_string())
+            .collect())
+    }
+
+    #[test]
+    fn independent_plugins_are_ordered_by_priority_then_registration() {
+        let order = sort(
+            vec![TestPlugin::new("c", 10), TestPlugin::new("a", 0), TestPlugin::new("b", 10)],
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(order, ["a", "c", "b"]);
+    }
+
+    #[test]
+    fn dependencies_run_before_dependents_regardless_of_priority() {
+        let order = sort(
+            vec![
+                TestPlugin::new("web", 0).depends_on("db"),
+                TestPlugin::new("db", 50),
+                TestPlugin::new("metrics", 10).after("web"),
+            ],
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(order, ["db", "web", "metrics"]);
+    }
+
+    #[test]
+    fn after_ignores_missing_plugins() {
+        let order = sort(vec![TestPlugin::new("web", 0).after("missing")], &[]).unwrap();
+
+        assert_eq!(order, ["web"]);
+    }
+
+    #[test]
+    fn missing_or_disabled_dependencies_fail() {
+        let missing = sort(vec![TestPlugin::new("web", 0).depends_on("db")], &[]).unwrap_err();
+        assert!(missing.to_string().contains("not registered"), "{}", missing);
+
+        let disabled = sort(vec![TestPlugin::new("web", 0).depends_on("db")], &["db"]).unwrap_err();
+        assert!(disabled.to_string().contains("disabled"), "{}", disabled);
+    }
+
+    #[test]
+    fn cycles_are_reported() {
+        let error = sort(
+            vec![
+                TestPlugin::new("a", 0).depends_on("b"),
+                TestPlugin::new("b", 0).after("a"),
+                TestPlugin::new("c", 0),
+            ],
+            &[],
+        )
+        .unwrap_err();
+
+        assert_eq!(error.to_string(), "Circular plugin dependency detected among: a, b");
+    }
+
+    /// 记录启动和关闭顺序的插件
+    struct LifecyclePlugin {
+        name: &'static str,
+        fail_startup: bool,
+        events: Arc<parking_lot::Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl ApplicationPlugin for LifecyclePlugin {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn on_startup(&self, _context: &Arc<ApplicationContext>) -> Result<()> {
+            self.events.lock().push(format!("start {}", self.name));
+            if self.fail_startup {
+                bail!("{} failed to start", self.name);
+            }
+            Ok(())
+        }
+
+        async fn on_shutdown(&self, _context: &Arc<ApplicationContext>) -> Result<()> {
+            self.events.lock().push(format!("stop {}", self.name));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_startup_shuts_down_only_plugins_that_were_started() {
+        let events = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let mut registry = PluginRegistry::new();
+        for (name, fail_startup) in [("web", false), ("jobs", true), ("metrics", false)] {
+            registry.register(Box::new(LifecyclePlugin {
+                name,
+                fail_startup,
+                events: Arc::clone(&events),
+            }));
+        }
+        let context = ApplicationContext::builder().build().unwrap();
+
+        let error = registry.startup_all(&context).await.unwrap_err();
+        assert_eq!(error.to_string(), "jobs failed to start");
+
+        registry.shutdown_all(&context).await.unwrap();
+        // 重复关闭不会再次关闭插件
+        registry.shutdown_all(&context).await.unwrap();
+
+        assert_eq!(*events.lock(), ["start web", "start jobs", "stop jobs", "stop web"]);
+    }
+}
@@ -0,0 +1,272 @@
+//! Profile 表达式与 Profile 组
+//!
+//! 支持类似 Spring 的 profile 表达式：
+//! - `prod` - 激活了 prod
+//! - `!prod` - 未激活 prod
+//! - `prod & eu` - 同时激活 prod 和 eu
+//! - `prod | staging` - 激活了 prod 或 staging
+//! - `prod & !(eu | us)` - 支持括号组合
+//!
+//! 优先级：`!` > `&` > `|`
+
+use crate::config::Environment;
+use crate::constants::CONFIG_PROFILES_GROUP_PREFIX;
+use anyhow::{bail, Result};
+
+/// Profile 表达式
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileExpression {
+    /// 单个 profile
+    Profile(String),
+
+    /// 取反
+    Not(Box<ProfileExpression>),
+
+    /// 与
+    And(Box<ProfileExpression>, Box<ProfileExpression>),
+
+    /// 或
+    Or(Box<ProfileExpression>, Box<ProfileExpression>),
+}
+
+impl ProfileExpression {
+    /// 解析 profile 表达式
+    pub fn parse(expression: &str) -> Result<Self> {
+        let tokens = tokenize(expression)?;
+        if tokens.is_empty() {
+            bail!("Empty profile expression");
+        }
+
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+        if parser.pos != parser.tokens.len() {
+            bail!("Invalid profile expression '{}': unexpected trailing input", expression);
+        }
+        Ok(expr)
+    }
+
+    /// 判断表达式是否匹配激活的 profiles
+    pub fn matches(&self, active_profiles: &[String]) -> bool {
+        match self {
+            ProfileExpression::Profile(name) => active_profiles.iter().any(|p| p == name),
+            ProfileExpression::Not(inner) => !inner.matches(active_profiles),
+            ProfileExpression::And(left, right) => {
+                left.matches(active_profiles) && right.matches(active_profiles)
+            }
+            ProfileExpression::Or(left, right) => {
+                left.matches(active_profiles) || right.matches(active_profiles)
+            }
+        }
+    }
+}
+
+/// 展开 profile 组
+///
+/// 组通过 `chimera.profiles.group.<name> = ["member1", "member2"]` 定义，
+/// 激活组时其成员会紧跟在组名之后被激活，组可以嵌套，重复的 profile 只保留第一次出现
+pub fn expand_profile_groups(env: &Environment, profiles: &[String]) -> Vec<String> {
+    let mut expanded = Vec::new();
+    for profile in profiles {
+        expand_group(env, profile, &mut expanded);
+    }
+    expanded
+}
+
+fn expand_group(env: &Environment, profile: &str, expanded: &mut Vec<String>) {
+    if expanded.iter().any(|p| p == profile) {
+        return;
+    }
+    expanded.push(profile.to_string());
+
+    let group_key = format!("{}{}", CONFIG_PROFILES_GROUP_PREFIX, profile);
+    if let Some(members) = env.get_string_array(&group_key) {
+        tracing::debug!("Expanding profile group '{}': {:?}", profile, members);
+        for member in members {
+            expand_group(env, &member, expanded);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Name(String),
+    Not,
+    And,
+    Or,
+    LeftParen,
+    RightParen,
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '!' => {
+                chars.next();
+                tokens.push(Token::Not);
+            }
+            '&' => {
+                chars.next();
+                tokens.push(Token::And);
+            }
+            '|' => {
+                chars.next();
+                tokens.push(Token::Or);
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LeftParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RightParen);
+            }
+            c if is_name_char(c) => {
+                let mut name = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !is_name_char(c) {
+                        break;
+                    }
+                    name.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Name(name));
+            }
+            other => bail!(
+                "Invalid character '{}' in profile expression '{}'",
+                other,
+                expression
+            ),
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<ProfileExpression> {
+        let mut left = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            let right = self.parse_and()?;
+            left = ProfileExpression::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<ProfileExpression> {
+        let mut left = self.parse_unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            let right = self.parse_unary()?;
+            left = ProfileExpression::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<ProfileExpression> {
+        match self.next() {
+            Some(Token::Not) => Ok(ProfileExpression::Not(Box::new(self.parse_unary()?))),
+            Some(Token::LeftParen) => {
+                let expr = self.parse_or()?;
+                match self.next() {
+                    Some(Token::RightParen) => Ok(expr),
+                    _ => bail!("Missing ')' in profile expression"),
+                }
+            }
+            Some(Token::Name(name)) => Ok(ProfileExpression::Profile(name)),
+            Some(token) => bail!("Unexpected token {:?} in profile expression", token),
+            None => bail!("Unexpected end of profile expression"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(name: &str) -> Box<ProfileExpression> {
+        Box::new(ProfileExpression::Profile(name.to_string()))
+    }
+
+    fn active(profiles: &[&str]) -> Vec<String> {
+        profiles.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_single_profile() {
+        assert_eq!(ProfileExpression::parse(" prod ").unwrap(), *profile("prod"));
+        assert_eq!(ProfileExpression::parse("eu-west.1").unwrap(), *profile("eu-west.1"));
+    }
+
+    #[test]
+    fn not_binds_tighter_than_and_and_and_tighter_than_or() {
+        let expr = ProfileExpression::parse("a | !b & c").unwrap();
+
+        assert_eq!(
+            expr,
+            ProfileExpression::Or(
+                profile("a"),
+                Box::new(ProfileExpression::And(Box::new(ProfileExpression::Not(profile("b"))), profile("c"))),
+            )
+        );
+    }
+
+    #[test]
+    fn parentheses_override_precedence() {
+        let expr = ProfileExpression::parse("prod & !(eu | us)").unwrap();
+
+        assert_eq!(
+            expr,
+            ProfileExpression::And(
+                profile("prod"),
+                Box::new(ProfileExpression::Not(Box::new(ProfileExpression::Or(profile("eu"), profile("us"))))),
+            )
+        );
+    }
+
+    #[test]
+    fn matches_active_profiles() {
+        let expr = ProfileExpression::parse("prod & !(eu | us)").unwrap();
+
+        assert!(expr.matches(&active(&["prod", "asia"])));
+        assert!(!expr.matches(&active(&["prod", "eu"])));
+        assert!(!expr.matches(&active(&["dev"])));
+        assert!(ProfileExpression::parse("!prod").unwrap().matches(&[]));
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        for expression in ["", "   ", "prod &", "(prod", "prod)", "prod eu", "prod && eu", "prod$"] {
+            assert!(
+                ProfileExpression::parse(expression).is_err(),
+                "expected '{}' to be rejected",
+                expression
+            );
+        }
+    }
+}
@@ -0,0 +1,382 @@
+//! 配置热加载
+//!
+//! 监听配置文件或目录的变化，重新加载对应的 PropertySource，
+//! 并通过 `EnvironmentChangeEvent` 通知应用，同时让刷新作用域的 Bean 失效重建
+//!
+//! ## 使用示例
+//!
+//! ```ignore
+//! let reloader = ConfigReloader::new(&context)
+//!     .debounce(Duration::from_millis(500));
+//! reloader.watch_file("config/application.toml", 0);
+//! reloader.start()?;
+//! ```
+//!
+//! 通常无需手动创建，设置 `chimera.config.reload.enabled = true` 后
+//! `ChimeraApplication` 会自动监听已加载的配置文件
+
+use crate::config::{PropertySource, TomlPropertySource};
+use crate::{ApplicationContext, Result};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use parking_lot::Mutex;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+/// 默认防抖时间（毫秒）
+pub const DEFAULT_RELOAD_DEBOUNCE_MS: u64 = 500;
+
+/// PropertySource 加载函数
+pub type PropertySourceLoader =
+    Arc<dyn Fn() -> Result<Box<dyn PropertySource>> + Send + Sync>;
+
+/// 被监听的配置源
+struct WatchedSource {
+    /// 监听的路径（文件或目录）
+    path: PathBuf,
+
+    /// 是否为目录（递归监听）
+    recursive: bool,
+
+    /// 重新加载配置源的函数
+    loader: PropertySourceLoader,
+}
+
+impl WatchedSource {
+    /// 判断文件变化是否影响此配置源
+    fn matches(&self, changed: &Path) -> bool {
+        let changed = normalize(changed);
+        let path = normalize(&self.path);
+        if self.recursive {
+            changed.starts_with(&path)
+        } else {
+            changed == path
+        }
+    }
+}
+
+/// 配置热加载器
+///
+/// 基于 notify 监听文件系统，变更经过防抖后重新加载配置源：
+/// - 加载成功：替换 Environment 中的同名配置源并发布 `EnvironmentChangeEvent`
+/// - 加载失败（例如 TOML 语法错误）：记录错误并保留旧配置
+pub struct ConfigReloader {
+    context: Weak<ApplicationContext>,
+    sources: Arc<Mutex<Vec<WatchedSource>>>,
+    debounce: Duration,
+    watcher: Mutex<Option<RecommendedWatcher>>,
+}
+
+impl ConfigReloader {
+    /// 创建配置热加载器
+    pub fn new(context: &Arc<ApplicationContext>) -> Self {
+        Self {
+            context: Arc::downgrade(context),
+            sources: Arc::new(Mutex::new(Vec::new())),
+            debounce: Duration::from_millis(DEFAULT_RELOAD_DEBOUNCE_MS),
+            watcher: Mutex::new(None),
+        }
+    }
+
+    /// 设置防抖时间
+    ///
+    /// 编辑器保存文件时通常会触发多次事件，防抖时间内的事件会合并处理
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// 监听 TOML 配置文件
+    ///
+    /// 重新加载时会按当前激活的 profiles 合并文件中的 `[chimera.profile.<表达式>]` 配置段
+    pub fn watch_file(&self, path: impl AsRef<Path>, priority: i32) {
+        let file = path.as_ref().to_path_buf();
+        let loader_path = file.clone();
+        let context = self.context.clone();
+        self.watch_source(file, false, Arc::new(move || {
+            let active_profiles = context
+                .upgrade()
+                .map(|ctx| ctx.environment().get_active_profiles())
+                .unwrap_or_default();
+            let source = TomlPropertySource::from_file(&loader_path)?
+                .with_active_profiles(&active_profiles)
+                .with_priority(priority);
+            Ok(Box::new(source) as Box<dyn PropertySource>)
+        }));
+    }
+
+    /// 监听目录，目录下任意文件变化时通过 loader 重新加载配置源
+    pub fn watch_directory(&self, path: impl AsRef<Path>, loader: PropertySourceLoader) {
+        self.watch_source(path.as_ref().to_path_buf(), true, loader);
+    }
+
+    /// 监听自定义配置源
+    pub fn watch_source(&self, path: PathBuf, recursive: bool, loader: PropertySourceLoader) {
+        tracing::debug!("Watching configuration source: {}", path.display());
+
+        if let Some(watcher) = self.watcher.lock().as_mut() {
+            if let Err(e) = watch_path(watcher, &path, recursive) {
+                tracing::warn!("Failed to watch {}: {}", path.display(), e);
+            }
+        }
+
+        self.sources.lock().push(WatchedSource {
+            path,
+            recursive,
+            loader,
+        });
+    }
+
+    /// 手动重新加载受指定路径影响的配置源
+    pub fn reload(&self, path: impl AsRef<Path>) -> Vec<String> {
+        reload_matching(&self.context, &self.sources, &[path.as_ref().to_path_buf()])
+    }
+
+    /// 手动重新加载所有配置源
+    pub fn reload_all(&self) -> Vec<String> {
+        let paths: Vec<PathBuf> = self.sources.lock().iter().map(|s| s.path.clone()).collect();
+        reload_matching(&self.context, &self.sources, &paths)
+    }
+
+    /// 开始监听
+    pub fn start(&self) -> Result<()> {
+        let mut guard = self.watcher.lock();
+        if guard.is_some() {
+            return Ok(());
+        }
+
+        let (tx, rx) = mpsc::channel::<Event>();
+        let mut watcher = RecommendedWatcher::new(
+            move |res: std::result::Result<Event, notify::Error>| match res {
+                Ok(event) => {
+                    let _ = tx.send(event);
+                }
+                Err(e) => {
+                    tracing::error!("Configuration file watching error: {}", e);
+                }
+            },
+            notify::Config::default(),
+        )?;
+
+        for source in self.sources.lock().iter() {
+            if let Err(e) = watch_path(&mut watcher, &source.path, source.recursive) {
+                tracing::warn!("Failed to watch {}: {}", source.path.display(), e);
+            }
+        }
+
+        let context = self.context.clone();
+        let sources = Arc::clone(&self.sources);
+        let debounce = self.debounce;
+
+        std::thread::Builder::new()
+            .name("chimera-config-reload".to_string())
+            .spawn(move || {
+                // watcher 被 drop 后 channel 断开，线程随之退出
+                while let Ok(event) = rx.recv() {
+                    let mut changed: Vec<PathBuf> = event.paths;
+
+                    // 防抖：合并防抖时间内的后续事件
+                    loop {
+                        match rx.recv_timeout(debounce) {
+                            Ok(event) => changed.extend(event.paths),
+                            Err(mpsc::RecvTimeoutError::Timeout) => break,
+                            Err(mpsc::RecvTimeoutError::Disconnected) => return,
+                        }
+                    }
+
+                    changed.sort();
+                    changed.dedup();
+                    reload_matching(&context, &sources, &changed);
+
+                    if context.strong_count() == 0 {
+                        return;
+                    }
+                }
+            })?;
+
+        *guard = Some(watcher);
+        tracing::info!("Configuration reload enabled (debounce: {} ms)", self.debounce.as_millis());
+        Ok(())
+    }
+
+    /// 停止监听
+    pub fn stop(&self) {
+        if self.watcher.lock().take().is_some() {
+            tracing::debug!("Configuration reload stopped");
+        }
+    }
+}
+
+impl Drop for ConfigReloader {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// 注册监听路径
+///
+/// 文件通过监听其父目录实现，以兼容编辑器“写临时文件再重命名”的保存方式
+fn watch_path(watcher: &mut RecommendedWatcher, path: &Path, recursive: bool) -> notify::Result<()> {
+    if recursive {
+        watcher.watch(path, RecursiveMode::Recursive)
+    } else {
+        let parent = match path.parent() {
+            Some(p) if !p.as_os_str().is_empty() => p,
+            _ => Path::new("."),
+        };
+        watcher.watch(parent, RecursiveMode::NonRecursive)
+    }
+}
+
+/// 重新加载受影响的配置源，返回发生变化的配置键
+fn reload_matching(
+    context: &Weak<ApplicationContext>,
+    sources: &Mutex<Vec<WatchedSource>>,
+    changed: &[PathBuf],
+) -> Vec<String> {
+    let context = match context.upgrade() {
+        Some(context) => context,
+        None => return Vec::new(),
+    };
+
+    let loaders: Vec<(PathBuf, PropertySourceLoader)> = sources
+        .lock()
+        .iter()
+        .filter(|s| changed.iter().any(|c| s.matches(c)))
+        .map(|s| (s.path.clone(), Arc::clone(&s.loader)))
+        .collect();
+
+    let mut all_changed = Vec::new();
+    for (path, loader) in loaders {
+        let loaded = loader().and_then(|source| {
+            context.environment().validate_source_encrypted_values(source.as_ref())?;
+            Ok(source)
+        });
+        match loaded {
+            Ok(source) => {
+                let source_name = source.name().to_string();
+                let keys = context.environment().replace_property_source(source);
+                all_changed.extend(keys.iter().cloned());
+                context.refresh_environment(&source_name, keys);
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Failed to reload configuration from {}, keeping previous values: {:#}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+    }
+
+    all_changed.sort();
+    all_changed.dedup();
+    all_changed
+}
+
+/// 规范化路径以便比较
+fn normalize(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| {
+        if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir()
+                .map(|dir| dir.join(path))
+                .unwrap_or_else(|_| path.to_path_buf())
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ConfigValue, MapPropertySource};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// 在临时目录中写入配置文件，返回文件路径
+    fn temp_config(content: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("chimera-reload-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("application.toml");
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    /// 创建上下文并加载配置文件（按当前激活的 profiles 合并配置段）
+    fn context_with_file(path: &Path, active_profiles: &[&str]) -> Arc<ApplicationContext> {
+        let context = ApplicationContext::builder().build().unwrap();
+        let environment = context.environment();
+        environment.set_active_profiles(active_profiles.iter().map(|p| p.to_string()).collect());
+        let source = TomlPropertySource::from_file(path)
+            .unwrap()
+            .with_active_profiles(&environment.get_active_profiles());
+        environment.add_property_source(Box::new(source));
+        context
+    }
+
+    #[test]
+    fn debounces_bursts_of_file_events_into_one_reload() {
+        let path = temp_config("value = 0\n");
+        let context = ApplicationContext::builder().build().unwrap();
+        let reloader = ConfigReloader::new(&context).debounce(Duration::from_millis(200));
+
+        let reloads = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&reloads);
+        reloader.watch_source(path.clone(), false, Arc::new(move || {
+            let count = counter.fetch_add(1, Ordering::SeqCst) as i64 + 1;
+            Ok(Box::new(MapPropertySource::new("watched").with_property("reloads", ConfigValue::Int(count)))
+                as Box<dyn PropertySource>)
+        }));
+        reloader.start().unwrap();
+
+        for burst in 1..=2 {
+            for value in 0..5 {
+                std::fs::write(&path, format!("value = {}\n", value)).unwrap();
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            std::thread::sleep(Duration::from_millis(1000));
+            assert_eq!(reloads.load(Ordering::SeqCst), burst);
+        }
+        assert_eq!(context.environment().get_i64("reloads"), Some(2));
+
+        reloader.stop();
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn reapplies_active_profiles_when_reloading_a_file() {
+        let path = temp_config("[server]\nport = 8080\n\n[chimera.profile.dev.server]\nport = 9000\n");
+        let context = context_with_file(&path, &["dev"]);
+        let reloader = ConfigReloader::new(&context);
+        reloader.watch_file(&path, 0);
+        assert_eq!(context.environment().get_i64("server.port"), Some(9000));
+
+        std::fs::write(&path, "[server]\nport = 8080\n\n[chimera.profile.dev.server]\nport = 9100\n").unwrap();
+        assert_eq!(reloader.reload(&path), vec!["server.port"]);
+        assert_eq!(context.environment().get_i64("server.port"), Some(9100));
+
+        // 重新加载时使用当前激活的 profiles
+        context.environment().set_active_profiles(Vec::new());
+        assert_eq!(reloader.reload_all(), vec!["server.port"]);
+        assert_eq!(context.environment().get_i64("server.port"), Some(8080));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn keeps_previous_values_when_encrypted_values_cannot_be_decrypted() {
+        let path = temp_config("[app]\nsecret = \"plain\"\nname = \"demo\"\n");
+        let context = context_with_file(&path, &[]);
+        let reloader = ConfigReloader::new(&context);
+        reloader.watch_file(&path, 0);
+
+        std::fs::write(&path, "[app]\nsecret = \"{cipher}AAAA\"\nname = \"renamed\"\n").unwrap();
+
+        assert!(reloader.reload(&path).is_empty());
+        assert_eq!(context.environment().get_string("app.secret").as_deref(), Some("plain"));
+        assert_eq!(context.environment().get_string("app.name").as_deref(), Some("demo"));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,205 @@
+//! 优雅停机
+//!
+//! 应用收到 SIGTERM / SIGINT 或通过 `ShutdownHandle` 主动触发停机后，按以下顺序关闭：
+//!
+//! 1. 通知各组件停止接收新的工作（如 Web 服务器停止接受新连接）
+//! 2. 执行插件关闭阶段（Web 服务器在此阶段等待进行中的请求处理完成）
+//! 3. 关闭应用上下文（发布 ApplicationShutdownEvent、执行 shutdown hooks、销毁 Bean）
+//!
+//! 第 2 步中 Web 服务器等待请求完成的时间受 `chimera.lifecycle.shutdown-timeout` 限制，
+//! 超时后中止服务器任务，其余插件照常关闭，随后关闭上下文
+//!
+//! ## 使用示例
+//!
+//! ```ignore
+//! let handle = context.shutdown_handle();
+//!
+//! // 在任意位置触发停机
+//! handle.shutdown();
+//!
+//! // 等待停机信号
+//! handle.wait().await;
+//! ```
+
+use crate::config::Environment;
+use crate::constants::CONFIG_LIFECYCLE_SHUTDOWN_TIMEOUT;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// 默认优雅停机超时时间（秒）
+pub const DEFAULT_SHUTDOWN_TIMEOUT_SECS: u64 = 30;
+
+/// 停机句柄
+///
+/// 可以克隆并在多个位置持有，任一位置调用 `shutdown` 都会通知所有等待者
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    sender: watch::Sender<bool>,
+}
+
+impl ShutdownHandle {
+    /// 创建新的停机句柄
+    pub fn new() -> Self {
+        let (sender, _) = watch::channel(false);
+        Self { sender }
+    }
+
+    /// 触发停机（重复调用无副作用）
+    pub fn shutdown(&self) {
+        self.sender.send_if_modified(|requested| !std::mem::replace(requested, true));
+    }
+
+    /// 是否已经触发停机
+    pub fn is_shutdown_requested(&self) -> bool {
+        *self.sender.borrow()
+    }
+
+    /// 等待停机信号（已经触发时立即返回）
+    pub async fn wait(&self) {
+        let mut receiver = self.sender.subscribe();
+        // 发送端由 self 持有，wait_for 不会因通道关闭而失败
+        let _ = receiver.wait_for(|requested| *requested).await;
+    }
+}
+
+impl Default for ShutdownHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for ShutdownHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShutdownHandle")
+            .field("shutdown_requested", &self.is_shutdown_requested())
+            .finish()
+    }
+}
+
+/// 等待进程停机信号（SIGINT，Unix 上还包括 SIGTERM），返回信号名称
+pub async fn shutdown_signal() -> &'static str {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Unable to listen for SIGINT: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut stream) => {
+                stream.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("Unable to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => "SIGINT",
+        _ = terminate => "SIGTERM",
+    }
+}
+
+/// 读取优雅停机超时时间
+///
+/// 支持纯数字（秒）或带单位的字符串（如 "500ms"、"30s"、"1m"）
+pub fn shutdown_timeout(environment: &Environment) -> Duration {
+    let default = Duration::from_secs(DEFAULT_SHUTDOWN_TIMEOUT_SECS);
+
+    if let Some(secs) = environment.get_i64(CONFIG_LIFECYCLE_SHUTDOWN_TIMEOUT) {
+        return Duration::from_secs(secs.max(0) as u64);
+    }
+
+    match environment.get_string(CONFIG_LIFECYCLE_SHUTDOWN_TIMEOUT) {
+        Some(value) => parse_duration(&value).unwrap_or_else(|| {
+            tracing::warn!(
+                "Invalid {} value '{}', using default {}s",
+                CONFIG_LIFECYCLE_SHUTDOWN_TIMEOUT,
+                value,
+                DEFAULT_SHUTDOWN_TIMEOUT_SECS
+            );
+            default
+        }),
+        None => default,
+    }
+}
+
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (number, unit) = match value.find(|c: char| !c.is_ascii_digit()) {
+        Some(idx) => value.split_at(idx),
+        None => (value, "s"),
+    };
+    let number: u64 = number.parse().ok()?;
+
+    match unit.trim() {
+        "ms" => Some(Duration::from_millis(number)),
+        "s" => Some(Duration::from_secs(number)),
+        "m" => Some(Duration::from_secs(number * 60)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ConfigValue, MapPropertySource};
+
+    #[test]
+    fn parses_durations_with_units() {
+        assert_eq!(parse_duration("500ms"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_duration("30s"), Some(Duration::from_secs(30)));
+        assert_eq!(parse_duration("2m"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_duration(" 10 s "), Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn plain_numbers_are_seconds() {
+        assert_eq!(parse_duration("15"), Some(Duration::from_secs(15)));
+        assert_eq!(parse_duration("0"), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        for value in ["", "s", "1h", "1.5s", "-1s", "ten"] {
+            assert_eq!(parse_duration(value), None, "expected '{}' to be rejected", value);
+        }
+    }
+
+    #[test]
+    fn shutdown_timeout_falls_back_to_default() {
+        let env = Environment::new();
+        assert_eq!(shutdown_timeout(&env), Duration::from_secs(DEFAULT_SHUTDOWN_TIMEOUT_SECS));
+
+        env.add_property_source(Box::new(
+            MapPropertySource::new("test")
+                .with_property(CONFIG_LIFECYCLE_SHUTDOWN_TIMEOUT, ConfigValue::String("soon".to_string())),
+        ));
+        assert_eq!(shutdown_timeout(&env), Duration::from_secs(DEFAULT_SHUTDOWN_TIMEOUT_SECS));
+    }
+
+    #[test]
+    fn shutdown_timeout_reads_numbers_and_durations() {
+        let env = Environment::new();
+        env.add_property_source(Box::new(
+            MapPropertySource::new("test").with_property(CONFIG_LIFECYCLE_SHUTDOWN_TIMEOUT, ConfigValue::Int(5)),
+        ));
+        assert_eq!(shutdown_timeout(&env), Duration::from_secs(5));
+
+        env.add_property_source(Box::new(
+            MapPropertySource::new("override")
+                .with_priority(1000)
+                .with_property(CONFIG_LIFECYCLE_SHUTDOWN_TIMEOUT, ConfigValue::String("250ms".to_string())),
+        ));
+        assert_eq!(shutdown_timeout(&env), Duration::from_millis(250));
+    }
+}
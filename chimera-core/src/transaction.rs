@@ -0,0 +1,415 @@
+//! 事务同步
+//!
+//! 提供与事务阶段绑定的事件分发支持：在事务中发布的事件，对于标记了
+//! `#[transactional_event_listener]` 的监听器不会立即执行，而是登记到当前任务的
+//! 事务同步注册表中，在事务到达指定阶段时再执行
+//!
+//! 注册表保存在 tokio task-local 中，由 `TransactionManager` 实现（通过 `TransactionTemplate`
+//! 或手动调用 `TransactionSynchronization::trigger`）驱动
+//!
+//! ## 使用示例
+//!
+//! ```ignore
+//! let template = TransactionTemplate::new(Arc::new(MyTransactionManager::new(pool)));
+//!
+//! template.execute(|| async {
+//!     repository.save(&order).await?;
+//!     // after_commit 监听器会在事务提交成功后才收到此事件
+//!     context.publish_event(Arc::new(OrderCreatedEvent::new(order.id)));
+//!     Ok(())
+//! }).await?;
+//! ```
+
+use crate::Result;
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// 事务阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TransactionPhase {
+    /// 提交前（仍在事务中执行，失败会导致事务回滚）
+    BeforeCommit,
+    /// 提交成功后
+    #[default]
+    AfterCommit,
+    /// 回滚后
+    AfterRollback,
+    /// 事务完成后（无论提交还是回滚）
+    AfterCompletion,
+}
+
+impl TransactionPhase {
+    /// 从字符串解析（after_commit、after-commit 等形式均可）
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().replace('-', "_").as_str() {
+            "before_commit" => Some(Self::BeforeCommit),
+            "after_commit" => Some(Self::AfterCommit),
+            "after_rollback" => Some(Self::AfterRollback),
+            "after_completion" => Some(Self::AfterCompletion),
+            _ => None,
+        }
+    }
+}
+
+/// 事务同步回调
+pub type SynchronizationCallback =
+    Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = Result<()>> + Send>> + Send>;
+
+tokio::task_local! {
+    static CURRENT_TRANSACTION: Arc<TransactionSynchronization>;
+}
+
+/// 事务同步注册表
+///
+/// 每个事务一个实例，保存在当前 tokio 任务中，登记需要在事务各阶段执行的回调
+pub struct TransactionSynchronization {
+    callbacks: Mutex<Vec<(TransactionPhase, SynchronizationCallback)>>,
+}
+
+impl TransactionSynchronization {
+    /// 创建新的事务同步注册表
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            callbacks: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// 获取当前任务中的事务同步注册表
+    pub fn current() -> Option<Arc<Self>> {
+        CURRENT_TRANSACTION.try_with(Arc::clone).ok()
+    }
+
+    /// 当前任务是否处于事务中
+    pub fn is_active() -> bool {
+        Self::current().is_some()
+    }
+
+    /// 在事务作用域中执行 Future，作用域内发布的事件会绑定到此事务
+    pub async fn scope<F: Future>(self: &Arc<Self>, future: F) -> F::Output {
+        CURRENT_TRANSACTION.scope(Arc::clone(self), future).await
+    }
+
+    /// 登记在指定阶段执行的回调
+    pub fn register(&self, phase: TransactionPhase, callback: SynchronizationCallback) {
+        self.callbacks.lock().push((phase, callback));
+    }
+
+    /// 待执行的回调数量
+    pub fn pending_count(&self) -> usize {
+        self.callbacks.lock().len()
+    }
+
+    /// 执行指定阶段登记的回调
+    ///
+    /// 回调执行过程中新登记的同阶段回调也会被执行。`BeforeCommit` 阶段遇到第一个失败即返回错误，
+    /// 其他阶段的失败只记录日志
+    pub async fn trigger(&self, phase: TransactionPhase) -> Result<()> {
+        loop {
+            let callbacks: Vec<_> = {
+                let mut registered = self.callbacks.lock();
+                let (matched, rest): (Vec<_>, Vec<_>) =
+                    registered.drain(..).partition(|(p, _)| *p == phase);
+                *registered = rest;
+                matched
+            };
+
+            if callbacks.is_empty() {
+                return Ok(());
+            }
+
+            for (_, callback) in callbacks {
+                if let Err(e) = callback().await {
+                    if phase == TransactionPhase::BeforeCommit {
+                        return Err(e);
+                    }
+                    tracing::error!("Transaction synchronization failed in {:?} phase: {:#}", phase, e);
+                }
+            }
+        }
+    }
+
+    /// 事务提交成功后调用：依次执行 AfterCommit 和 AfterCompletion 阶段
+    pub async fn after_commit(&self) {
+        let _ = self.trigger(TransactionPhase::AfterCommit).await;
+        let _ = self.trigger(TransactionPhase::AfterCompletion).await;
+    }
+
+    /// 事务回滚后调用：依次执行 AfterRollback 和 AfterCompletion 阶段，并丢弃未执行的回调
+    pub async fn after_rollback(&self) {
+        let _ = self.trigger(TransactionPhase::AfterRollback).await;
+        let _ = self.trigger(TransactionPhase::AfterCompletion).await;
+        self.callbacks.lock().clear();
+    }
+}
+
+/// 事务
+#[async_trait]
+pub trait Transaction: Send {
+    /// 提交事务
+    async fn commit(self: Box<Self>) -> Result<()>;
+
+    /// 回滚事务
+    async fn rollback(self: Box<Self>) -> Result<()>;
+}
+
+/// 事务管理器
+///
+/// 由数据访问层实现，例如基于数据库连接池开启事务
+#[async_trait]
+pub trait TransactionManager: Send + Sync {
+    /// 开启事务
+    async fn begin(&self) -> Result<Box<dyn Transaction>>;
+}
+
+/// 事务模板
+///
+/// 在事务中执行业务逻辑，并按事务结果驱动事务同步注册表：
+/// 1. 开启事务，在事务作用域中执行业务逻辑
+/// 2. 业务逻辑成功：执行 BeforeCommit 回调，然后提交
+/// 3. 提交成功执行 AfterCommit、AfterCompletion；业务逻辑、BeforeCommit 回调或提交失败时回滚，
+///    执行 AfterRollback、AfterCompletion
+pub struct TransactionTemplate {
+    manager: Arc<dyn TransactionManager>,
+}
+
+impl TransactionTemplate {
+    /// 创建事务模板
+    pub fn new(manager: Arc<dyn TransactionManager>) -> Self {
+        Self { manager }
+    }
+
+    /// 在事务中执行
+    pub async fn execute<F, Fut, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let synchronization = TransactionSynchronization::new();
+        let transaction = self.manager.begin().await?;
+
+        let result = synchronization
+            .scope(async {
+                let value = f().await?;
+                synchronization.trigger(TransactionPhase::BeforeCommit).await?;
+                Ok(value)
+            })
+            .await;
+
+        match result {
+            Ok(value) => match transaction.commit().await {
+                Ok(()) => {
+                    synchronization.after_commit().await;
+                    Ok(value)
+                }
+                Err(e) => {
+                    synchronization.after_rollback().await;
+                    Err(e)
+                }
+            },
+            Err(e) => {
+                if let Err(rollback_error) = transaction.rollback().await {
+                    tracing::error!("Failed to roll back transaction: {:#}", rollback_error);
+                }
+                synchronization.after_rollback().await;
+                Err(e)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{
+        ApplicationEventMulticaster, Event, MethodEventListener, SimpleApplicationEventMulticaster,
+    };
+    use std::any::Any;
+    use std::time::SystemTime;
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    struct OrderSaved;
+
+    impl Event for OrderSaved {
+        fn event_name(&self) -> &str {
+            "OrderSaved"
+        }
+
+        fn timestamp(&self) -> SystemTime {
+            SystemTime::UNIX_EPOCH
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    /// 记录提交和回滚的事务管理器
+    struct RecordingManager {
+        log: Log,
+        fail_commit: bool,
+    }
+
+    struct RecordingTransaction {
+        log: Log,
+        fail_commit: bool,
+    }
+
+    #[async_trait]
+    impl Transaction for RecordingTransaction {
+        async fn commit(self: Box<Self>) -> Result<()> {
+            if self.fail_commit {
+                anyhow::bail!("commit failed");
+            }
+            self.log.lock().push("commit".to_string());
+            Ok(())
+        }
+
+        async fn rollback(self: Box<Self>) -> Result<()> {
+            self.log.lock().push("rollback".to_string());
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl TransactionManager for RecordingManager {
+        async fn begin(&self) -> Result<Box<dyn Transaction>> {
+            Ok(Box::new(RecordingTransaction {
+                log: Arc::clone(&self.log),
+                fail_commit: self.fail_commit,
+            }))
+        }
+    }
+
+    fn template(log: &Log, fail_commit: bool) -> TransactionTemplate {
+        TransactionTemplate::new(Arc::new(RecordingManager {
+            log: Arc::clone(log),
+            fail_commit,
+        }))
+    }
+
+    fn listener(name: &'static str, log: &Log) -> MethodEventListener<OrderSaved> {
+        let log = Arc::clone(log);
+        MethodEventListener::new(name, move |_: &OrderSaved| {
+            log.lock().push(name.to_string());
+            Ok(Vec::new())
+        })
+    }
+
+    /// 每个事务阶段各注册一个监听器，另加一个不绑定事务的监听器
+    fn multicaster(log: &Log) -> SimpleApplicationEventMulticaster {
+        let multicaster = SimpleApplicationEventMulticaster::new();
+        multicaster.add_listener(Arc::new(listener("immediate", log)));
+        for (name, phase) in [
+            ("before_commit", TransactionPhase::BeforeCommit),
+            ("after_commit", TransactionPhase::AfterCommit),
+            ("after_rollback", TransactionPhase::AfterRollback),
+            ("after_completion", TransactionPhase::AfterCompletion),
+        ] {
+            multicaster.add_listener(Arc::new(listener(name, log).with_transaction_phase(phase)));
+        }
+        multicaster
+    }
+
+    #[test]
+    fn phases_parse_from_either_separator() {
+        assert_eq!(TransactionPhase::parse("after-commit"), Some(TransactionPhase::AfterCommit));
+        assert_eq!(TransactionPhase::parse("BEFORE_COMMIT"), Some(TransactionPhase::BeforeCommit));
+        assert_eq!(TransactionPhase::parse("after_rollback"), Some(TransactionPhase::AfterRollback));
+        assert_eq!(TransactionPhase::parse("on_commit"), None);
+    }
+
+    #[tokio::test]
+    async fn committed_transaction_dispatches_commit_phases() {
+        let log = Log::default();
+        let multicaster = multicaster(&log);
+
+        template(&log, false)
+            .execute(|| async {
+                multicaster.multicast_event(Arc::new(OrderSaved));
+                log.lock().push("body".to_string());
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *log.lock(),
+            ["immediate", "body", "before_commit", "commit", "after_commit", "after_completion"]
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_body_rolls_back_and_dispatches_rollback_phases() {
+        let log = Log::default();
+        let multicaster = multicaster(&log);
+
+        let result: Result<()> = template(&log, false)
+            .execute(|| async {
+                multicaster.multicast_event(Arc::new(OrderSaved));
+                anyhow::bail!("insert failed")
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().to_string(), "insert failed");
+        assert_eq!(*log.lock(), ["immediate", "rollback", "after_rollback", "after_completion"]);
+    }
+
+    #[tokio::test]
+    async fn failing_before_commit_listener_rolls_back() {
+        let log = Log::default();
+        let multicaster = multicaster(&log);
+        multicaster.add_listener(Arc::new(
+            MethodEventListener::new("validator", |_: &OrderSaved| anyhow::bail!("invalid order"))
+                .with_transaction_phase(TransactionPhase::BeforeCommit),
+        ));
+
+        let result = template(&log, false)
+            .execute(|| async {
+                multicaster.multicast_event(Arc::new(OrderSaved));
+                Ok(())
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().to_string(), "invalid order");
+        assert_eq!(
+            *log.lock(),
+            ["immediate", "before_commit", "rollback", "after_rollback", "after_completion"]
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_commit_dispatches_rollback_phases() {
+        let log = Log::default();
+        let multicaster = multicaster(&log);
+
+        let result = template(&log, true)
+            .execute(|| async {
+                multicaster.multicast_event(Arc::new(OrderSaved));
+                Ok(())
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().to_string(), "commit failed");
+        assert_eq!(*log.lock(), ["immediate", "before_commit", "after_rollback", "after_completion"]);
+    }
+
+    #[tokio::test]
+    async fn outside_a_transaction_only_fallback_listeners_run() {
+        let log = Log::default();
+        let multicaster = multicaster(&log);
+        multicaster.add_listener(Arc::new(
+            listener("fallback", &log)
+                .with_transaction_phase(TransactionPhase::AfterCommit)
+                .with_fallback_execution(true),
+        ));
+
+        multicaster.multicast_event(Arc::new(OrderSaved));
+
+        assert!(!TransactionSynchronization::is_active());
+        assert_eq!(*log.lock(), ["immediate", "fallback"]);
+    }
+}
@@ -84,6 +84,37 @@ pub mod naming {
     pub fn to_kebab_case(s: &str) -> String {
         to_snake_case(s).replace('_', "-")
     }
+
+    /// Computes the Levenshtein edit distance between two strings.
+    ///
+    /// Used to suggest similar bean names and configuration keys in error messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chimera_core::utils::naming::levenshtein;
+    ///
+    /// assert_eq!(levenshtein("userService", "userServise"), 1);
+    /// assert_eq!(levenshtein("server.port", "server.prot"), 2);
+    /// assert_eq!(levenshtein("", "abc"), 3);
+    /// ```
+    pub fn levenshtein(a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+        for (i, ca) in a.chars().enumerate() {
+            let mut current = vec![i + 1; b.len() + 1];
+            for (j, cb) in b.iter().enumerate() {
+                let cost = usize::from(ca != *cb);
+                current[j + 1] = (previous[j] + cost)
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1);
+            }
+            previous = current;
+        }
+
+        previous[b.len()]
+    }
 }
 
 /// Dependency resolution utilities
@@ -193,6 +224,8 @@ pub mod dependency {
         }
     }
 
+    impl std::error::Error for DependencyValidationError {}
+
     /// Validates dependency graph for circular dependencies and missing beans
     ///
     /// # Arguments
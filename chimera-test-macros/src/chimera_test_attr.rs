@@ -0,0 +1,234 @@
+//! #[chimera_test] 属性宏
+//!
+//! 把测试函数改写为 `#[test]` 函数：在共享运行时上启动测试上下文、注册 `#[mock_bean]` 覆盖、
+//! 解析参数并执行原函数体，最后关闭上下文
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Expr, FnArg, GenericArgument, ItemFn, Lit, Pat, PathArguments, Token, Type};
+
+/// #[chimera_test(...)] 参数
+#[derive(Default)]
+struct ChimeraTestArgs {
+    properties: Vec<String>,
+    profiles: Vec<String>,
+    cache: bool,
+    slice: Option<String>,
+    components: Vec<syn::Path>,
+    configurations: Vec<syn::Path>,
+    controllers: Vec<syn::Path>,
+}
+
+impl Parse for ChimeraTestArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = ChimeraTestArgs::default();
+        let items = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        for item in items {
+            if item.path.is_ident("properties") {
+                args.properties.extend(string_list(&item.value)?);
+            } else if item.path.is_ident("profile") || item.path.is_ident("profiles") {
+                args.profiles.extend(string_list(&item.value)?);
+            } else if item.path.is_ident("slice") {
+                let slice = string_list(&item.value)?.join("");
+                if slice != "core" && slice != "web" {
+                    return Err(syn::Error::new_spanned(&item.value, "expected `\"core\"` or `\"web\"`"));
+                }
+                args.slice = Some(slice);
+            } else if item.path.is_ident("components") {
+                args.components.extend(type_list(&item.value)?);
+            } else if item.path.is_ident("configurations") {
+                args.configurations.extend(type_list(&item.value)?);
+            } else if item.path.is_ident("controllers") {
+                args.controllers.extend(type_list(&item.value)?);
+            } else if item.path.is_ident("cache") {
+                match &item.value {
+                    Expr::Lit(expr_lit) => match &expr_lit.lit {
+                        Lit::Bool(lit) => args.cache = lit.value,
+                        other => return Err(syn::Error::new_spanned(other, "expected `true` or `false`")),
+                    },
+                    other => return Err(syn::Error::new_spanned(other, "expected `true` or `false`")),
+                }
+            } else {
+                return Err(syn::Error::new_spanned(
+                    &item.path,
+                    "expected `properties`, `profile`, `profiles`, `slice`, `components`, `configurations`, `controllers` or `cache`",
+                ));
+            }
+        }
+
+        if let Some(controller) = args.controllers.first() {
+            if args.slice.as_deref() != Some("web") {
+                return Err(syn::Error::new_spanned(controller, "`controllers` requires `slice = \"web\"`"));
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+/// 解析 `"value"` 或 `["a", "b"]`
+fn string_list(expr: &Expr) -> syn::Result<Vec<String>> {
+    match expr {
+        Expr::Lit(expr_lit) => match &expr_lit.lit {
+            Lit::Str(lit) => Ok(vec![lit.value()]),
+            other => Err(syn::Error::new_spanned(other, "expected a string")),
+        },
+        Expr::Array(array) => {
+            let mut values = Vec::new();
+            for elem in &array.elems {
+                values.extend(string_list(elem)?);
+            }
+            Ok(values)
+        }
+        other => Err(syn::Error::new_spanned(other, "expected a string or an array of strings")),
+    }
+}
+
+/// 解析 `Type` 或 `[A, path::to::B]`
+fn type_list(expr: &Expr) -> syn::Result<Vec<syn::Path>> {
+    match expr {
+        Expr::Path(expr_path) => Ok(vec![expr_path.path.clone()]),
+        Expr::Array(array) => {
+            let mut paths = Vec::new();
+            for elem in &array.elems {
+                paths.extend(type_list(elem)?);
+            }
+            Ok(paths)
+        }
+        other => Err(syn::Error::new_spanned(other, "expected a type or an array of types")),
+    }
+}
+
+/// chimera_test 属性宏实现
+pub(crate) fn chimera_test_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as ChimeraTestArgs);
+    let input = parse_macro_input!(item as ItemFn);
+
+    match generate(args, input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn generate(args: ChimeraTestArgs, input: ItemFn) -> syn::Result<TokenStream2> {
+    let test_name = &input.sig.ident;
+    let output = &input.sig.output;
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+
+    if !input.sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.sig.generics,
+            "#[chimera_test] functions cannot be generic",
+        ));
+    }
+
+    // 原函数体改写为内部函数，移除参数上的 #[mock_bean]
+    let mut body_fn = input.clone();
+    body_fn.attrs.clear();
+    body_fn.sig.ident = format_ident!("__chimera_test_body");
+
+    let mut overrides = Vec::new();
+    let mut resolved = Vec::new();
+    let mut arguments = Vec::new();
+
+    for (index, arg) in body_fn.sig.inputs.iter_mut().enumerate() {
+        let FnArg::Typed(pat_type) = arg else {
+            return Err(syn::Error::new_spanned(arg, "#[chimera_test] functions cannot take `self`"));
+        };
+
+        let bean_type = arc_inner_type(&pat_type.ty).ok_or_else(|| {
+            syn::Error::new_spanned(&pat_type.ty, "#[chimera_test] parameters must be `Arc<T>`")
+        })?;
+        let parameter = match pat_type.pat.as_ref() {
+            Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
+            _ => format!("#{}", index),
+        };
+
+        if let Some(mock) = pat_type.attrs.iter().find(|attr| attr.path().is_ident("mock_bean")) {
+            let instance = match &mock.meta {
+                syn::Meta::Path(_) => quote! { <#bean_type as ::std::default::Default>::default() },
+                _ => {
+                    let expr: Expr = mock.parse_args()?;
+                    quote! { #expr }
+                }
+            };
+            overrides.push(quote! {
+                .override_bean::<#bean_type>(#instance)
+            });
+        }
+        pat_type.attrs.retain(|attr| !attr.path().is_ident("mock_bean"));
+
+        let variable = format_ident!("__chimera_arg_{}", index);
+        resolved.push(quote! {
+            let #variable = ::chimera_test::__private::resolve::<#bean_type>(&__chimera_context, #parameter);
+        });
+        arguments.push(variable);
+    }
+
+    let properties = &args.properties;
+    let profiles = &args.profiles;
+    let properties_len = properties.len();
+    let profiles_len = profiles.len();
+    let cache = args.cache;
+    let components = &args.components;
+    let configurations = &args.configurations;
+    let controllers = &args.controllers;
+    let builder = match args.slice.as_deref() {
+        Some("core") => quote! { ::chimera_test::ChimeraTest::core_slice() },
+        Some(_) => quote! { ::chimera_test::ChimeraTest::web_slice() },
+        None => quote! { ::chimera_test::ChimeraTest::new() },
+    };
+    let call = if body_fn.sig.asyncness.is_some() {
+        quote! { __chimera_test_body(#(#arguments),*).await }
+    } else {
+        quote! { __chimera_test_body(#(#arguments),*) }
+    };
+
+    Ok(quote! {
+        #[test]
+        #(#attrs)*
+        #vis fn #test_name() #output {
+            #body_fn
+
+            let __chimera_test = #builder
+                #(.with_component::<#components>())*
+                #(.with_configuration::<#configurations>())*
+                #(.controller::<#controllers>())*
+                .properties([#(#properties),*] as [&str; #properties_len])
+                .profiles([#(#profiles),*] as [&str; #profiles_len])
+                .cache(#cache)
+                #(#overrides)*;
+
+            ::chimera_test::__private::block_on(::chimera_test::__private::run(
+                __chimera_test,
+                |__chimera_context| async move {
+                    #(#resolved)*
+                    #call
+                },
+            ))
+        }
+    })
+}
+
+/// 取出 `Arc<T>` 中的 `T`
+fn arc_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Arc" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,25 @@
+//! Chimera Test Macros
+//!
+//! 提供 `#[chimera_test]` 属性宏，请通过 `chimera_test::chimera_test` 使用
+
+mod chimera_test_attr;
+
+use proc_macro::TokenStream;
+
+/// chimera_test 宏
+///
+/// 为测试启动 ApplicationContext，按参数类型注入 Bean，测试结束后关闭上下文。
+/// 详细说明见 `chimera_test::chimera_test`
+///
+/// # 示例
+///
+/// ```ignore
+/// #[chimera_test(properties = ["server.port=0"], profile = "test")]
+/// async fn finds_user(service: Arc<UserService>, #[mock_bean] repository: Arc<InMemoryUserRepository>) {
+///     // ...
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn chimera_test(attr: TokenStream, item: TokenStream) -> TokenStream {
+    chimera_test_attr::chimera_test_impl(attr, item)
+}
@@ -0,0 +1,361 @@
+//! 测试上下文
+//!
+//! `ChimeraTest` 按测试配置启动应用，`TestContext` 持有启动后的应用并负责停机
+
+use crate::override_bean::{BeanOverride, BeanOverrideProcessor};
+use chimera_core::prelude::*;
+use chimera_core::CONFIG_PROFILES_ACTIVE;
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::sync::OnceCell;
+
+/// 对 ChimeraApplication 的额外配置（显式注册组件、扫描过滤器）
+type ApplicationCustomizer = Box<dyn FnOnce(ChimeraApplication) -> ChimeraApplication + Send>;
+
+/// 测试切片的组件扫描过滤器，参数为组件的 Bean 名称和切片限定的组件
+type SliceFilter = fn(&str, &[&'static str]) -> bool;
+
+/// 已缓存的测试上下文，按测试配置（切片、内联配置和 profiles）区分
+type ContextCache = Mutex<HashMap<String, Arc<OnceCell<Arc<RunningApplication>>>>>;
+
+static CONTEXT_CACHE: OnceLock<ContextCache> = OnceLock::new();
+
+/// 进程退出时关闭缓存的上下文（执行 shutdown hooks、销毁 Bean、停止 Web 服务器）
+#[ctor::dtor]
+fn close_cached_contexts() {
+    let Some(cache) = CONTEXT_CACHE.get() else {
+        return;
+    };
+    let applications: Vec<Arc<RunningApplication>> = cache
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .drain()
+        .filter_map(|(_, cell)| cell.get().cloned())
+        .collect();
+    if applications.is_empty() {
+        return;
+    }
+
+    crate::__private::block_on(async {
+        for application in applications {
+            if let Err(e) = application.shutdown().await {
+                tracing::error!("Failed to shut down cached test application context: {:#}", e);
+            }
+        }
+    });
+}
+
+/// 测试应用构建器
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// let context = ChimeraTest::new()
+///     .property("server.port=0")
+///     .profile("test")
+///     .override_bean(InMemoryUserRepository::default())
+///     .start()
+///     .await?;
+///
+/// let service = context.get_bean::<UserService>()?;
+/// // ...
+///
+/// context.close().await?;
+/// ```
+///
+/// `core_slice()` 不加载插件，`web_slice()`（需启用 `web` 特性）只注册控制器、异常处理器和
+/// Web 基础 Bean，其余协作者通过 `with_component` 显式注册或通过 `override_bean` 提供 mock
+pub struct ChimeraTest {
+    /// 内联配置（`key=value` 形式）
+    properties: Vec<String>,
+
+    /// 激活的 profiles
+    profiles: Vec<String>,
+
+    /// Bean 覆盖
+    overrides: Vec<BeanOverride>,
+
+    /// 显式注册的组件和自定义扫描过滤器
+    customizers: Vec<ApplicationCustomizer>,
+
+    /// 测试切片名称
+    slice: &'static str,
+
+    /// 测试切片的组件扫描过滤器，参数为组件的 Bean 名称和切片限定的组件
+    slice_filter: Option<SliceFilter>,
+
+    /// 切片限定的组件（Web 切片中为要测试的控制器），为空时不限定
+    pub(crate) slice_components: Vec<&'static str>,
+
+    /// 是否启用全局组件扫描
+    component_scan: bool,
+
+    /// 是否处理自动配置
+    auto_configuration: bool,
+
+    /// 是否加载插件
+    plugins: bool,
+
+    /// 是否复用相同配置的上下文
+    cache: bool,
+}
+
+impl ChimeraTest {
+    pub fn new() -> Self {
+        Self {
+            properties: Vec::new(),
+            profiles: Vec::new(),
+            overrides: Vec::new(),
+            customizers: Vec::new(),
+            slice: "full",
+            slice_filter: None,
+            slice_components: Vec::new(),
+            component_scan: true,
+            auto_configuration: true,
+            plugins: true,
+            cache: false,
+        }
+    }
+
+    /// 核心测试切片：不加载任何插件（包括 Web 服务器），只启动依赖注入容器
+    pub fn core_slice() -> Self {
+        Self::new().plugins(false)
+    }
+
+    /// 在核心切片的基础上只扫描 `filter` 接受的组件
+    #[cfg_attr(not(feature = "web"), allow(dead_code))]
+    pub(crate) fn slice(name: &'static str, filter: SliceFilter) -> Self {
+        let mut test = Self::core_slice();
+        test.slice = name;
+        test.slice_filter = Some(filter);
+        test
+    }
+
+    /// 添加内联配置，形式为 `key=value`
+    pub fn property(mut self, property: impl Into<String>) -> Self {
+        self.properties.push(property.into());
+        self
+    }
+
+    /// 添加多个内联配置
+    pub fn properties<I, S>(mut self, properties: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.properties.extend(properties.into_iter().map(Into::into));
+        self
+    }
+
+    /// 激活测试 profile
+    pub fn profile(mut self, profile: impl Into<String>) -> Self {
+        self.profiles.push(profile.into());
+        self
+    }
+
+    /// 激活多个测试 profiles
+    pub fn profiles<I, S>(mut self, profiles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.profiles.extend(profiles.into_iter().map(Into::into));
+        self
+    }
+
+    /// 用给定实例替换容器中类型为 `T` 的 Bean（沿用原有 Bean 名称），不存在时按类型名注册
+    ///
+    /// 组件在扫描之后、创建任何实例之前被替换，依赖 `T` 的其他 Bean（包括 `#[configuration]` 类）会注入该实例；
+    /// 由 `#[bean]` 方法或自动配置定义的 Bean 在所有定义加载完成后、初始化之前替换
+    pub fn override_bean<T>(mut self, instance: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        self.overrides.push(BeanOverride::new(None, instance));
+        self
+    }
+
+    /// 用给定实例替换指定名称的 Bean
+    pub fn override_named_bean<T>(mut self, name: impl Into<String>, instance: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        self.overrides.push(BeanOverride::new(Some(name.into()), instance));
+        self
+    }
+
+    /// 显式注册组件（不受组件扫描设置和切片影响），用于向切片中加入测试需要的协作者
+    pub fn with_component<T: Component>(mut self) -> Self {
+        self.customizers.push(Box::new(|application| application.with_component::<T>()));
+        self
+    }
+
+    /// 显式注册配置类及其 `#[bean]` 方法
+    pub fn with_configuration<T: Component>(mut self) -> Self {
+        self.customizers.push(Box::new(|application| application.with_configuration::<T>()));
+        self
+    }
+
+    /// 添加组件扫描过滤器，参数为组件的 Bean 名称
+    pub fn scan_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.customizers.push(Box::new(move |application| application.scan_filter(filter)));
+        self
+    }
+
+    /// 设置是否启用全局组件扫描（默认启用）
+    pub fn component_scan(mut self, enabled: bool) -> Self {
+        self.component_scan = enabled;
+        self
+    }
+
+    /// 设置是否处理自动配置（默认处理，不受组件扫描设置和切片影响）
+    pub fn auto_configuration(mut self, enabled: bool) -> Self {
+        self.auto_configuration = enabled;
+        self
+    }
+
+    /// 设置是否加载插件（默认加载）
+    pub fn plugins(mut self, enabled: bool) -> Self {
+        self.plugins = enabled;
+        self
+    }
+
+    /// 设置是否复用相同配置的上下文（默认不复用）
+    ///
+    /// 切片、内联配置和 profiles 都相同的测试共享同一个上下文，缓存的上下文在测试进程退出时关闭；
+    /// 存在 Bean 覆盖、显式注册的组件或自定义扫描过滤器时总是启动新的上下文
+    pub fn cache(mut self, cache: bool) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// 启动测试应用
+    pub async fn start(self) -> Result<TestContext> {
+        if !self.cache || !self.overrides.is_empty() || !self.customizers.is_empty() {
+            let application = self.launch().await?;
+            return Ok(TestContext {
+                application: Arc::new(application),
+                cached: false,
+            });
+        }
+
+        let key = self.cache_key();
+        let cell = {
+            let mut cache = CONTEXT_CACHE.get_or_init(Default::default).lock().unwrap();
+            Arc::clone(cache.entry(key.clone()).or_default())
+        };
+        let application = cell
+            .get_or_try_init(|| async move {
+                tracing::debug!("Caching test application context: {}", key);
+                self.launch().await.map(Arc::new)
+            })
+            .await?;
+
+        Ok(TestContext {
+            application: Arc::clone(application),
+            cached: true,
+        })
+    }
+
+    fn cache_key(&self) -> String {
+        format!(
+            "slice={}{:?};plugins={};component_scan={};auto_configuration={};properties={:?};profiles={:?}",
+            self.slice,
+            self.slice_components,
+            self.plugins,
+            self.component_scan,
+            self.auto_configuration,
+            self.properties,
+            self.profiles
+        )
+    }
+
+    async fn launch(self) -> Result<RunningApplication> {
+        let mut properties = self.properties;
+        if !self.profiles.is_empty() {
+            // 以内联配置激活 profiles，优先于配置文件中的 chimera.profiles.active
+            properties.push(format!("{}={}", CONFIG_PROFILES_ACTIVE, self.profiles.join(",")));
+        }
+
+        let mut application = ChimeraApplication::new()
+            .banner(false)
+            .properties(properties)
+            .plugins(self.plugins)
+            .component_scan(self.component_scan)
+            .auto_configuration(self.auto_configuration);
+
+        if let Some(filter) = self.slice_filter {
+            tracing::debug!("Using {} test slice", self.slice);
+            let components = self.slice_components;
+            application = application.scan_filter(move |name| filter(name, &components));
+        }
+        for customize in self.customizers {
+            application = customize(application);
+        }
+
+        if !self.overrides.is_empty() {
+            let processor = Arc::new(BeanOverrideProcessor::new(self.overrides));
+            application = application.initializer(move |context| {
+                context.add_bean_definition_registry_post_processor(processor.clone());
+                context.add_bean_factory_post_processor(processor.clone());
+                Ok(())
+            });
+        }
+
+        application.start().await
+    }
+}
+
+impl Default for ChimeraTest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 已启动的测试应用
+pub struct TestContext {
+    application: Arc<RunningApplication>,
+    cached: bool,
+}
+
+impl TestContext {
+    /// 获取 ApplicationContext 引用
+    pub fn context(&self) -> &Arc<ApplicationContext> {
+        self.application.context()
+    }
+
+    /// 获取正在运行的应用
+    pub fn application(&self) -> &RunningApplication {
+        &self.application
+    }
+
+    /// 是否为缓存的上下文
+    pub fn is_cached(&self) -> bool {
+        self.cached
+    }
+
+    /// 通过类型获取 Bean
+    pub fn get_bean<T: Any + Send + Sync>(&self) -> Result<Arc<T>> {
+        self.context().get_bean_by_type::<T>()
+    }
+
+    /// 结束测试：非缓存的上下文执行优雅停机，缓存的上下文保留给后续测试，在进程退出时关闭
+    pub async fn close(self) -> Result<()> {
+        if self.cached {
+            return Ok(());
+        }
+        self.application.shutdown().await
+    }
+}
+
+impl std::ops::Deref for TestContext {
+    type Target = Arc<ApplicationContext>;
+
+    fn deref(&self) -> &Self::Target {
+        self.context()
+    }
+}
@@ -0,0 +1,153 @@
+//! Chimera Test - Chimera 应用的测试支持
+//!
+//! 提供 `#[chimera_test]` 属性宏，为每个测试启动 ApplicationContext（或复用相同配置的缓存上下文），
+//! 按参数类型注入 Bean，并在测试结束时保证停机
+//!
+//! # 示例
+//!
+//! ```rust,ignore
+//! use chimera_core::prelude::*;
+//! use chimera_test::chimera_test;
+//!
+//! #[chimera_test(properties = ["server.port=0"], profile = "test")]
+//! async fn finds_user(service: Arc<UserService>, #[mock_bean] repository: Arc<InMemoryUserRepository>) {
+//!     repository.insert(User::new(1, "alice"));
+//!     assert_eq!(service.find(1).unwrap().name, "alice");
+//! }
+//! ```
+
+mod context;
+mod override_bean;
+#[cfg(feature = "web")]
+mod web;
+
+pub use context::{ChimeraTest, TestContext};
+
+/// 测试属性宏
+///
+/// 把 `async fn`（或普通 `fn`）转换为 `#[test]` 函数，测试开始前启动应用上下文，
+/// 结束后（包括测试 panic 时）关闭上下文
+///
+/// # 参数
+///
+/// - `properties = ["key=value", ...]` - 内联配置，优先级高于配置文件和环境变量
+/// - `profile = "test"` / `profiles = ["test", "h2"]` - 激活的 profiles
+/// - `cache = true` - 复用相同配置的上下文（存在 `#[mock_bean]` 或显式注册的组件时不复用）
+/// - `slice = "core"` - 不加载插件；`slice = "web"`（需启用 `web` 特性）只注册控制器、异常处理器和 Web 基础 Bean
+/// - `components = [A, B]` / `configurations = [C]` - 显式注册的组件和配置类（例如切片中控制器的协作者）
+/// - `controllers = [UserController]` - Web 切片只注册并挂载这些控制器（需要 `slice = "web"`）
+///
+/// # 测试函数参数
+///
+/// - `Arc<ApplicationContext>` - 测试使用的应用上下文
+/// - `Arc<T>` - 容器中类型为 `T` 的 Bean
+/// - `#[mock_bean] Arc<T>` - 用 `T::default()` 替换容器中类型为 `T` 的 Bean 后注入
+/// - `#[mock_bean(expr)] Arc<T>` - 用表达式的值替换容器中类型为 `T` 的 Bean 后注入
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// #[chimera_test(properties = ["app.greeting=hi"])]
+/// async fn greets(context: Arc<ApplicationContext>, greeter: Arc<Greeter>) {
+///     assert_eq!(greeter.greet("bob"), "hi bob");
+/// }
+///
+/// #[chimera_test]
+/// async fn uses_mock(
+///     service: Arc<OrderService>,
+///     #[mock_bean(FakePaymentGateway::accepting())] gateway: Arc<FakePaymentGateway>,
+/// ) -> Result<()> {
+///     service.place_order(42)?;
+///     assert_eq!(gateway.charges(), 1);
+///     Ok(())
+/// }
+///
+/// #[chimera_test(slice = "web", components = [UserService])]
+/// async fn lists_users(context: Arc<ApplicationContext>, #[mock_bean] repository: Arc<InMemoryUserRepository>) {
+///     let client = WebTestClient::new(context).await.unwrap();
+///     client.get("/api/users").send().await.assert_ok();
+/// }
+/// ```
+pub use chimera_test_macros::chimera_test;
+
+/// `#[chimera_test]` 生成代码使用的内部实现，不属于公开 API
+#[doc(hidden)]
+pub mod __private {
+    use crate::{ChimeraTest, TestContext};
+    use chimera_core::prelude::*;
+    use futures::FutureExt;
+    use std::any::{Any, TypeId};
+    use std::future::Future;
+    use std::panic::AssertUnwindSafe;
+    use std::sync::{Arc, OnceLock};
+
+    /// 所有测试共享的运行时
+    ///
+    /// 缓存的上下文（以及其中的 Web 服务器等后台任务）需要在多个测试之间存活，
+    /// 因此不能为每个测试创建独立的运行时
+    fn runtime() -> &'static tokio::runtime::Runtime {
+        static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+        RUNTIME.get_or_init(|| {
+            tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .thread_name("chimera-test")
+                .build()
+                .expect("Failed to build test runtime")
+        })
+    }
+
+    /// 在共享运行时上执行测试
+    pub fn block_on<F: Future>(future: F) -> F::Output {
+        runtime().block_on(future)
+    }
+
+    /// 启动上下文并执行测试体，测试体结束（或 panic）后关闭上下文
+    pub async fn run<R, B, Fut>(test: ChimeraTest, body: B) -> R
+    where
+        B: FnOnce(Arc<ApplicationContext>) -> Fut,
+        Fut: Future<Output = R>,
+    {
+        let context: TestContext = match test.start().await {
+            Ok(context) => context,
+            Err(e) => panic!("Failed to start test application context: {:#}", e),
+        };
+
+        let result = AssertUnwindSafe(body(Arc::clone(context.context())))
+            .catch_unwind()
+            .await;
+
+        let closed = context.close().await;
+        match result {
+            Ok(output) => {
+                if let Err(e) = closed {
+                    panic!("Failed to shut down test application context: {:#}", e);
+                }
+                output
+            }
+            Err(panic) => {
+                if let Err(e) = closed {
+                    tracing::error!("Failed to shut down test application context: {:#}", e);
+                }
+                std::panic::resume_unwind(panic)
+            }
+        }
+    }
+
+    /// 按类型解析测试函数参数：`ApplicationContext` 返回上下文本身，其他类型从容器中获取
+    pub fn resolve<T: Any + Send + Sync>(context: &Arc<ApplicationContext>, parameter: &str) -> Arc<T> {
+        if TypeId::of::<T>() == TypeId::of::<ApplicationContext>() {
+            let context: Arc<dyn Any + Send + Sync> = Arc::clone(context) as _;
+            return context.downcast::<T>().expect("ApplicationContext type mismatch");
+        }
+
+        match context.get_bean_by_type::<T>() {
+            Ok(bean) => bean,
+            Err(e) => panic!(
+                "Failed to resolve test parameter '{}' of type '{}': {:#}",
+                parameter,
+                std::any::type_name::<T>(),
+                e
+            ),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Bean 覆盖
+//!
+//! 在 Bean 实例化之前用测试提供的实例替换容器中的 Bean 定义，用于注入 mock
+
+use chimera_core::prelude::*;
+use chimera_core::utils::naming::to_camel_case;
+use std::any::{Any, TypeId};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// 在容器中执行替换的函数，参数为是否允许注册新的定义，返回是否执行了替换
+type ApplyOverride = Box<dyn Fn(&ApplicationContext, bool) -> Result<bool> + Send + Sync>;
+
+/// 单个 Bean 覆盖，替换指定类型（或名称）的 Bean 定义
+pub(crate) struct BeanOverride {
+    /// 被覆盖的类型名，用于日志
+    type_name: &'static str,
+
+    /// 执行替换
+    apply: ApplyOverride,
+
+    /// 是否已经执行替换
+    applied: AtomicBool,
+}
+
+impl BeanOverride {
+    /// 创建覆盖，`name` 为空时替换所有同类型的 Bean 定义并沿用原有名称
+    pub(crate) fn new<T>(name: Option<String>, instance: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        // 单例工厂只会被调用一次，实例在首次创建时取出
+        let instance = Arc::new(Mutex::new(Some(instance)));
+        let type_name = std::any::type_name::<T>();
+
+        let apply = move |context: &ApplicationContext, register_missing: bool| -> Result<bool> {
+            let bean_factory = context.get_bean_factory();
+            let existing = bean_factory.get_bean_names_for_type(TypeId::of::<T>());
+
+            let bean_name = match name.clone().or_else(|| existing.first().cloned()) {
+                Some(bean_name) if bean_factory.contains_bean_definition(&bean_name) => bean_name,
+                // 目标还没有定义（可能由 #[bean] 方法或自动配置定义），等所有定义加载完成后再替换
+                _ if !register_missing => return Ok(false),
+                Some(bean_name) => bean_name,
+                None => default_bean_name(type_name),
+            };
+
+            // 只有由 #[bean] 方法或自动配置定义、并被配置类依赖的 Bean 才可能已经创建
+            if bean_factory.contains_singleton(&bean_name) {
+                tracing::warn!(
+                    "Bean '{}' was created before the test override was applied; \
+                     beans that already received it keep the real instance",
+                    bean_name
+                );
+            }
+
+            for existing_name in &existing {
+                bean_factory.remove_bean_definition(existing_name)?;
+            }
+            if bean_factory.contains_bean_definition(&bean_name) {
+                bean_factory.remove_bean_definition(&bean_name)?;
+            }
+
+            let instance = Arc::clone(&instance);
+            context.register_singleton(bean_name.clone(), move || {
+                instance
+                    .lock()
+                    .unwrap()
+                    .take()
+                    .ok_or_else(|| anyhow!("Overridden bean of type '{}' was already created", type_name))
+            })?;
+
+            tracing::debug!(
+                "Bean '{}' overridden with test instance of type '{}' (replaced: {:?})",
+                bean_name,
+                type_name,
+                existing
+            );
+            Ok(true)
+        };
+
+        Self {
+            type_name,
+            apply: Box::new(apply),
+            applied: AtomicBool::new(false),
+        }
+    }
+
+    /// 执行尚未完成的替换
+    fn apply(&self, context: &ApplicationContext, register_missing: bool) -> Result<()> {
+        if self.applied.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let applied = (self.apply)(context, register_missing)
+            .with_context(|| format!("Failed to override bean of type '{}'", self.type_name))?;
+        self.applied.store(applied, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+/// 在 Bean 实例化之前应用所有覆盖
+///
+/// 组件扫描之后立即替换已经存在的定义（此时还没有创建任何实例，配置类也会注入替换后的实例）；
+/// 由 `#[bean]` 方法、自动配置定义或尚不存在的 Bean 在所有定义加载完成后、初始化之前替换或注册
+pub(crate) struct BeanOverrideProcessor {
+    overrides: Vec<BeanOverride>,
+}
+
+impl BeanOverrideProcessor {
+    pub(crate) fn new(overrides: Vec<BeanOverride>) -> Self {
+        Self { overrides }
+    }
+}
+
+impl BeanDefinitionRegistryPostProcessor for BeanOverrideProcessor {
+    fn post_process_bean_definition_registry(&self, context: &ApplicationContext) -> Result<()> {
+        for bean_override in &self.overrides {
+            bean_override.apply(context, false)?;
+        }
+        Ok(())
+    }
+
+    /// 最后执行，覆盖其他处理器注册的定义
+    fn order(&self) -> i32 {
+        i32::MAX
+    }
+}
+
+impl BeanFactoryPostProcessor for BeanOverrideProcessor {
+    fn post_process_bean_factory(&self, context: &ApplicationContext) -> Result<()> {
+        for bean_override in &self.overrides {
+            bean_override.apply(context, true)?;
+        }
+        Ok(())
+    }
+
+    /// 最后执行，覆盖其他处理器注册的定义
+    fn order(&self) -> i32 {
+        i32::MAX
+    }
+}
+
+/// 按类型名推断 Bean 名称（去掉路径和泛型参数后转为 camelCase）
+fn default_bean_name(type_name: &str) -> String {
+    let base = type_name.split('<').next().unwrap_or(type_name);
+    to_camel_case(base.rsplit("::").next().unwrap_or(base))
+}
@@ -0,0 +1,36 @@
+//! Web 测试切片
+//!
+//! 只注册控制器（可限定为要测试的控制器）、异常处理器、chimera-web 的基础 Bean 以及显式指定的协作者，不启动 Web 服务器
+
+use crate::context::{ChimeraTest, TestContext};
+use chimera_core::prelude::*;
+use chimera_web::testing::{is_web_slice_component_for, WebTestClient};
+use std::sync::Arc;
+
+impl ChimeraTest {
+    /// Web 测试切片
+    ///
+    /// 不加载插件，组件扫描只注册控制器和异常处理器，Web 基础 Bean 由自动配置提供。控制器依赖的其他 Bean
+    /// 需要通过 `with_component` 显式注册或通过 `override_bean` 提供 mock，否则启动时报告缺失的依赖
+    pub fn web_slice() -> Self {
+        Self::slice("web", is_web_slice_component_for)
+    }
+
+    /// 只测试控制器 `C` 的 Web 测试切片，其他控制器不注册，也不挂载路由
+    pub fn web_slice_for<C: Component>() -> Self {
+        Self::web_slice().controller::<C>()
+    }
+
+    /// 把控制器 `C` 加入 Web 测试切片；指定过控制器后，切片只注册这些控制器
+    pub fn controller<C: Component>(mut self) -> Self {
+        self.slice_components.push(C::bean_name());
+        self
+    }
+}
+
+impl TestContext {
+    /// 基于测试上下文创建进程内 HTTP 测试客户端
+    pub async fn web_client(&self) -> Result<WebTestClient> {
+        WebTestClient::new(Arc::clone(self.context())).await
+    }
+}
@@ -0,0 +1,169 @@
+//! `#[chimera_test]` 集成测试：内联配置、profiles、`#[mock_bean]` 和上下文缓存
+
+use chimera_core::prelude::*;
+use chimera_core_macros::{auto_configuration, bean, configuration, Component, Configuration};
+use chimera_test::{chimera_test, ChimeraTest};
+use std::sync::{Arc, Mutex};
+
+/// 记录问候语的仓库
+#[derive(Component, Default)]
+pub struct GreetingRepository {
+    greetings: Mutex<Vec<String>>,
+}
+
+impl GreetingRepository {
+    fn seeded(greeting: &str) -> Self {
+        Self {
+            greetings: Mutex::new(vec![greeting.to_string()]),
+        }
+    }
+
+    fn save(&self, greeting: String) {
+        self.greetings.lock().unwrap().push(greeting);
+    }
+
+    fn greetings(&self) -> Vec<String> {
+        self.greetings.lock().unwrap().clone()
+    }
+}
+
+#[derive(Component)]
+pub struct Greeter {
+    #[value("app.greeting", default = "hello")]
+    greeting: String,
+
+    #[autowired]
+    repository: Arc<GreetingRepository>,
+}
+
+impl Greeter {
+    fn greet(&self, name: &str) -> String {
+        let greeting = format!("{} {}", self.greeting, name);
+        self.repository.save(greeting.clone());
+        greeting
+    }
+}
+
+/// 被配置类依赖的 Bean，在注册 `#[bean]` 方法时随配置类一起创建
+#[derive(Component, Default)]
+pub struct AuditLog {
+    mocked: bool,
+}
+
+pub struct AuditReport {
+    source: &'static str,
+}
+
+#[derive(Configuration)]
+pub struct AuditConfiguration {
+    #[autowired]
+    audit_log: Arc<AuditLog>,
+}
+
+#[configuration]
+impl AuditConfiguration {
+    #[bean]
+    fn audit_report(&self) -> AuditReport {
+        AuditReport { source: "configuration" }
+    }
+}
+
+#[chimera_test(slice = "core", properties = ["app.greeting=hi"], profile = "test")]
+async fn applies_properties_and_profiles(context: Arc<ApplicationContext>, greeter: Arc<Greeter>) {
+    assert_eq!(greeter.greet("bob"), "hi bob");
+    assert_eq!(context.environment().get_active_profiles(), vec!["test".to_string()]);
+}
+
+#[chimera_test(slice = "core")]
+async fn uses_default_values_without_properties(greeter: Arc<Greeter>) {
+    assert_eq!(greeter.greet("bob"), "hello bob");
+}
+
+#[chimera_test(slice = "core")]
+async fn injects_mock_bean_into_dependents(greeter: Arc<Greeter>, #[mock_bean] repository: Arc<GreetingRepository>) {
+    greeter.greet("alice");
+
+    assert!(Arc::ptr_eq(&greeter.repository, &repository));
+    assert_eq!(repository.greetings(), vec!["hello alice".to_string()]);
+}
+
+#[chimera_test(slice = "core")]
+async fn injects_mock_bean_from_expression(
+    greeter: Arc<Greeter>,
+    #[mock_bean(GreetingRepository::seeded("seed"))] repository: Arc<GreetingRepository>,
+) -> Result<()> {
+    greeter.greet("carol");
+
+    assert_eq!(repository.greetings(), vec!["seed".to_string(), "hello carol".to_string()]);
+    Ok(())
+}
+/// 由自动配置提供的默认 Bean
+pub struct GreetingFormat {
+    template: &'static str,
+}
+
+#[derive(Configuration)]
+pub struct GreetingAutoConfiguration {}
+
+#[auto_configuration]
+impl GreetingAutoConfiguration {
+    #[bean]
+    #[conditional_on_missing_bean]
+    fn greeting_format(&self) -> GreetingFormat {
+        GreetingFormat { template: "{greeting} {name}" }
+    }
+}
+
+#[tokio::test]
+async fn overrides_beans_injected_into_configuration_classes() {
+    let context = ChimeraTest::core_slice().override_bean(AuditLog { mocked: true }).start().await.unwrap();
+
+    let configuration = context.get_bean::<AuditConfiguration>().unwrap();
+    assert!(configuration.audit_log.mocked);
+    assert!(Arc::ptr_eq(&configuration.audit_log, &context.get_bean::<AuditLog>().unwrap()));
+
+    context.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn overrides_beans_defined_by_bean_methods() {
+    let context = ChimeraTest::core_slice().override_bean(AuditReport { source: "test" }).start().await.unwrap();
+
+    assert_eq!(context.get_bean::<AuditReport>().unwrap().source, "test");
+
+    context.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn auto_configurations_ignore_scan_filters() {
+    let context = ChimeraTest::core_slice().scan_filter(|_| false).start().await.unwrap();
+    assert_eq!(context.get_bean::<GreetingFormat>().unwrap().template, "{greeting} {name}");
+    assert!(!context.contains_bean_by_type::<Greeter>());
+    context.close().await.unwrap();
+
+    let context = ChimeraTest::core_slice().component_scan(false).start().await.unwrap();
+    assert!(context.contains_bean_by_type::<GreetingFormat>());
+    context.close().await.unwrap();
+
+    let context = ChimeraTest::core_slice().auto_configuration(false).start().await.unwrap();
+    assert!(!context.contains_bean_by_type::<GreetingFormat>());
+    assert!(context.contains_bean_by_type::<Greeter>());
+    context.close().await.unwrap();
+}
+
+#[test]
+fn reuses_cached_contexts_with_same_configuration() {
+    chimera_test::__private::block_on(async {
+        let first = ChimeraTest::core_slice().property("app.greeting=cached").cache(true).start().await.unwrap();
+        let second = ChimeraTest::core_slice().property("app.greeting=cached").cache(true).start().await.unwrap();
+        let other = ChimeraTest::core_slice().property("app.greeting=other").cache(true).start().await.unwrap();
+
+        assert!(first.is_cached());
+        assert!(Arc::ptr_eq(first.context(), second.context()));
+        assert!(!Arc::ptr_eq(first.context(), other.context()));
+
+        for context in [first, second, other] {
+            context.close().await.unwrap();
+        }
+    });
+}
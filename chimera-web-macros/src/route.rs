@@ -4,6 +4,7 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Attribute, FnArg, ImplItem, ImplItemFn, ItemImpl, ItemStruct, Type};
 
+
 /// controller 宏实现
 ///
 /// 可以用于结构体或 impl 块：
@@ -59,6 +60,7 @@ fn handle_controller_struct(attr: TokenStream, item: ItemStruct) -> TokenStream
         ::chimera_core::inventory::submit! {
             ::chimera_web::controller::ControllerRegistration {
                 type_name: stringify!(#name),
+                bean_name: <#name as ::chimera_core::Component>::bean_name,
                 base_path: #base_path,
                 register: |router| {
                     #name::__register_routes(router)
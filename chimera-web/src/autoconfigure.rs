@@ -0,0 +1,52 @@
+//! Web 自动配置
+//!
+//! 注册 Web 服务器使用的默认 Bean。用户定义了同类型的 Bean 时（例如自定义 `ServerProperties`），
+//! 对应的默认 Bean 会被跳过
+
+use crate::multipart::MultipartProperties;
+use crate::server::ServerProperties;
+use crate::template::{TemplateEngine, TemplateProperties};
+use chimera_core::prelude::*;
+use chimera_core_macros::{auto_configuration, bean, Configuration};
+use std::sync::Arc;
+
+/// Web 自动配置
+#[derive(Configuration)]
+pub struct WebAutoConfiguration {
+    #[autowired]
+    environment: Arc<Environment>,
+}
+
+#[auto_configuration]
+impl WebAutoConfiguration {
+    /// 服务器配置
+    #[bean("serverProperties")]
+    #[conditional_on_missing_bean]
+    pub fn server_properties(&self) -> ServerProperties {
+        ServerProperties::from_environment(&self.environment)
+    }
+
+    /// 文件上传配置
+    #[bean("multipartProperties")]
+    #[conditional_on_missing_bean]
+    pub fn multipart_properties(&self) -> MultipartProperties {
+        MultipartProperties::from_environment(&self.environment)
+    }
+
+    /// 模板配置
+    #[bean("templateProperties")]
+    #[conditional_on_missing_bean]
+    pub fn template_properties(&self) -> TemplateProperties {
+        TemplateProperties::from_environment(&self.environment)
+    }
+
+    /// 模板引擎（`chimera.tera.enabled = true` 时注册）
+    #[bean("templateEngine")]
+    #[conditional_on_property(name = "chimera.tera.enabled", having_value = "true")]
+    #[conditional_on_missing_bean]
+    pub fn template_engine(&self) -> Result<TemplateEngine> {
+        let engine = TemplateEngine::from_environment(&self.environment)?;
+        tracing::info!("Template engine initialized and registered as bean");
+        Ok(engine)
+    }
+}
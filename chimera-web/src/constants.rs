@@ -46,3 +46,75 @@ pub const TERA_PATTERN: &str = "chimera.tera.pattern";
 
 /// 是否启用 Tera 热加载
 pub const TERA_HOT_RELOAD: &str = "chimera.tera.hot-reload";
+
+// ==================== Management 配置 ====================
+
+/// 是否启用管理端点
+pub const MANAGEMENT_ENABLED: &str = "chimera.web.management.enabled";
+
+/// 管理端点基础路径
+pub const MANAGEMENT_BASE_PATH: &str = "chimera.web.management.base-path";
+
+// ==================== 配置元数据 ====================
+
+use chimera_core::metadata::ConfigPropertyMetadata;
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(SERVER_HOST, "String", Some("0.0.0.0"), "服务器监听地址")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(SERVER_PORT, "u16", Some("8080"), "服务器监听端口")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(SERVER_WORKERS, "usize", Some("0"), "工作线程数（0 表示使用 CPU 核心数）")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(SERVER_REQUEST_TIMEOUT, "u64", Some("30"), "请求超时时间（秒）")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(SERVER_ENABLE_CORS, "bool", Some("false"), "是否启用 CORS")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(SERVER_ENABLE_REQUEST_LOGGING, "bool", Some("true"), "是否启用请求日志")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(SERVER_ENABLE_GLOBAL_EXCEPTION_HANDLING, "bool", Some("true"), "是否启用全局异常处理")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(MULTIPART_MAX_FILE_SIZE, "usize", Some("10485760"), "Multipart 最大文件大小（字节）")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(MULTIPART_MAX_FIELDS, "usize", Some("100"), "Multipart 最大字段数量")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(TERA_ENABLED, "bool", Some("false"), "是否启用 Tera 模板引擎")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(TERA_TEMPLATE_DIR, "String", Some("templates"), "Tera 模板目录")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(TERA_PATTERN, "String", Some("templates/**/*"), "Tera 模板文件匹配模式")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(TERA_HOT_RELOAD, "bool", Some("false"), "是否启用 Tera 模板热加载")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(MANAGEMENT_ENABLED, "bool", Some("false"), "是否启用管理端点")
+}
+
+chimera_core::inventory::submit! {
+    ConfigPropertyMetadata::framework(MANAGEMENT_BASE_PATH, "String", Some("/management"), "管理端点基础路径")
+}
@@ -115,6 +115,9 @@ pub struct ControllerRegistration {
     /// 控制器类型名称
     pub type_name: &'static str,
 
+    /// 控制器的 Bean 名称（取自 `Component::bean_name`）
+    pub bean_name: fn() -> &'static str,
+
     /// 基础路径
     pub base_path: &'static str,
 
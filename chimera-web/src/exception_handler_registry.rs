@@ -9,7 +9,8 @@ use std::sync::Arc;
 /// 异常处理器注册信息
 pub struct ExceptionHandlerRegistration {
     pub name: &'static str,
-    pub bean_name: &'static str,
+    /// 异常处理器的 Bean 名称（取自 `Component::bean_name`）
+    pub bean_name: fn() -> &'static str,
     /// 类型转换函数：将 Arc<dyn Any> 转换为 Arc<dyn GlobalExceptionHandler>
     pub cast_fn: fn(Arc<dyn std::any::Any + Send + Sync>) -> Option<Arc<dyn GlobalExceptionHandler>>,
 }
@@ -17,7 +18,7 @@ pub struct ExceptionHandlerRegistration {
 impl ExceptionHandlerRegistration {
     pub const fn new(
         name: &'static str,
-        bean_name: &'static str,
+        bean_name: fn() -> &'static str,
         cast_fn: fn(Arc<dyn std::any::Any + Send + Sync>) -> Option<Arc<dyn GlobalExceptionHandler>>,
     ) -> Self {
         Self {
@@ -46,8 +47,10 @@ pub async fn build_exception_handler_registry_from_inventory(
     tracing::info!("Discovering exception handlers from inventory...");
 
     for handler_info in get_all_exception_handlers() {
+        let bean_name = (handler_info.bean_name)();
+
         // 从容器中获取已经创建好的bean实例
-        match context.get_bean(handler_info.bean_name) {
+        match context.get_bean(bean_name) {
             Ok(bean_any) => {
                 // 使用类型转换函数将 Arc<dyn Any> 转换为 Arc<dyn GlobalExceptionHandler>
                 match (handler_info.cast_fn)(bean_any) {
@@ -55,18 +58,18 @@ pub async fn build_exception_handler_registry_from_inventory(
                         tracing::info!(
                             "Auto-registered exception handler: {} (bean: {})",
                             handler_info.name,
-                            handler_info.bean_name
+                            bean_name
                         );
                         registry.register_arc(handler);
                     }
                     None => {
                         tracing::error!(
                             "Failed to cast bean '{}' to GlobalExceptionHandler",
-                            handler_info.bean_name
+                            bean_name
                         );
                         return Err(anyhow::anyhow!(
                             "Failed to cast bean '{}' to GlobalExceptionHandler",
-                            handler_info.bean_name
+                            bean_name
                         ));
                     }
                 }
@@ -74,12 +77,12 @@ pub async fn build_exception_handler_registry_from_inventory(
             Err(e) => {
                 tracing::error!(
                     "Failed to get exception handler bean '{}': {}",
-                    handler_info.bean_name,
+                    bean_name,
                     e
                 );
                 return Err(anyhow::anyhow!(
                     "Failed to get exception handler bean '{}': {}",
-                    handler_info.bean_name, e
+                    bean_name, e
                 ));
             }
         }
@@ -10,6 +10,7 @@
 //! - **类型安全** - 基于 Axum 的类型安全提取器
 //! - **中间件支持** - 集成 Tower 中间件生态系统
 
+pub mod autoconfigure;
 pub mod server;
 pub mod extractors;
 pub mod controller;
@@ -21,10 +22,12 @@ pub mod validators;
 pub mod multipart;
 pub mod constants;
 pub mod template;
+pub mod management;
+pub mod testing;
 
 // 重新导出核心类型
 pub use exception_handler_registry::{ExceptionHandlerRegistration, get_all_exception_handlers};
-pub use template::{Template, TemplateEngine, TemplateProperties};
+pub use template::{RenderedTemplate, Template, TemplateEngine, TemplateProperties};
 
 // 导出 inventory，供宏使用
 pub use inventory;
@@ -42,6 +45,7 @@ pub mod prelude {
     pub use crate::validators;
     pub use crate::multipart;
     pub use crate::template::*;
+    pub use crate::management::*;
 
     pub use axum;
     pub use axum::routing::{get, post, put, delete, patch};
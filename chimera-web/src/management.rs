@@ -0,0 +1,94 @@
+//! 管理端点
+//!
+//! 提供应用运行时信息的 HTTP 端点，通过 `chimera.web.management.enabled = true` 启用
+//!
+//! ## 端点
+//!
+//! - `GET {base-path}/config-metadata` - 所有已知配置键的元数据，以及配置文件中疑似拼写错误的未知键
+//! - `GET {base-path}/events` - 按事件类型分组的监听器（名称、顺序、同步/异步），以及事件发布次数、
+//!   阻塞发布线程的耗时、各监听器的耗时和失败次数
+//! - `GET {base-path}/conditions` - 自动配置的条件评估报告（每个自动配置及其 Bean 生效或被跳过的原因）
+
+use axum::{routing::get, Extension, Json, Router};
+use chimera_core::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::constants::*;
+
+/// 管理端点配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManagementProperties {
+    /// 是否启用管理端点
+    pub enabled: bool,
+
+    /// 管理端点基础路径（默认 "/management"）
+    pub base_path: String,
+}
+
+impl Default for ManagementProperties {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            base_path: "/management".to_string(),
+        }
+    }
+}
+
+impl ManagementProperties {
+    /// 从 Environment 加载配置
+    pub fn from_environment(env: &Environment) -> Self {
+        let base_path = env
+            .get_string(MANAGEMENT_BASE_PATH)
+            .unwrap_or_else(|| "/management".to_string());
+
+        Self {
+            enabled: env.get_bool(MANAGEMENT_ENABLED).unwrap_or(false),
+            base_path: format!("/{}", base_path.trim_matches('/')),
+        }
+    }
+}
+
+/// 构建管理端点路由
+pub fn management_router(properties: &ManagementProperties) -> Router {
+    let base = properties.base_path.trim_end_matches('/');
+
+    Router::new()
+        .route(&format!("{}/config-metadata", base), get(config_metadata))
+        .route(&format!("{}/events", base), get(events))
+        .route(&format!("{}/conditions", base), get(conditions))
+}
+
+/// 配置元数据端点
+async fn config_metadata(
+    Extension(context): Extension<Arc<ApplicationContext>>,
+) -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "properties": chimera_core::metadata::all(),
+        "unknown_keys": chimera_core::metadata::find_unknown_keys(context.environment()),
+    }))
+}
+
+/// 事件端点
+async fn events(
+    Extension(context): Extension<Arc<ApplicationContext>>,
+) -> Json<serde_json::Value> {
+    let publisher = context.event_publisher();
+
+    Json(serde_json::json!({
+        "listener_count": publisher.listener_count(),
+        "listeners": publisher.listeners_by_event_type(),
+        "metrics": publisher.metrics(),
+    }))
+}
+
+/// 条件评估报告端点
+async fn conditions(
+    Extension(context): Extension<Arc<ApplicationContext>>,
+) -> Json<serde_json::Value> {
+    let report = context.condition_evaluation_report().unwrap_or_default();
+    Json(serde_json::json!({
+        "positive_matches": report.positive_matches().collect::<Vec<_>>(),
+        "negative_matches": report.negative_matches().collect::<Vec<_>>(),
+    }))
+}
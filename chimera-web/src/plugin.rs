@@ -1,22 +1,31 @@
 //! Chimera Web 插件
 //!
-//! 提供 Web 自动装配支持
+//! 启动和关闭 Web 服务器，Web 相关的默认 Bean 由 `WebAutoConfiguration` 注册
 
 use chimera_core::prelude::*;
-use crate::server::ServerProperties;
-use crate::multipart::MultipartProperties;
 use crate::server::ChimeraWebServer;
-use crate::template::{TemplateProperties, TemplateEngine};
+use tokio::sync::Mutex;
+use chimera_core::constants::{LOCAL_SERVER_ADDRESS, LOCAL_SERVER_PORT};
+use chimera_core::shutdown::shutdown_timeout;
 use std::sync::Arc;
+use tokio::task::JoinHandle;
+
+/// 记录 Web 服务器实际监听地址的配置源名称
+const LOCAL_SERVER_PROPERTY_SOURCE: &str = "local-server";
 
 /// Web 应用插件
 ///
 /// 自动装配 Web 服务器
-pub struct WebPlugin;
+pub struct WebPlugin {
+    /// 后台运行的服务器任务，关闭时等待其处理完进行中的请求
+    server_task: Mutex<Option<JoinHandle<()>>>,
+}
 
 impl Default for WebPlugin {
     fn default() -> Self {
-        Self
+        Self {
+            server_task: Mutex::new(None),
+        }
     }
 }
 
@@ -26,85 +35,69 @@ impl ApplicationPlugin for WebPlugin {
         "chimera-web"
     }
 
-    fn priority(&self) -> i32 {
-        90 // Web 插件优先级较低，在其他插件之后配置
+    fn version(&self) -> Option<&str> {
+        Some(env!("CARGO_PKG_VERSION"))
     }
 
-    /// 配置阶段
-    fn configure(&self, context: &Arc<ApplicationContext>) -> Result<()> {
-        let env = Arc::clone(context.environment());
-
-        // 注册 ServerProperties Bean
-        context
-            .register_singleton("serverProperties", {
-                let env = Arc::clone(&env);
-                move || Ok(ServerProperties::from_environment(&env))
-            })?;
-
-        // 注册 MultipartProperties Bean
-        context
-            .register_singleton("multipartProperties", {
-                let env = Arc::clone(&env);
-                move || Ok(MultipartProperties::from_environment(&env))
-            })?;
-
-        // 注册 TemplateProperties Bean
-        context
-            .register_singleton("templateProperties", {
-                let env = Arc::clone(&env);
-                move || Ok(TemplateProperties::from_environment(&env))
-            })?;
-
-        // 注册 TemplateEngine Bean（如果启用）
-        match TemplateEngine::from_environment(&env) {
-            Ok(engine) => {
-                tracing::info!("Template engine initialized and registered as bean");
-                context
-                    .register_singleton("templateEngine", move || Ok(engine.clone()))?;
-            }
-            Err(e) => {
-                tracing::debug!("Template engine not initialized: {}", e);
-            }
-        }
-
-        Ok(())
+    fn priority(&self) -> i32 {
+        90 // Web 插件优先级较低，在其他插件之后配置
     }
 
     /// 启动阶段 - 启动 Web 服务器
     async fn on_startup(&self, context: &Arc<ApplicationContext>) -> Result<()> {
-        // 创建并启动服务器（在后台运行）
-        let context_clone = Arc::clone(context);
-        tokio::spawn(async move {
-            match ChimeraWebServer::new(context_clone).await {
-                Ok(server) => {
-                    // 初始化中间件和自动配置
-                    match server
-                        .initialize_middleware().await
-                        .and_then(|s| s.auto_register_controllers())
-                        .map(|s| s.with_middleware())
-                    {
-                        Ok(configured_server) => {
-                            if let Err(e) = configured_server.run().await {
-                                tracing::error!("Web server error: {}", e);
-                            }
-                        }
-                        Err(e) => {
-                            tracing::error!("Failed to configure web server: {}", e);
-                        }
-                    }
-                }
-                Err(e) => {
-                    tracing::error!("Failed to create web server: {}", e);
-                }
+        // 在启动阶段完成配置和端口绑定，失败时中止应用启动
+        let server = ChimeraWebServer::new(Arc::clone(context))
+            .await
+            .context("Failed to create web server")?
+            .auto_configure()
+            .await
+            .context("Failed to configure web server")?
+            .bind()
+            .await?;
+
+        // 记录实际监听地址（配置端口为 0 时由系统分配）
+        let local_addr = server.local_addr().context("Failed to read web server address")?;
+        context.environment().replace_property_source(Box::new(
+            MapPropertySource::new(LOCAL_SERVER_PROPERTY_SOURCE)
+                .with_property(LOCAL_SERVER_ADDRESS, ConfigValue::String(local_addr.to_string()))
+                .with_property(LOCAL_SERVER_PORT, ConfigValue::Int(local_addr.port() as i64)),
+        ));
+
+        // 在后台处理请求，应用停机时停止接受新连接
+        let shutdown_handle = context.shutdown_handle();
+        let task = tokio::spawn(async move {
+            let signal = async move { shutdown_handle.wait().await };
+            if let Err(e) = server.serve_with_graceful_shutdown(signal).await {
+                tracing::error!("Web server error: {}", e);
             }
         });
+        *self.server_task.lock().await = Some(task);
 
         Ok(())
     }
 
     /// 关闭阶段
-    async fn on_shutdown(&self, _context: &Arc<ApplicationContext>) -> Result<()> {
-        tracing::info!("Web server shutting down");
+    async fn on_shutdown(&self, context: &Arc<ApplicationContext>) -> Result<()> {
+        tracing::info!("Web server shutting down, waiting for in-flight requests");
+
+        // 确保服务器收到停机通知（插件可能被单独关闭）
+        context.shutdown_handle().shutdown();
+
+        let task = self.server_task.lock().await.take();
+        if let Some(mut task) = task {
+            // 只限制等待进行中请求的时间，超时后中止服务器任务，不影响其余插件关闭
+            let timeout = shutdown_timeout(context.environment());
+            match tokio::time::timeout(timeout, &mut task).await {
+                Ok(result) => result.context("Web server task failed")?,
+                Err(_) => {
+                    tracing::warn!(
+                        "Web server did not finish in-flight requests within {:?}, aborting",
+                        timeout
+                    );
+                    task.abort();
+                }
+            }
+        }
         Ok(())
     }
 
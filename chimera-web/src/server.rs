@@ -13,6 +13,7 @@ use crate::{
     exception_handler::{build_exception_handler_registry, GlobalExceptionHandlerRegistry},
     middleware::{global_exception_handler, request_id, request_logging},
     controller::get_all_controllers,
+    management::{management_router, ManagementProperties},
 };
 
 /// Web 服务器配置
@@ -134,7 +135,7 @@ impl ChimeraWebServer {
         Ok(self)
     }
 
-    /// 自动注册所有控制器路由
+    /// 自动注册容器中所有控制器的路由
     pub fn auto_register_controllers(mut self) -> Result<Self> {
         use std::collections::HashMap;
 
@@ -143,7 +144,20 @@ impl ChimeraWebServer {
         // 收集所有路由并检测冲突
         let mut route_map: HashMap<(String, String), Vec<String>> = HashMap::new(); // (method, path) -> [controller_names]
 
-        let controllers: Vec<_> = get_all_controllers().collect();
+        // 只注册容器中存在的控制器（被组件扫描过滤器或测试切片排除的控制器不挂载路由）
+        let controllers: Vec<_> = get_all_controllers()
+            .filter(|controller| {
+                let registered = self.context.contains_bean((controller.bean_name)());
+                if !registered {
+                    tracing::debug!(
+                        "Skipping controller {}: bean '{}' is not registered",
+                        controller.type_name,
+                        (controller.bean_name)()
+                    );
+                }
+                registered
+            })
+            .collect();
 
         // 第一遍：收集所有路由并检测冲突
         for controller in &controllers {
@@ -198,6 +212,17 @@ impl ChimeraWebServer {
         Ok(self)
     }
 
+    /// 注册管理端点（需启用 chimera.web.management.enabled）
+    pub fn register_management_endpoints(mut self) -> Self {
+        let properties = ManagementProperties::from_environment(self.context.environment());
+        if properties.enabled {
+            let router = self.router.unwrap_or_default();
+            self.router = Some(router.merge(management_router(&properties)));
+            tracing::info!("Management endpoints enabled at {}", properties.base_path);
+        }
+        self
+    }
+
     /// 应用中间件
     pub fn with_middleware(mut self) -> Self {
         let mut router = self.router.unwrap_or_else(|| Router::new());
@@ -235,31 +260,143 @@ impl ChimeraWebServer {
         Ok(self.initialize_middleware()
             .await?
             .auto_register_controllers()?
+            .register_management_endpoints()
             .with_middleware())
     }
 
-    /// 启动服务器
-    pub async fn run(self) -> Result<()> {
-        let addr = self.config.address();
+    /// 取出配置完成的路由（未设置路由时返回空路由），用于在进程内直接处理请求
+    pub fn into_router(self) -> Router {
+        self.router.unwrap_or_default()
+    }
 
-        // 获取路由，如果没有则创建空路由
-        let app = self.router
-            .unwrap_or_else(|| Router::new())
-            .into_make_service();
+    /// 绑定监听地址
+    ///
+    /// 绑定失败时返回 `WebServerBindError`，调用方可以在启动阶段及时发现端口冲突等问题
+    pub async fn bind(self) -> Result<BoundWebServer> {
+        let addr = self.config.address();
 
         let listener = TcpListener::bind(&addr)
             .await
-            .map_err(|e| anyhow::anyhow!("Failed to bind to {}: {}", addr, e))?;
+            .map_err(|source| WebServerBindError {
+                address: addr.clone(),
+                port: self.config.port,
+                source,
+            })?;
 
+        // 获取路由，如果没有则创建空路由
+        let router = self.router.unwrap_or_default();
+
+        Ok(BoundWebServer {
+            listener,
+            router,
+            port: self.config.port,
+        })
+    }
+
+    /// 启动服务器
+    pub async fn run(self) -> Result<()> {
+        self.bind().await?.serve().await
+    }
+}
+
+/// 已绑定监听地址、尚未开始处理请求的服务器
+pub struct BoundWebServer {
+    listener: TcpListener,
+    router: Router,
+    port: u16,
+}
+
+impl BoundWebServer {
+    /// 实际监听的地址（端口配置为 0 时由系统分配）
+    pub fn local_addr(&self) -> std::io::Result<std::net::SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// 开始处理请求，直到进程退出
+    pub async fn serve(self) -> Result<()> {
+        self.serve_with_graceful_shutdown(std::future::pending()).await
+    }
+
+    /// 开始处理请求，`signal` 完成后停止接受新连接，并等待进行中的请求处理完成后返回
+    pub async fn serve_with_graceful_shutdown<F>(self, signal: F) -> Result<()>
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
         tracing::info!(
             "Web Server (Axum) started on port(s): {} (http)",
-            self.config.port
+            self.local_addr().map(|addr| addr.port()).unwrap_or(self.port)
         );
 
-        axum::serve(listener, app)
+        axum::serve(self.listener, self.router.into_make_service())
+            .with_graceful_shutdown(signal)
             .await
             .map_err(|e| anyhow::anyhow!("Server error: {}", e))?;
 
+        tracing::info!("Web server stopped accepting requests, in-flight requests completed");
         Ok(())
     }
 }
+
+/// Web 服务器绑定监听地址失败
+#[derive(Debug, thiserror::Error)]
+#[error("Failed to bind to {address}: {source}")]
+pub struct WebServerBindError {
+    /// 监听地址
+    pub address: String,
+    /// 监听端口
+    pub port: u16,
+    /// 底层 IO 错误
+    #[source]
+    pub source: std::io::Error,
+}
+
+/// Web 服务器绑定失败分析器
+#[derive(Default)]
+pub struct WebServerBindFailureAnalyzer;
+
+impl FailureAnalyzer for WebServerBindFailureAnalyzer {
+    fn analyze(
+        &self,
+        error: &anyhow::Error,
+        _context: Option<&Arc<ApplicationContext>>,
+    ) -> Option<FailureAnalysis> {
+        let e = chimera_core::diagnostics::find_cause::<WebServerBindError>(error)?;
+
+        let analysis = match e.source.kind() {
+            std::io::ErrorKind::AddrInUse => FailureAnalysis::new(
+                format!("Web server failed to start. Port {} was already in use.", e.port),
+                format!(
+                    "Identify and stop the process that's listening on port {}, or configure \
+                     this application to listen on another port via '{}'.",
+                    e.port, SERVER_PORT
+                ),
+            ),
+            std::io::ErrorKind::PermissionDenied => FailureAnalysis::new(
+                format!(
+                    "Web server failed to start. Permission denied while binding to {}.",
+                    e.address
+                ),
+                format!(
+                    "Ports below 1024 usually require elevated privileges. Configure another \
+                     port via '{}' or run the application with the required permissions.",
+                    SERVER_PORT
+                ),
+            ),
+            _ => FailureAnalysis::new(
+                format!("Web server failed to bind to {}: {}", e.address, e.source),
+                format!(
+                    "Check that '{}' and '{}' describe an address this machine can listen on.",
+                    SERVER_HOST, SERVER_PORT
+                ),
+            ),
+        };
+
+        Some(analysis)
+    }
+
+    fn order(&self) -> i32 {
+        1000
+    }
+}
+
+chimera_core::submit_failure_analyzer!(WebServerBindFailureAnalyzer);
@@ -163,10 +163,44 @@ impl Template {
 
 }
 
+tokio::task_local! {
+    /// 当前请求是否记录渲染的模板，只在 `record_rendered_templates` 的作用域内为 true
+    static RECORD_RENDERED: bool;
+}
+
+/// 在作用域内处理请求，其中 `Template` 渲染成功后把 `RenderedTemplate` 写入响应的 extensions
+///
+/// `WebTestClient` 用它包装发送的每个请求，记录只作用于该客户端的请求，不影响共享的 `TemplateEngine`
+pub async fn record_rendered_templates<F: std::future::Future>(future: F) -> F::Output {
+    RECORD_RENDERED.scope(true, future).await
+}
+
+/// 已渲染的模板信息
+///
+/// 在 `record_rendered_templates` 作用域内（`WebTestClient` 发送的请求）渲染时写入响应的 extensions，
+/// 便于测试中断言渲染的模板和模型数据
+#[derive(Debug, Clone)]
+pub struct RenderedTemplate {
+    /// 模板名称
+    pub name: String,
+    /// 模板上下文数据
+    pub model: serde_json::Value,
+}
+
 impl IntoResponse for Template {
     fn into_response(self) -> Response {
         match self.engine.render_internal(&self.template_name, &self.context) {
-            Ok(html) => (self.status, Html(html)).into_response(),
+            Ok(html) => {
+                let mut response = (self.status, Html(html)).into_response();
+                // 只在测试中记录，避免每次渲染都额外序列化模型数据
+                if RECORD_RENDERED.try_with(|record| *record).unwrap_or(false) {
+                    response.extensions_mut().insert(RenderedTemplate {
+                        name: self.template_name,
+                        model: self.context.into_json(),
+                    });
+                }
+                response
+            }
             Err(err) => {
                 tracing::error!(error = ?err, template = %self.template_name, "Template render error");
 
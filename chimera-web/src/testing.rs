@@ -0,0 +1,635 @@
+//! Web 测试支持
+//!
+//! `WebTestClient` 使用与 Web 服务器相同的 `auto_configure()` 构建路由（控制器、异常处理器、中间件），
+//! 通过 `tower::ServiceExt::oneshot` 在进程内处理请求，无需绑定端口
+//!
+//! ## 使用示例
+//!
+//! ```ignore
+//! use chimera_web::testing::WebTestClient;
+//! use serde_json::json;
+//!
+//! let client = WebTestClient::new(context).await?;
+//!
+//! client
+//!     .post("/api/users")
+//!     .header("X-Tenant", "acme")
+//!     .json(&json!({ "name": "Alice" }))
+//!     .send()
+//!     .await
+//!     .assert_status(StatusCode::CREATED)
+//!     .assert_json_path("data.name", json!("Alice"));
+//!
+//! client
+//!     .get("/users/1")
+//!     .send()
+//!     .await
+//!     .assert_ok()
+//!     .assert_template("user/detail.html")
+//!     .assert_model("user.name", json!("Alice"));
+//! ```
+
+use axum::body::Body;
+use axum::http::{header, HeaderMap, HeaderName, HeaderValue, Method, Request, StatusCode};
+use axum::Router;
+use bytes::Bytes;
+use chimera_core::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+use crate::controller::get_all_controllers;
+use crate::exception_handler_registry::get_all_exception_handlers;
+use crate::server::ChimeraWebServer;
+use crate::template::{record_rendered_templates, RenderedTemplate};
+
+/// 判断组件是否属于 Web 测试切片：控制器和异常处理器
+///
+/// 作为组件扫描过滤器使用时，其他扫描到的组件都不会注册，控制器依赖的服务需要显式注册或用 mock 替换；
+/// ServerProperties 等 Web 基础 Bean 由自动配置提供，不受扫描过滤器影响
+pub fn is_web_slice_component(bean_name: &str) -> bool {
+    is_web_slice_component_for(bean_name, &[])
+}
+
+/// 判断组件是否属于只测试指定控制器的 Web 测试切片
+///
+/// `controllers` 为要测试的控制器的 Bean 名称，为空时接受所有控制器；异常处理器总是注册
+pub fn is_web_slice_component_for(bean_name: &str, controllers: &[&str]) -> bool {
+    if get_all_controllers().any(|controller| (controller.bean_name)() == bean_name) {
+        return controllers.is_empty() || controllers.contains(&bean_name);
+    }
+
+    get_all_exception_handlers()
+        .iter()
+        .any(|handler| (handler.bean_name)() == bean_name)
+}
+
+/// 进程内 HTTP 测试客户端
+#[derive(Clone)]
+pub struct WebTestClient {
+    router: Router,
+}
+
+impl WebTestClient {
+    /// 基于应用上下文构建完整配置的路由（与 Web 服务器启动时的配置一致）
+    pub async fn new(context: Arc<ApplicationContext>) -> Result<Self> {
+        let router = ChimeraWebServer::new(context)
+            .await?
+            .auto_configure()
+            .await?
+            .into_router();
+        Ok(Self { router })
+    }
+
+    /// 使用自定义路由
+    pub fn from_router(router: Router) -> Self {
+        Self { router }
+    }
+
+    /// 创建指定方法的请求
+    pub fn request(&self, method: Method, uri: impl Into<String>) -> TestRequest {
+        TestRequest {
+            router: self.router.clone(),
+            method,
+            uri: uri.into(),
+            headers: HeaderMap::new(),
+            cookies: Vec::new(),
+            body: Body::empty(),
+        }
+    }
+
+    /// GET 请求
+    pub fn get(&self, uri: impl Into<String>) -> TestRequest {
+        self.request(Method::GET, uri)
+    }
+
+    /// POST 请求
+    pub fn post(&self, uri: impl Into<String>) -> TestRequest {
+        self.request(Method::POST, uri)
+    }
+
+    /// PUT 请求
+    pub fn put(&self, uri: impl Into<String>) -> TestRequest {
+        self.request(Method::PUT, uri)
+    }
+
+    /// PATCH 请求
+    pub fn patch(&self, uri: impl Into<String>) -> TestRequest {
+        self.request(Method::PATCH, uri)
+    }
+
+    /// DELETE 请求
+    pub fn delete(&self, uri: impl Into<String>) -> TestRequest {
+        self.request(Method::DELETE, uri)
+    }
+}
+
+/// 测试请求构建器
+pub struct TestRequest {
+    router: Router,
+    method: Method,
+    uri: String,
+    headers: HeaderMap,
+    cookies: Vec<(String, String)>,
+    body: Body,
+}
+
+impl TestRequest {
+    /// 添加请求头
+    ///
+    /// 请求头名称或值无效时 panic
+    pub fn header(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        let name = HeaderName::from_bytes(name.as_ref().as_bytes())
+            .unwrap_or_else(|e| panic!("Invalid header name '{}': {}", name.as_ref(), e));
+        let value = HeaderValue::from_str(value.as_ref())
+            .unwrap_or_else(|e| panic!("Invalid value for header '{}': {}", name, e));
+        self.headers.append(name, value);
+        self
+    }
+
+    /// 添加 Cookie
+    pub fn cookie(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.cookies.push((name.into(), value.into()));
+        self
+    }
+
+    /// 以 JSON 作为请求体
+    pub fn json<T: Serialize + ?Sized>(self, body: &T) -> Self {
+        let body = serde_json::to_vec(body).expect("Failed to serialize JSON request body");
+        self.body_with_type("application/json", body)
+    }
+
+    /// 以表单（application/x-www-form-urlencoded）作为请求体
+    pub fn form<T: Serialize + ?Sized>(self, form: &T) -> Self {
+        let body = serde_urlencoded::to_string(form).expect("Failed to serialize form request body");
+        self.body_with_type("application/x-www-form-urlencoded", body)
+    }
+
+    /// 以 multipart/form-data 作为请求体
+    pub fn multipart(self, body: MultipartBody) -> Self {
+        let content_type = format!("multipart/form-data; boundary={}", body.boundary);
+        let body = body.into_body();
+        self.body_with_type(&content_type, body)
+    }
+
+    /// 设置原始请求体
+    pub fn body(mut self, body: impl Into<Body>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    fn body_with_type(mut self, content_type: &str, body: impl Into<Body>) -> Self {
+        self.headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_str(content_type).expect("Invalid content type"),
+        );
+        self.body = body.into();
+        self
+    }
+
+    /// 发送请求
+    ///
+    /// 请求依次经过中间件、异常处理器和控制器，与真实服务器的处理流程一致
+    pub async fn send(self) -> TestResponse {
+        let mut request = Request::builder()
+            .method(self.method.clone())
+            .uri(&self.uri)
+            .body(self.body)
+            .unwrap_or_else(|e| panic!("Invalid request {} {}: {}", self.method, self.uri, e));
+        *request.headers_mut() = self.headers;
+
+        if !self.cookies.is_empty() {
+            let cookie = self
+                .cookies
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect::<Vec<_>>()
+                .join("; ");
+            request.headers_mut().insert(
+                header::COOKIE,
+                HeaderValue::from_str(&cookie).expect("Invalid cookie value"),
+            );
+        }
+
+        // 只记录本次请求渲染的模板，同一引擎处理的其他请求不受影响
+        let response = match record_rendered_templates(self.router.oneshot(request)).await {
+            Ok(response) => response,
+            Err(infallible) => match infallible {},
+        };
+
+        let (parts, body) = response.into_parts();
+        let body = axum::body::to_bytes(body, usize::MAX)
+            .await
+            .expect("Failed to read response body");
+
+        TestResponse {
+            method: self.method,
+            uri: self.uri,
+            status: parts.status,
+            headers: parts.headers,
+            template: parts.extensions.get::<RenderedTemplate>().cloned(),
+            body,
+        }
+    }
+}
+
+/// multipart/form-data 请求体
+pub struct MultipartBody {
+    boundary: String,
+    parts: Vec<MultipartPart>,
+}
+
+struct MultipartPart {
+    name: String,
+    file_name: Option<String>,
+    content_type: Option<String>,
+    data: Bytes,
+}
+
+impl MultipartBody {
+    pub fn new() -> Self {
+        Self {
+            boundary: format!("chimera-test-{}", uuid::Uuid::new_v4().simple()),
+            parts: Vec::new(),
+        }
+    }
+
+    /// 添加文本字段
+    pub fn text(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.parts.push(MultipartPart {
+            name: name.into(),
+            file_name: None,
+            content_type: None,
+            data: Bytes::from(value.into()),
+        });
+        self
+    }
+
+    /// 添加文件字段
+    pub fn file(
+        mut self,
+        name: impl Into<String>,
+        file_name: impl Into<String>,
+        content_type: impl Into<String>,
+        data: impl Into<Bytes>,
+    ) -> Self {
+        self.parts.push(MultipartPart {
+            name: name.into(),
+            file_name: Some(file_name.into()),
+            content_type: Some(content_type.into()),
+            data: data.into(),
+        });
+        self
+    }
+
+    fn into_body(self) -> Vec<u8> {
+        let mut body = Vec::new();
+        for part in self.parts {
+            body.extend_from_slice(format!("--{}\r\n", self.boundary).as_bytes());
+            let name = escape_disposition_value(&part.name);
+            match &part.file_name {
+                Some(file_name) => body.extend_from_slice(
+                    format!(
+                        "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n",
+                        name,
+                        escape_disposition_value(file_name)
+                    )
+                    .as_bytes(),
+                ),
+                None => body.extend_from_slice(
+                    format!("Content-Disposition: form-data; name=\"{}\"\r\n", name).as_bytes(),
+                ),
+            }
+            if let Some(content_type) = &part.content_type {
+                body.extend_from_slice(format!("Content-Type: {}\r\n", content_type).as_bytes());
+            }
+            body.extend_from_slice(b"\r\n");
+            body.extend_from_slice(&part.data);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        body
+    }
+}
+
+/// 转义 Content-Disposition 中的字段名和文件名（与浏览器一致，`"`、CR、LF 按百分号编码）
+fn escape_disposition_value(value: &str) -> String {
+    value.replace('"', "%22").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+impl Default for MultipartBody {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 测试响应
+///
+/// `assert_*` 方法在断言失败时 panic，错误信息中包含请求和响应体，返回 `&Self` 以便链式调用
+pub struct TestResponse {
+    method: Method,
+    uri: String,
+    status: StatusCode,
+    headers: HeaderMap,
+    template: Option<RenderedTemplate>,
+    body: Bytes,
+}
+
+impl TestResponse {
+    /// 响应状态码
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// 响应头
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// 获取响应头的值
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+    /// 获取响应设置的 Cookie 值（来自 Set-Cookie）
+    pub fn cookie(&self, name: &str) -> Option<String> {
+        self.headers
+            .get_all(header::SET_COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .filter_map(|value| value.split(';').next()?.split_once('='))
+            .find(|(cookie_name, _)| cookie_name.trim() == name)
+            .map(|(_, value)| value.trim().to_string())
+    }
+
+    /// 响应体字节
+    pub fn bytes(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// 响应体文本
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// 将响应体反序列化为 JSON
+    pub fn json<T: DeserializeOwned>(&self) -> T {
+        serde_json::from_slice(&self.body).unwrap_or_else(|e| {
+            panic!("{}: response body is not valid JSON ({})\n{}", self.request_line(), e, self.text())
+        })
+    }
+
+    /// 按路径获取 JSON 响应体中的值，路径形如 `data.items[0].name`（可带 `$.` 前缀）
+    pub fn json_path(&self, path: &str) -> Option<Value> {
+        json_path(&self.json::<Value>(), path).cloned()
+    }
+
+    /// 渲染的模板（响应不是由 `Template` 渲染时为 None）
+    pub fn rendered_template(&self) -> Option<&RenderedTemplate> {
+        self.template.as_ref()
+    }
+
+    /// 断言状态码
+    pub fn assert_status(&self, status: StatusCode) -> &Self {
+        assert_eq!(
+            self.status,
+            status,
+            "{}: unexpected status\n{}",
+            self.request_line(),
+            self.text()
+        );
+        self
+    }
+
+    /// 断言状态码为 200 OK
+    pub fn assert_ok(&self) -> &Self {
+        self.assert_status(StatusCode::OK)
+    }
+
+    /// 断言响应头的值
+    pub fn assert_header(&self, name: &str, value: &str) -> &Self {
+        assert_eq!(
+            self.header(name),
+            Some(value),
+            "{}: unexpected value for header '{}'",
+            self.request_line(),
+            name
+        );
+        self
+    }
+
+    /// 断言存在响应头
+    pub fn assert_header_exists(&self, name: &str) -> &Self {
+        assert!(
+            self.headers.contains_key(name),
+            "{}: missing header '{}'",
+            self.request_line(),
+            name
+        );
+        self
+    }
+
+    /// 断言响应体包含指定文本
+    pub fn assert_body_contains(&self, text: &str) -> &Self {
+        let body = self.text();
+        assert!(
+            body.contains(text),
+            "{}: response body does not contain '{}'\n{}",
+            self.request_line(),
+            text,
+            body
+        );
+        self
+    }
+
+    /// 断言 JSON 响应体等于期望值
+    pub fn assert_json(&self, expected: Value) -> &Self {
+        assert_eq!(
+            self.json::<Value>(),
+            expected,
+            "{}: unexpected JSON body",
+            self.request_line()
+        );
+        self
+    }
+
+    /// 断言 JSON 路径上的值
+    pub fn assert_json_path(&self, path: &str, expected: Value) -> &Self {
+        let body = self.json::<Value>();
+        let actual = json_path(&body, path).unwrap_or_else(|| {
+            panic!("{}: JSON path '{}' not found\n{}", self.request_line(), path, body)
+        });
+        assert_eq!(
+            actual,
+            &expected,
+            "{}: unexpected value at JSON path '{}'",
+            self.request_line(),
+            path
+        );
+        self
+    }
+
+    /// 断言 JSON 路径存在
+    pub fn assert_json_path_exists(&self, path: &str) -> &Self {
+        let body = self.json::<Value>();
+        assert!(
+            json_path(&body, path).is_some(),
+            "{}: JSON path '{}' not found\n{}",
+            self.request_line(),
+            path,
+            body
+        );
+        self
+    }
+
+    /// 断言渲染的模板名称
+    pub fn assert_template(&self, name: &str) -> &Self {
+        assert_eq!(
+            self.template.as_ref().map(|template| template.name.as_str()),
+            Some(name),
+            "{}: unexpected rendered template",
+            self.request_line()
+        );
+        self
+    }
+
+    /// 断言模板模型中指定路径的值，路径格式同 `json_path`
+    pub fn assert_model(&self, path: &str, expected: Value) -> &Self {
+        let template = self.template.as_ref().unwrap_or_else(|| {
+            panic!("{}: response was not rendered from a template", self.request_line())
+        });
+        let actual = json_path(&template.model, path).unwrap_or_else(|| {
+            panic!(
+                "{}: model attribute '{}' not found in template '{}'",
+                self.request_line(),
+                path,
+                template.name
+            )
+        });
+        assert_eq!(
+            actual,
+            &expected,
+            "{}: unexpected model attribute '{}'",
+            self.request_line(),
+            path
+        );
+        self
+    }
+
+    fn request_line(&self) -> String {
+        format!("{} {} -> {}", self.method, self.uri, self.status)
+    }
+}
+
+/// 按 `a.b[0].c` 形式的路径查找 JSON 值
+fn json_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let path = path.strip_prefix('$').unwrap_or(path);
+    let mut current = value;
+
+    for segment in path.split('.').filter(|segment| !segment.is_empty()) {
+        let (key, indexes) = match segment.find('[') {
+            Some(position) => segment.split_at(position),
+            None => (segment, ""),
+        };
+        if !key.is_empty() {
+            current = current.get(key)?;
+        }
+        for index in indexes.split('[').filter(|index| !index.is_empty()) {
+            let index: usize = index.strip_suffix(']')?.parse().ok()?;
+            current = current.get(index)?;
+        }
+    }
+
+    Some(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::TemplateEngine;
+    use serde_json::json;
+
+    fn document() -> Value {
+        json!({
+            "data": {
+                "items": [
+                    {"name": "alice", "tags": ["admin", "ops"]},
+                    {"name": "bob", "tags": []}
+                ],
+                "total": 2
+            },
+            "matrix": [[1, 2], [3, 4]]
+        })
+    }
+
+    #[test]
+    fn resolves_object_keys_and_array_indexes() {
+        let document = document();
+
+        assert_eq!(json_path(&document, "data.total"), Some(&json!(2)));
+        assert_eq!(json_path(&document, "data.items[1].name"), Some(&json!("bob")));
+        assert_eq!(json_path(&document, "data.items[0].tags[1]"), Some(&json!("ops")));
+        assert_eq!(json_path(&document, "matrix[1][0]"), Some(&json!(3)));
+    }
+
+    #[test]
+    fn accepts_root_prefix_and_empty_path() {
+        let document = document();
+
+        assert_eq!(json_path(&document, "$.data.items[0].name"), Some(&json!("alice")));
+        assert_eq!(json_path(&document, "$"), Some(&document));
+        assert_eq!(json_path(&document, ""), Some(&document));
+    }
+
+    #[test]
+    fn missing_or_malformed_paths_return_none() {
+        let document = document();
+
+        for path in ["data.missing", "data.items[2]", "data.items[x]", "data.items[0", "data.total.value", "matrix[0][5]"] {
+            assert_eq!(json_path(&document, path), None, "expected '{}' to resolve to nothing", path);
+        }
+    }
+
+    #[test]
+    fn escapes_multipart_field_names_and_file_names() {
+        let body = MultipartBody::new()
+            .text("say \"hi\"", "hello")
+            .file("upload", "evil\"\r\nX-Injected: 1.txt", "text/plain", "data")
+            .into_body();
+        let body = String::from_utf8(body).unwrap();
+
+        assert!(body.contains("Content-Disposition: form-data; name=\"say %22hi%22\"\r\n"), "{}", body);
+        assert!(
+            body.contains("name=\"upload\"; filename=\"evil%22%0D%0AX-Injected: 1.txt\"\r\n"),
+            "{}",
+            body
+        );
+        assert!(!body.contains("\r\nX-Injected"));
+    }
+
+    #[tokio::test]
+    async fn records_rendered_templates_only_for_test_client_requests() {
+        let dir = std::env::temp_dir().join(format!("chimera-templates-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("hello.html"), "Hello {{ name }}").unwrap();
+        let engine = TemplateEngine::new(&format!("{}/*.html", dir.display()), false).unwrap();
+        let router = Router::new().route(
+            "/",
+            axum::routing::get(move || {
+                let engine = engine.clone();
+                async move { engine.render("hello.html").with("name", "Ann") }
+            }),
+        );
+
+        let response = WebTestClient::from_router(router.clone()).get("/").send().await;
+        response.assert_ok().assert_template("hello.html").assert_model("name", json!("Ann"));
+
+        // 不经过测试客户端的请求不记录
+        let response = router.oneshot(Request::get("/").body(Body::empty()).unwrap()).await.unwrap();
+        assert!(response.extensions().get::<RenderedTemplate>().is_none());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -403,6 +403,29 @@ impl TypedEventListener<UserRegisteredEvent> for AuditService {
     }
 }
 
+/// 通过 #[event_listener] 方法监听多种事件
+#[derive(Component, Clone, Debug)]
+struct UserActivityTracker;
+
+#[component]
+impl UserActivityTracker {
+    #[event_listener(order = -1)]
+    fn on_user_registered(&self, event: &UserRegisteredEvent) {
+        println!("📈 Tracking registration of user: {}", event.username);
+    }
+
+    #[event_listener(condition = "event.user_id.starts_with(\"admin\")")]
+    fn on_admin_registered(&self, event: &UserRegisteredEvent) {
+        println!("🔐 Admin account registered: {}", event.username);
+    }
+
+    #[event_listener]
+    async fn on_health_check(&self, event: &SystemHealthCheckEvent) -> Result<()> {
+        println!("📈 Health check recorded: {}", event.message);
+        Ok(())
+    }
+}
+
 // ==================== 主程序 ====================
 
 pub mod rand {
@@ -491,12 +514,20 @@ async fn main() -> Result<()> {
         // 注册用户（触发事件）
         let user_id = user_service.register_user("alice")?;
 
+        // 以闭包注册临时监听器，守卫离开作用域时自动注销
+        let welcome_listener = context
+            .on(|event: &UserRegisteredEvent| {
+                println!("  👋 Welcome email queued for {}", event.username);
+            })
+            .into_guard();
+
         // 发布用户注册事件
         let event = Arc::new(UserRegisteredEvent::new(
             user_id.clone(),
             "alice".to_string(),
         ));
         context.publish_event(event);
+        drop(welcome_listener);
 
         // 查询用户
         user_service.get_user(&user_id)?;
@@ -519,7 +550,7 @@ async fn main() -> Result<()> {
         println!();
     } // 释放所有bean引用
 
-    context.shutdown()?;
+    context.shutdown().await?;
 
     println!("Demo completed successfully");
     println!("Framework features demonstrated:");